@@ -1,9 +1,39 @@
+use erm::diff::ChangeSet;
 use erm::dtos::diagram::Diagram;
+use erm::{ImageData, OpenedDiagram};
 
 // Learn more about Tauri commands at https://tauri.app/develop/calling-rust/
 #[tauri::command]
-fn load_diagram(filename: &str) -> Result<Diagram, String> {
-    erm::open(filename).map_err(|e| format!("failed to open {}:\n\t{}", filename, e))
+fn load_diagram(
+    filename: &str,
+    virtual_diagram: Option<&str>,
+) -> Result<OpenedDiagram, erm::errors::Error> {
+    match virtual_diagram {
+        Some(name) => erm::open_virtual_diagram(filename, name),
+        None => erm::open(filename),
+    }
+}
+
+#[tauri::command]
+fn load_image(filename: &str, index: usize) -> Result<Option<ImageData>, erm::errors::Error> {
+    erm::open_image(filename, index)
+}
+
+#[tauri::command]
+fn save_diagram(
+    diagram: Diagram,
+    filename: &str,
+    canonical: Option<bool>,
+) -> Result<(), erm::errors::Error> {
+    match canonical {
+        Some(true) => erm::save_with_format(&diagram, filename, erm::Format::Canonical),
+        _ => erm::save(&diagram, filename),
+    }
+}
+
+#[tauri::command]
+fn diff_diagrams(old: Diagram, new: Diagram) -> ChangeSet {
+    erm::diff(&old, &new)
 }
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
@@ -13,7 +43,12 @@ pub fn run() {
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_process::init())
         .plugin(tauri_plugin_os::init())
-        .invoke_handler(tauri::generate_handler![load_diagram])
+        .invoke_handler(tauri::generate_handler![
+            load_diagram,
+            load_image,
+            save_diagram,
+            diff_diagrams
+        ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
@@ -0,0 +1,100 @@
+use super::schema::{Content, SCHEMA};
+use super::xml_tree::{Element, Node};
+
+/// Complex types whose repeated children are sorted by identity rather than kept in document
+/// order, so that concurrent additions land in different places of the file.
+const SORTED_TYPES: &[&str] = &["DiagramWalkersType", "ConnectionsType"];
+
+type SortKey = (usize, usize, Option<String>);
+
+/// Rewrites a document tree into the canonical `.erm` layout.
+///
+/// Child elements follow the order declared in `erm.xsd`, tables and relationships are
+/// sorted by name, booleans are spelled `true`/`false` and elements without content are empty.
+/// Elements the schema does not declare keep their relative order after the declared ones.
+pub(crate) fn canonicalize(root: &mut Element) {
+    collapse_empty_elements(root);
+
+    let declaration = SCHEMA.root();
+    if root.name == declaration.name {
+        canonicalize_element(root, &declaration.content);
+    }
+}
+
+/// Drops the line break that empty elements written across two lines keep.
+fn collapse_empty_elements(element: &mut Element) {
+    if element.is_expanded() {
+        element.children.clear();
+    }
+    for node in &mut element.children {
+        if let Node::Element(child) = node {
+            collapse_empty_elements(child);
+        }
+    }
+}
+
+fn canonicalize_element(element: &mut Element, content: &Content) {
+    match content {
+        Content::Complex(type_name) => canonicalize_children(element, type_name),
+        Content::Boolean => {
+            let text = element.text();
+            let normalized = match text.trim().to_ascii_lowercase().as_str() {
+                "true" | "1" => "true".to_string(),
+                "false" | "0" => "false".to_string(),
+                "" => String::new(),
+                _ => text,
+            };
+            element.children = if normalized.is_empty() {
+                Vec::new()
+            } else {
+                vec![Node::Text(normalized)]
+            };
+        }
+        Content::Simple => {}
+    }
+}
+
+fn canonicalize_children(element: &mut Element, type_name: &str) {
+    let declarations = SCHEMA.children(type_name);
+    let sorted = SORTED_TYPES.contains(&type_name);
+
+    let mut children = Vec::with_capacity(element.children.len());
+    for node in element.children.drain(..) {
+        match node {
+            Node::Element(mut child) => {
+                let key = match declarations.iter().find(|d| d.name == child.name) {
+                    Some(declaration) => {
+                        canonicalize_element(&mut child, &declaration.content);
+                        if sorted {
+                            (declaration.rank, declaration.position, child.identity())
+                        } else {
+                            (declaration.rank, 0, None)
+                        }
+                    }
+                    None => (usize::MAX, 0, None),
+                };
+                children.push((Some(key), Node::Element(child)));
+            }
+            // Text between child elements is layout; elements of a complex type carry none.
+            Node::Text(_) => {}
+            Node::Comment(comment) => children.push((None, Node::Comment(comment))),
+        }
+    }
+
+    // Comments travel with the element that follows them.
+    let mut next_key: SortKey = (usize::MAX, usize::MAX, None);
+    let mut keyed = children
+        .into_iter()
+        .rev()
+        .map(|(key, node)| {
+            if let Some(key) = key {
+                next_key = key;
+            }
+            (next_key.clone(), node)
+        })
+        .collect::<Vec<_>>();
+    keyed.reverse();
+    keyed.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    element.children = keyed.into_iter().map(|(_, node)| node).collect();
+}
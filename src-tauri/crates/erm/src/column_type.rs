@@ -2,8 +2,9 @@ use std::fmt;
 
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize, Default)]
 pub enum ColumnType {
+    #[default]
     #[serde(rename = "char")]
     Char,
     #[serde(rename = "character(n)")]
@@ -196,9 +197,3 @@ impl fmt::Display for ColumnType {
         formatter.write_str(self.as_str())
     }
 }
-
-impl Default for ColumnType {
-    fn default() -> Self {
-        Self::Char
-    }
-}
@@ -0,0 +1,709 @@
+mod migration;
+pub mod mysql;
+pub mod postgresql;
+pub mod sqlite;
+
+use std::fmt;
+
+use serde::Serialize;
+
+use crate::column_type::ColumnType;
+use crate::dtos::diagram::Diagram;
+use crate::dtos::diagram::column_groups::ColumnGroup;
+use crate::dtos::diagram::diagram_settings::ExportSettings;
+use crate::dtos::diagram::diagram_walkers::tables::Table;
+use crate::dtos::diagram::diagram_walkers::tables::columns::ColumnItem;
+use crate::dtos::diagram::diagram_walkers::tables::connections::{OnAction, Relationship};
+use crate::dtos::diagram::diagram_walkers::views::View;
+
+/// A generated DDL script with the parts of the diagram the dialect could not express.
+#[derive(Debug, Clone, PartialEq, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Ddl {
+    pub sql: String,
+    pub warnings: Vec<Warning>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Warning {
+    pub table: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub column: Option<String>,
+    #[serde(flatten)]
+    pub kind: WarningKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(
+    tag = "kind",
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase"
+)]
+pub enum WarningKind {
+    /// The column has no type and none could be resolved from its referred column.
+    MissingColumnType,
+    UnsignedIgnored,
+    /// The values of an `enum` or `set` column were dropped.
+    ArgsIgnored {
+        column_type: ColumnType,
+        args: String,
+    },
+    /// The length or decimal of the column type has no equivalent and was dropped.
+    PrecisionIgnored {
+        column_type: ColumnType,
+    },
+    AutoIncrementUnsupported {
+        column_type: Option<ColumnType>,
+    },
+    /// An identity column cannot have a default value as well.
+    DefaultIgnored {
+        default_value: String,
+    },
+    FullTextIndexUnsupported {
+        index: String,
+    },
+    /// The column type is only available once the named database extension is installed.
+    ExtensionRequired {
+        extension: String,
+    },
+    TableConstraintIgnored {
+        constraint: String,
+    },
+    TableOptionIgnored {
+        option: String,
+    },
+    /// The database cannot create a table without columns, so the table was skipped.
+    TableWithoutColumns,
+    /// The view has no query, so it was skipped.
+    ViewWithoutQuery,
+    /// The database cannot alter the column in place; the table has to be rebuilt by hand.
+    ColumnChangeUnsupported,
+    PrimaryKeyChangeUnsupported,
+    UniqueKeyChangeUnsupported {
+        unique_key: String,
+    },
+    ForeignKeyChangeUnsupported {
+        foreign_key: String,
+    },
+}
+
+impl Warning {
+    pub(crate) fn new(table: &str, column: Option<&str>, kind: WarningKind) -> Self {
+        Self {
+            table: table.to_string(),
+            column: column.map(str::to_string),
+            kind,
+        }
+    }
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.column {
+            Some(column) => write!(formatter, "{}.{column}: ", self.table)?,
+            None => write!(formatter, "{}: ", self.table)?,
+        }
+
+        match &self.kind {
+            WarningKind::MissingColumnType => formatter.write_str("column type is missing"),
+            WarningKind::UnsignedIgnored => formatter.write_str("unsigned is not supported"),
+            WarningKind::ArgsIgnored { column_type, args } => {
+                write!(formatter, "{column_type} values are not supported: {args}")
+            }
+            WarningKind::PrecisionIgnored { column_type } => {
+                write!(formatter, "{column_type} precision is not supported")
+            }
+            WarningKind::AutoIncrementUnsupported {
+                column_type: Some(column_type),
+            } => write!(
+                formatter,
+                "auto increment is not supported for {column_type}"
+            ),
+            WarningKind::AutoIncrementUnsupported { column_type: None } => {
+                formatter.write_str("auto increment is not supported without a column type")
+            }
+            WarningKind::DefaultIgnored { default_value } => write!(
+                formatter,
+                "default value is not supported for an identity column: {default_value}"
+            ),
+            WarningKind::FullTextIndexUnsupported { index } => {
+                write!(formatter, "full-text index is not supported: {index}")
+            }
+            WarningKind::ExtensionRequired { extension } => {
+                write!(formatter, "column type requires the {extension} extension")
+            }
+            WarningKind::TableConstraintIgnored { constraint } => {
+                write!(formatter, "table constraint is not supported: {constraint}")
+            }
+            WarningKind::TableOptionIgnored { option } => {
+                write!(formatter, "table option is not supported: {option}")
+            }
+            WarningKind::TableWithoutColumns => {
+                formatter.write_str("table without columns is not supported")
+            }
+            WarningKind::ViewWithoutQuery => formatter.write_str("view without a query is skipped"),
+            WarningKind::ColumnChangeUnsupported => {
+                formatter.write_str("changing the column requires rebuilding the table")
+            }
+            WarningKind::PrimaryKeyChangeUnsupported => {
+                formatter.write_str("changing the primary key requires rebuilding the table")
+            }
+            WarningKind::UniqueKeyChangeUnsupported { unique_key } => write!(
+                formatter,
+                "changing a unique key requires rebuilding the table: {unique_key}"
+            ),
+            WarningKind::ForeignKeyChangeUnsupported { foreign_key } => write!(
+                formatter,
+                "changing a foreign key requires rebuilding the table: {foreign_key}"
+            ),
+        }
+    }
+}
+
+/// The statements to generate and how to word comments, read from the diagram's export settings.
+///
+/// Settings missing from the file fall back to creating everything and dropping nothing.
+pub(crate) struct Options {
+    pub create_table: bool,
+    pub create_index: bool,
+    pub create_foreign_key: bool,
+    pub create_view: bool,
+    pub create_comment: bool,
+    pub drop_table: bool,
+    pub drop_index: bool,
+    pub drop_view: bool,
+    pub inline_table_comment: bool,
+    pub inline_column_comment: bool,
+    comment_value: CommentValue,
+    line_feed_replacement: Option<String>,
+}
+
+enum CommentValue {
+    Description,
+    LogicalName,
+    LogicalNameDescription,
+}
+
+impl From<&ExportSettings> for Options {
+    fn from(settings: &ExportSettings) -> Self {
+        let comment_value = if settings.comment_value_logical_name_description == Some(true) {
+            CommentValue::LogicalNameDescription
+        } else if settings.comment_value_description == Some(true) {
+            CommentValue::Description
+        } else if settings.comment_value_logical_name == Some(true) {
+            CommentValue::LogicalName
+        } else {
+            CommentValue::LogicalNameDescription
+        };
+
+        Self {
+            create_table: settings.create_table.unwrap_or(true),
+            create_index: settings.create_index.unwrap_or(true),
+            create_foreign_key: settings.create_foreign_key.unwrap_or(true),
+            create_view: settings.create_view.unwrap_or(true),
+            create_comment: settings.create_comment.unwrap_or(true),
+            drop_table: settings.drop_table.unwrap_or(false),
+            drop_index: settings.drop_index.unwrap_or(false),
+            drop_view: settings.drop_view.unwrap_or(false),
+            inline_table_comment: settings.inline_table_comment.unwrap_or(false),
+            inline_column_comment: settings.inline_column_comment.unwrap_or(false),
+            comment_value,
+            line_feed_replacement: (settings.comment_replace_line_feed == Some(true))
+                .then(|| settings.comment_replace_string.clone().unwrap_or_default()),
+        }
+    }
+}
+
+impl Options {
+    pub fn new(diagram: &Diagram) -> Self {
+        Self::from(&diagram.diagram_settings.export_settings)
+    }
+
+    /// Words a comment from a logical name and a description, skipping the empty parts.
+    fn comment(&self, logical_name: Option<&str>, description: Option<&str>) -> Option<String> {
+        let parts = match self.comment_value {
+            CommentValue::Description => [None, description],
+            CommentValue::LogicalName => [logical_name, None],
+            CommentValue::LogicalNameDescription => [logical_name, description],
+        };
+        let parts = parts
+            .into_iter()
+            .flatten()
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>();
+        if parts.is_empty() {
+            return None;
+        }
+
+        let comment = parts.join(": ");
+        Some(match &self.line_feed_replacement {
+            Some(replacement) => comment
+                .replace("\r\n", replacement)
+                .replace(['\r', '\n'], replacement),
+            None => comment,
+        })
+    }
+}
+
+/// A table with its column group references expanded and foreign key column types resolved.
+pub(crate) struct TableDefinition<'a> {
+    pub table: &'a Table,
+    pub columns: Vec<ColumnDefinition<'a>>,
+    pub foreign_keys: Vec<ForeignKey<'a>>,
+}
+
+impl<'a> TableDefinition<'a> {
+    pub fn primary_key(&self) -> Vec<&'a str> {
+        self.columns
+            .iter()
+            .filter(|column| column.primary_key)
+            .map(|column| column.physical_name)
+            .collect()
+    }
+
+    pub fn comment(&self, options: &Options) -> Option<String> {
+        options.comment(
+            Some(self.table.logical_name.as_str()),
+            Some(self.table.description.as_str()),
+        )
+    }
+}
+
+pub(crate) struct ColumnDefinition<'a> {
+    pub physical_name: &'a str,
+    pub logical_name: Option<&'a str>,
+    pub description: Option<&'a str>,
+    pub column_type: Option<ColumnType>,
+    pub length: Option<u16>,
+    pub decimal: Option<u16>,
+    pub args: Option<&'a str>,
+    pub unsigned: bool,
+    pub not_null: bool,
+    pub unique_key: bool,
+    pub default_value: Option<&'a str>,
+    pub primary_key: bool,
+    pub auto_increment: bool,
+    referred_column: Option<&'a str>,
+}
+
+impl ColumnDefinition<'_> {
+    pub fn comment(&self, options: &Options) -> Option<String> {
+        options.comment(self.logical_name, self.description)
+    }
+}
+
+pub(crate) struct ForeignKey<'a> {
+    pub name: &'a str,
+    pub columns: Vec<&'a str>,
+    pub referenced_table: &'a str,
+    pub referenced_columns: Vec<&'a str>,
+    pub on_delete: Option<&'a OnAction>,
+    pub on_update: Option<&'a OnAction>,
+}
+
+/// A view with its query ready to follow `CREATE VIEW ... AS`.
+pub(crate) struct ViewDefinition<'a> {
+    pub view: &'a View,
+    pub query: &'a str,
+}
+
+impl ViewDefinition<'_> {
+    pub fn comment(&self, options: &Options) -> Option<String> {
+        options.comment(
+            Some(self.view.logical_name.as_str()),
+            Some(self.view.description.as_str()),
+        )
+    }
+}
+
+/// Collects the tables of the diagram in the order they are declared.
+pub(crate) fn table_definitions(diagram: &Diagram) -> Vec<TableDefinition<'_>> {
+    let tables = diagram
+        .diagram_walkers
+        .as_ref()
+        .and_then(|walkers| walkers.tables.as_deref())
+        .unwrap_or_default();
+    let column_groups = diagram.column_groups.as_deref().unwrap_or_default();
+
+    let mut definitions = tables
+        .iter()
+        .map(|table| TableDefinition {
+            table,
+            columns: columns(table, column_groups),
+            foreign_keys: Vec::new(),
+        })
+        .collect::<Vec<_>>();
+
+    resolve_referred_column_types(&mut definitions);
+
+    let foreign_keys = definitions
+        .iter()
+        .map(|definition| foreign_keys(definition, &definitions))
+        .collect::<Vec<_>>();
+    for (definition, foreign_keys) in definitions.iter_mut().zip(foreign_keys) {
+        definition.foreign_keys = foreign_keys;
+    }
+
+    definitions
+}
+
+/// Collects the views of the diagram in the order they are declared, since a view may select from
+/// the views before it. Views without a query are skipped with a warning.
+pub(crate) fn view_definitions<'a>(
+    diagram: &'a Diagram,
+    warnings: &mut Vec<Warning>,
+) -> Vec<ViewDefinition<'a>> {
+    let views = diagram
+        .diagram_walkers
+        .as_ref()
+        .and_then(|walkers| walkers.views.as_deref())
+        .unwrap_or_default();
+
+    views
+        .iter()
+        .filter_map(|view| {
+            // The query is written by hand, so it may end with its own statement terminator.
+            let query = view.sql.trim().trim_end_matches(';').trim_end();
+            if query.is_empty() {
+                warnings.push(Warning::new(
+                    &view.physical_name,
+                    None,
+                    WarningKind::ViewWithoutQuery,
+                ));
+                return None;
+            }
+            Some(ViewDefinition { view, query })
+        })
+        .collect()
+}
+
+fn columns<'a>(table: &'a Table, column_groups: &'a [ColumnGroup]) -> Vec<ColumnDefinition<'a>> {
+    let mut columns = Vec::new();
+
+    for item in table.columns.items.as_deref().unwrap_or_default() {
+        match item {
+            ColumnItem::Normal(column) => columns.push(ColumnDefinition {
+                physical_name: &column.physical_name,
+                logical_name: column.logical_name.as_deref(),
+                description: column.description.as_deref(),
+                column_type: column.column_type,
+                length: column.length,
+                decimal: column.decimal,
+                args: column.args.as_deref(),
+                unsigned: column.unsigned == Some(true),
+                not_null: column.not_null == Some(true),
+                unique_key: column.unique_key == Some(true),
+                default_value: column.default_value.as_deref(),
+                primary_key: column.primary_key == Some(true),
+                auto_increment: column.auto_increment == Some(true),
+                referred_column: column.referred_column.as_deref(),
+            }),
+            ColumnItem::Group(group_name) => {
+                let group_columns = column_groups
+                    .iter()
+                    .find(|group| group.column_group_name == *group_name)
+                    .and_then(|group| group.columns.normal_columns.as_deref())
+                    .unwrap_or_default();
+
+                columns.extend(group_columns.iter().map(|column| ColumnDefinition {
+                    physical_name: &column.physical_name,
+                    logical_name: column.logical_name.as_deref(),
+                    description: column.description.as_deref(),
+                    column_type: Some(column.column_type),
+                    length: column.length,
+                    decimal: column.decimal,
+                    args: column.args.as_deref(),
+                    unsigned: column.unsigned == Some(true),
+                    not_null: column.not_null == Some(true),
+                    unique_key: column.unique_key == Some(true),
+                    default_value: column.default_value.as_deref(),
+                    primary_key: false,
+                    auto_increment: false,
+                    referred_column: None,
+                }));
+            }
+        }
+    }
+
+    columns
+}
+
+/// Foreign key columns usually omit their type and inherit it from the referred column.
+fn resolve_referred_column_types(definitions: &mut [TableDefinition]) {
+    // Each pass resolves one more level of a chain of references; a cycle stops after all passes.
+    for _ in 0..definitions.len() {
+        let mut resolved = Vec::new();
+
+        for (table_index, definition) in definitions.iter().enumerate() {
+            for (column_index, column) in definition.columns.iter().enumerate() {
+                if column.column_type.is_some() {
+                    continue;
+                }
+                let Some(referred) = column
+                    .referred_column
+                    .and_then(|reference| find_referenced_column(definitions, reference))
+                    .filter(|referred| referred.column_type.is_some())
+                else {
+                    continue;
+                };
+
+                resolved.push((
+                    table_index,
+                    column_index,
+                    (
+                        referred.column_type,
+                        referred.length,
+                        referred.decimal,
+                        referred.args,
+                        referred.unsigned,
+                    ),
+                ));
+            }
+        }
+
+        if resolved.is_empty() {
+            return;
+        }
+
+        for (table_index, column_index, (column_type, length, decimal, args, unsigned)) in resolved
+        {
+            let column = &mut definitions[table_index].columns[column_index];
+            column.column_type = column_type;
+            column.length = length;
+            column.decimal = decimal;
+            column.args = args;
+            column.unsigned = unsigned;
+        }
+    }
+}
+
+fn find_referenced_column<'d, 'a>(
+    definitions: &'d [TableDefinition<'a>],
+    reference: &str,
+) -> Option<&'d ColumnDefinition<'a>> {
+    let (table_name, column_name) = reference.strip_prefix("table.")?.split_once('.')?;
+
+    find_column(find_table(definitions, table_name)?, column_name)
+}
+
+pub(crate) fn find_column<'d, 'a>(
+    definition: &'d TableDefinition<'a>,
+    column_name: &str,
+) -> Option<&'d ColumnDefinition<'a>> {
+    definition
+        .columns
+        .iter()
+        .find(|column| column.physical_name == column_name)
+}
+
+pub(crate) fn find_table<'d, 'a>(
+    definitions: &'d [TableDefinition<'a>],
+    table_name: &str,
+) -> Option<&'d TableDefinition<'a>> {
+    definitions
+        .iter()
+        .find(|definition| definition.table.physical_name == table_name)
+}
+
+fn foreign_keys<'a>(
+    definition: &TableDefinition<'a>,
+    definitions: &[TableDefinition<'a>],
+) -> Vec<ForeignKey<'a>> {
+    definition
+        .table
+        .connections
+        .relationships
+        .as_deref()
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|relationship| foreign_key(definition, relationship, definitions))
+        .collect()
+}
+
+fn foreign_key<'a>(
+    definition: &TableDefinition<'a>,
+    relationship: &'a Relationship,
+    definitions: &[TableDefinition<'a>],
+) -> Option<ForeignKey<'a>> {
+    let referenced_table = relationship.source.strip_prefix("table.")?;
+    let parent = find_table(definitions, referenced_table)?;
+
+    let columns = relationship
+        .fk_columns
+        .fk_column
+        .iter()
+        .map(|fk_column| fk_column.fk_column_name.as_str())
+        .collect::<Vec<_>>();
+    if columns.is_empty() {
+        return None;
+    }
+
+    let referenced_columns = if let Some(reference) = &relationship.referred_simple_unique_column {
+        vec![reference.rsplit('.').next()?]
+    } else if let Some(key_name) = &relationship.referred_compound_unique_key {
+        parent
+            .table
+            .compound_unique_key_list
+            .compound_unique_keys
+            .as_deref()
+            .unwrap_or_default()
+            .iter()
+            .find(|key| key.name == *key_name)?
+            .columns
+            .iter()
+            .map(|column| column.column_id.as_str())
+            .collect()
+    } else {
+        // Without an explicit unique key, each fk column names the parent column it refers to.
+        let referred = columns
+            .iter()
+            .map(|column_name| {
+                definition
+                    .columns
+                    .iter()
+                    .find(|column| column.physical_name == *column_name)?
+                    .referred_column?
+                    .rsplit('.')
+                    .next()
+            })
+            .collect::<Option<Vec<_>>>();
+
+        match referred {
+            Some(referred) => referred,
+            None => parent.primary_key(),
+        }
+    };
+
+    Some(ForeignKey {
+        name: &relationship.name,
+        columns,
+        referenced_table,
+        referenced_columns,
+        on_delete: relationship.on_delete_action.as_ref(),
+        on_update: relationship.on_update_action.as_ref(),
+    })
+}
+
+/// Orders tables so that every table comes after the tables its foreign keys refer to.
+///
+/// Tables in a reference cycle keep their relative diagram order.
+pub(crate) fn dependency_order<'d, 'a>(
+    definitions: &'d [TableDefinition<'a>],
+) -> Vec<&'d TableDefinition<'a>> {
+    fn visit<'d, 'a>(
+        index: usize,
+        definitions: &'d [TableDefinition<'a>],
+        visited: &mut Vec<bool>,
+        ordered: &mut Vec<&'d TableDefinition<'a>>,
+    ) {
+        if visited[index] {
+            return;
+        }
+        visited[index] = true;
+
+        for foreign_key in &definitions[index].foreign_keys {
+            if let Some(parent) = definitions.iter().position(|definition| {
+                definition.table.physical_name == foreign_key.referenced_table
+            }) {
+                visit(parent, definitions, visited, ordered);
+            }
+        }
+        ordered.push(&definitions[index]);
+    }
+
+    let mut visited = vec![false; definitions.len()];
+    let mut ordered = Vec::new();
+    for index in 0..definitions.len() {
+        visit(index, definitions, &mut visited, &mut ordered);
+    }
+
+    ordered
+}
+
+/// Quotes an identifier with double quotes, as PostgreSQL and SQLite do.
+pub(crate) fn identifier(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
+/// Quotes each name with the dialect's `identifier` and lists them comma-separated.
+pub(crate) fn identifiers(names: &[&str], identifier: fn(&str) -> String) -> String {
+    names
+        .iter()
+        .map(|name| identifier(name))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Writes a standard string literal, which only doubles single quotes.
+pub(crate) fn string_literal(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+/// Writes a default value as is when it is an expression, or else with the dialect's
+/// `string_literal`.
+pub(crate) fn default_literal(value: &str, string_literal: fn(&str) -> String) -> String {
+    if is_default_expression(value) {
+        value.trim().to_string()
+    } else {
+        string_literal(value)
+    }
+}
+
+pub(crate) fn referential_action(action: &OnAction) -> &'static str {
+    match action {
+        OnAction::NoAction => "NO ACTION",
+        _ => action.as_str(),
+    }
+}
+
+pub(crate) fn create_view(definition: &ViewDefinition, identifier: fn(&str) -> String) -> String {
+    format!(
+        "CREATE VIEW {} AS\n{}",
+        identifier(&definition.view.physical_name),
+        definition.query
+    )
+}
+
+/// Writes a comment as an SQL line comment, which cannot span lines.
+pub(crate) fn line_comment(comment: &str) -> String {
+    format!(
+        "-- {}",
+        comment.replace("\r\n", " ").replace(['\r', '\n'], " ")
+    )
+}
+
+/// Table constraints are written for one database; only the standard forms are kept.
+pub(crate) fn is_table_constraint(constraint: &str) -> bool {
+    let constraint = constraint.to_ascii_uppercase();
+
+    [
+        "CONSTRAINT ",
+        "PRIMARY KEY",
+        "UNIQUE",
+        "CHECK",
+        "FOREIGN KEY",
+    ]
+    .iter()
+    .any(|keyword| constraint.starts_with(keyword))
+}
+
+/// Returns true when a default value can be written as is instead of as a string literal.
+pub(crate) fn is_default_expression(value: &str) -> bool {
+    let value = value.trim();
+
+    value.parse::<f64>().is_ok()
+        || (value.starts_with('\'') && value.ends_with('\'') && value.len() > 1)
+        || value.ends_with(')')
+        || matches!(
+            value.to_ascii_uppercase().as_str(),
+            "NULL"
+                | "TRUE"
+                | "FALSE"
+                | "CURRENT_DATE"
+                | "CURRENT_TIME"
+                | "CURRENT_TIMESTAMP"
+                | "LOCALTIME"
+                | "LOCALTIMESTAMP"
+        )
+}
@@ -0,0 +1,355 @@
+use crate::diff::{Change, ChangeSet};
+use crate::dtos::diagram::diagram_walkers::tables::compound_unique_key_list::CompoundUniqueKey;
+use crate::dtos::diagram::diagram_walkers::tables::indexes::Index;
+
+use super::{ColumnDefinition, ForeignKey, TableDefinition, find_column, find_table};
+
+/// The changes between two versions of a diagram that each dialect turns into `ALTER`
+/// statements.
+///
+/// Tables and columns are matched by name, or through the renames detected by
+/// [`crate::diff::diff`]. Indexes, unique keys and foreign keys are matched by name within their
+/// table, so renaming one of them drops it and creates it again.
+pub(crate) struct Migration<'d, 'a> {
+    /// Tables of the old diagram that are gone, in the order they can be dropped.
+    pub dropped_tables: Vec<&'d TableDefinition<'a>>,
+    /// Old and new names of the renamed tables.
+    pub renamed_tables: Vec<(&'a str, &'a str)>,
+    pub created_tables: Vec<&'d TableDefinition<'a>>,
+    /// Every table found in both diagrams, changed or not.
+    pub tables: Vec<TableChanges<'d, 'a>>,
+    /// Foreign keys to drop before anything else, with the old name of their table.
+    pub dropped_foreign_keys: Vec<(&'a str, &'d ForeignKey<'a>)>,
+    /// Foreign keys to add once every table has its new shape, with the new name of their table.
+    ///
+    /// The foreign keys of created tables are left to the dialect, since some declare them inside
+    /// `CREATE TABLE`.
+    pub created_foreign_keys: Vec<(&'a str, &'d ForeignKey<'a>)>,
+}
+
+pub(crate) struct TableChanges<'d, 'a> {
+    pub old: &'d TableDefinition<'a>,
+    pub new: &'d TableDefinition<'a>,
+    /// Old and new names of the renamed columns.
+    pub renamed_columns: Vec<(&'a str, &'a str)>,
+    pub dropped_columns: Vec<&'d ColumnDefinition<'a>>,
+    pub added_columns: Vec<&'d ColumnDefinition<'a>>,
+    pub modified_columns: Vec<ColumnChange<'d, 'a>>,
+    pub primary_key_changed: bool,
+    /// Indexes that were removed or changed; changed indexes are created again.
+    pub dropped_indexes: Vec<&'a Index>,
+    pub created_indexes: Vec<&'a Index>,
+    pub dropped_unique_keys: Vec<&'a CompoundUniqueKey>,
+    pub added_unique_keys: Vec<&'a CompoundUniqueKey>,
+}
+
+pub(crate) struct ColumnChange<'d, 'a> {
+    pub old: &'d ColumnDefinition<'a>,
+    pub new: &'d ColumnDefinition<'a>,
+}
+
+impl ColumnChange<'_, '_> {
+    /// Returns true when the type, nullability, default value or auto increment changed.
+    pub fn definition_changed(&self) -> bool {
+        let (old, new) = (self.old, self.new);
+
+        old.column_type != new.column_type
+            || old.length != new.length
+            || old.decimal != new.decimal
+            || old.args != new.args
+            || old.unsigned != new.unsigned
+            || old.not_null != new.not_null
+            || old.default_value != new.default_value
+            || old.auto_increment != new.auto_increment
+    }
+
+    pub fn unique_key_changed(&self) -> bool {
+        self.old.unique_key != self.new.unique_key
+    }
+
+    fn comment_changed(&self) -> bool {
+        self.old.logical_name != self.new.logical_name
+            || self.old.description != self.new.description
+    }
+}
+
+impl<'d, 'a> Migration<'d, 'a> {
+    pub fn new(
+        changes: &ChangeSet,
+        old: &'d [TableDefinition<'a>],
+        new: &'d [TableDefinition<'a>],
+    ) -> Self {
+        let mut renamed_tables = Vec::new();
+        let mut renamed_columns = Vec::new();
+        let mut removed = Vec::new();
+        let mut added = Vec::new();
+        for change in &changes.changes {
+            match change {
+                Change::TableRenamed { old_name, new_name } => {
+                    if let (Some(old_table), Some(new_table)) =
+                        (find_table(old, old_name), find_table(new, new_name))
+                    {
+                        renamed_tables.push((
+                            old_table.table.physical_name.as_str(),
+                            new_table.table.physical_name.as_str(),
+                        ));
+                    }
+                }
+                Change::ColumnRenamed {
+                    table,
+                    old_name,
+                    new_name,
+                } => renamed_columns.push((table.as_str(), old_name.as_str(), new_name.as_str())),
+                Change::TableRemoved { table } => removed.push(table.as_str()),
+                Change::TableAdded { table } => added.push(table.as_str()),
+                _ => {}
+            }
+        }
+
+        let dropped_tables = super::dependency_order(old)
+            .into_iter()
+            .rev()
+            .filter(|definition| removed.contains(&definition.table.physical_name.as_str()))
+            .collect();
+        let created_tables = new
+            .iter()
+            .filter(|definition| added.contains(&definition.table.physical_name.as_str()))
+            .collect();
+
+        let tables = new
+            .iter()
+            .filter_map(|new_table| {
+                let name = new_table.table.physical_name.as_str();
+                let old_name = renamed_tables
+                    .iter()
+                    .find(|(_, new_name)| *new_name == name)
+                    .map_or(name, |(old_name, _)| *old_name);
+                if added.contains(&name) {
+                    return None;
+                }
+
+                let old_table = find_table(old, old_name)?;
+                let renamed_columns = renamed_columns
+                    .iter()
+                    .filter(|(table, _, _)| *table == name)
+                    .filter_map(|(_, old_name, new_name)| {
+                        Some((
+                            find_column(old_table, old_name)?.physical_name,
+                            find_column(new_table, new_name)?.physical_name,
+                        ))
+                    })
+                    .collect();
+
+                Some(TableChanges::new(old_table, new_table, renamed_columns))
+            })
+            .collect();
+
+        let mut migration = Self {
+            dropped_tables,
+            renamed_tables,
+            created_tables,
+            tables,
+            dropped_foreign_keys: Vec::new(),
+            created_foreign_keys: Vec::new(),
+        };
+        migration.compare_foreign_keys();
+
+        migration
+    }
+
+    /// Returns the name a table of the old diagram has in the new one.
+    fn new_name<'n>(&self, old_name: &'n str) -> &'n str
+    where
+        'a: 'n,
+    {
+        self.renamed_tables
+            .iter()
+            .find(|(renamed, _)| *renamed == old_name)
+            .map_or(old_name, |(_, new_name)| *new_name)
+    }
+
+    fn table(&self, new_name: &str) -> Option<&TableChanges<'d, 'a>> {
+        self.tables
+            .iter()
+            .find(|table| table.new.table.physical_name == new_name)
+    }
+
+    /// Drops the foreign keys that were removed or changed, and those that would keep the columns
+    /// and keys they depend on from being altered, then creates them again at the end.
+    fn compare_foreign_keys(&mut self) {
+        let mut dropped = Vec::new();
+        let mut created = Vec::new();
+
+        for table in &self.tables {
+            let old_table_name = table.old.table.physical_name.as_str();
+            let new_table_name = table.new.table.physical_name.as_str();
+
+            for old_key in &table.old.foreign_keys {
+                let new_key = table
+                    .new
+                    .foreign_keys
+                    .iter()
+                    .find(|new_key| new_key.name == old_key.name);
+                let unchanged = new_key.is_some_and(|new_key| {
+                    self.same_foreign_key(old_key, new_key)
+                        && !self.depends_on_changes(table, new_key)
+                });
+                if !unchanged {
+                    dropped.push((old_table_name, old_key));
+                }
+            }
+
+            for new_key in &table.new.foreign_keys {
+                let unchanged = table.old.foreign_keys.iter().any(|old_key| {
+                    old_key.name == new_key.name && self.same_foreign_key(old_key, new_key)
+                }) && !self.depends_on_changes(table, new_key);
+                if !unchanged {
+                    created.push((new_table_name, new_key));
+                }
+            }
+        }
+
+        self.dropped_foreign_keys = dropped;
+        self.created_foreign_keys = created;
+    }
+
+    fn same_foreign_key(&self, old: &ForeignKey, new: &ForeignKey) -> bool {
+        old.columns == new.columns
+            && self.new_name(old.referenced_table) == new.referenced_table
+            && old.referenced_columns == new.referenced_columns
+            && old.on_delete == new.on_delete
+            && old.on_update == new.on_update
+    }
+
+    /// Databases refuse to alter a column or drop a key a foreign key still relies on.
+    fn depends_on_changes(&self, table: &TableChanges, foreign_key: &ForeignKey) -> bool {
+        table.alters_keys_of(&foreign_key.columns)
+            || self
+                .table(foreign_key.referenced_table)
+                .is_some_and(|parent| parent.alters_keys_of(&foreign_key.referenced_columns))
+    }
+}
+
+impl<'d, 'a> TableChanges<'d, 'a> {
+    fn new(
+        old: &'d TableDefinition<'a>,
+        new: &'d TableDefinition<'a>,
+        renamed_columns: Vec<(&'a str, &'a str)>,
+    ) -> Self {
+        let old_name = |new_name: &'a str| {
+            renamed_columns
+                .iter()
+                .find(|(_, renamed)| *renamed == new_name)
+                .map_or(new_name, |(old_name, _)| *old_name)
+        };
+        let dropped_columns = old
+            .columns
+            .iter()
+            .filter(|column| {
+                find_column(new, column.physical_name).is_none()
+                    && renamed_columns
+                        .iter()
+                        .all(|(renamed, _)| *renamed != column.physical_name)
+            })
+            .collect();
+        let added_columns = new
+            .columns
+            .iter()
+            .filter(|column| find_column(old, old_name(column.physical_name)).is_none())
+            .collect();
+        let modified_columns = new
+            .columns
+            .iter()
+            .filter_map(|new_column| {
+                let change = ColumnChange {
+                    old: find_column(old, old_name(new_column.physical_name))?,
+                    new: new_column,
+                };
+                (change.definition_changed()
+                    || change.unique_key_changed()
+                    || change.comment_changed())
+                .then_some(change)
+            })
+            .collect();
+
+        let primary_key_changed = old.primary_key()
+            != new
+                .primary_key()
+                .into_iter()
+                .map(old_name)
+                .collect::<Vec<_>>()
+            || old.table.primary_key_name != new.table.primary_key_name;
+
+        let old_indexes = old.table.indexes.as_deref().unwrap_or_default();
+        let new_indexes = new.table.indexes.as_deref().unwrap_or_default();
+        let old_keys = unique_keys(old);
+        let new_keys = unique_keys(new);
+
+        Self {
+            old,
+            new,
+            renamed_columns,
+            dropped_columns,
+            added_columns,
+            modified_columns,
+            primary_key_changed,
+            dropped_indexes: changed(old_indexes, new_indexes, |index| &index.name),
+            created_indexes: changed(new_indexes, old_indexes, |index| &index.name),
+            dropped_unique_keys: changed(old_keys, new_keys, |key| &key.name),
+            added_unique_keys: changed(new_keys, old_keys, |key| &key.name),
+        }
+    }
+
+    /// Returns true when any of the columns, or a key or index over them, is altered or dropped.
+    fn alters_keys_of(&self, columns: &[&str]) -> bool {
+        let references = |column_id: &String| columns.contains(&column_id.as_str());
+
+        (self.primary_key_changed
+            && self
+                .old
+                .primary_key()
+                .iter()
+                .any(|column| columns.contains(column)))
+            || self.modified_columns.iter().any(|column| {
+                (column.definition_changed() || column.unique_key_changed())
+                    && columns.contains(&column.new.physical_name)
+            })
+            || self.dropped_indexes.iter().any(|index| {
+                index
+                    .columns
+                    .iter()
+                    .any(|column| references(&column.column_id))
+            })
+            || self.dropped_unique_keys.iter().any(|key| {
+                key.columns
+                    .iter()
+                    .any(|column| references(&column.column_id))
+            })
+    }
+}
+
+/// Returns the items missing from `others` or different from the item of the same name there.
+fn changed<'i, T: PartialEq>(
+    items: &'i [T],
+    others: &[T],
+    name: impl Fn(&T) -> &String,
+) -> Vec<&'i T> {
+    items
+        .iter()
+        .filter(|item| {
+            others
+                .iter()
+                .find(|other| name(other) == name(item))
+                .is_none_or(|other| other != *item)
+        })
+        .collect()
+}
+
+fn unique_keys<'a>(definition: &TableDefinition<'a>) -> &'a [CompoundUniqueKey] {
+    definition
+        .table
+        .compound_unique_key_list
+        .compound_unique_keys
+        .as_deref()
+        .unwrap_or_default()
+}
@@ -0,0 +1,521 @@
+use crate::column_type::ColumnType;
+use crate::diff;
+use crate::dtos::diagram::Diagram;
+use crate::dtos::diagram::diagram_walkers::tables::indexes::Index;
+
+use super::migration::{Migration, TableChanges};
+use super::{
+    ColumnDefinition, Ddl, ForeignKey, Options, TableDefinition, Warning, WarningKind, create_view,
+    default_literal, identifiers, line_comment, referential_action,
+};
+
+/// Generates MySQL DDL for every table and view of the diagram, as configured by its export
+/// settings.
+///
+/// Tables come first in diagram order, followed by their indexes, the foreign keys and finally
+/// the views, so the script does not depend on the order in which the tables were declared.
+pub fn generate(diagram: &Diagram) -> Ddl {
+    let options = Options::new(diagram);
+    let definitions = super::table_definitions(diagram);
+    let mut warnings = Vec::new();
+    let views = super::view_definitions(diagram, &mut warnings);
+    let mut statements = Vec::new();
+
+    if options.drop_view {
+        statements.extend(views.iter().rev().map(|definition| {
+            format!(
+                "DROP VIEW IF EXISTS {}",
+                identifier(&definition.view.physical_name)
+            )
+        }));
+    }
+    if options.drop_index {
+        for definition in &definitions {
+            for index in definition.table.indexes.as_deref().unwrap_or_default() {
+                statements.push(format!(
+                    "DROP INDEX {} ON {}",
+                    identifier(&index.name),
+                    identifier(&definition.table.physical_name)
+                ));
+            }
+        }
+    }
+    if options.drop_table {
+        statements.extend(super::dependency_order(&definitions).into_iter().rev().map(
+            |definition| {
+                format!(
+                    "DROP TABLE IF EXISTS {}",
+                    identifier(&definition.table.physical_name)
+                )
+            },
+        ));
+    }
+    if options.create_table {
+        for definition in &definitions {
+            statements.push(create_table(definition, &options, &mut warnings));
+        }
+    }
+    if options.create_index {
+        for definition in &definitions {
+            for index in definition.table.indexes.as_deref().unwrap_or_default() {
+                statements.push(create_index(
+                    &definition.table.physical_name,
+                    index,
+                    &options,
+                ));
+            }
+        }
+    }
+    if options.create_foreign_key {
+        for definition in &definitions {
+            for foreign_key in &definition.foreign_keys {
+                statements.push(add_foreign_key(
+                    &definition.table.physical_name,
+                    foreign_key,
+                ));
+            }
+        }
+    }
+
+    if options.create_view {
+        for definition in &views {
+            statements.push(create_view(definition, identifier));
+        }
+    }
+
+    let sql = statements
+        .into_iter()
+        .map(|statement| format!("{statement};\n"))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    Ddl { sql, warnings }
+}
+
+/// Generates the MySQL statements that turn the tables of the `old` diagram into those of `new`.
+///
+/// Foreign keys that were removed, changed or stand in the way of a change are dropped first and
+/// added back last. Indexes, foreign keys and comments follow the export settings of the new
+/// diagram, so they are left alone when it does not create them.
+pub fn migrate(old: &Diagram, new: &Diagram) -> Ddl {
+    let options = Options::new(new);
+    let old_definitions = super::table_definitions(old);
+    let new_definitions = super::table_definitions(new);
+    let migration = Migration::new(&diff::diff(old, new), &old_definitions, &new_definitions);
+    let mut warnings = Vec::new();
+    let mut statements = Vec::new();
+
+    if options.create_foreign_key {
+        for (table_name, foreign_key) in &migration.dropped_foreign_keys {
+            statements.push(format!(
+                "ALTER TABLE {} DROP FOREIGN KEY {}",
+                identifier(table_name),
+                identifier(foreign_key.name)
+            ));
+        }
+    }
+    for table in &migration.tables {
+        let table_name = &table.old.table.physical_name;
+        if options.create_index {
+            for index in &table.dropped_indexes {
+                statements.push(format!(
+                    "DROP INDEX {} ON {}",
+                    identifier(&index.name),
+                    identifier(table_name)
+                ));
+            }
+        }
+        for key in &table.dropped_unique_keys {
+            statements.push(format!(
+                "ALTER TABLE {} DROP INDEX {}",
+                identifier(table_name),
+                identifier(&key.name)
+            ));
+        }
+    }
+    for definition in &migration.dropped_tables {
+        statements.push(format!(
+            "DROP TABLE {}",
+            identifier(&definition.table.physical_name)
+        ));
+    }
+    for (old_name, new_name) in &migration.renamed_tables {
+        statements.push(format!(
+            "RENAME TABLE {} TO {}",
+            identifier(old_name),
+            identifier(new_name)
+        ));
+    }
+    for definition in &migration.created_tables {
+        statements.push(create_table(definition, &options, &mut warnings));
+        if options.create_index {
+            for index in definition.table.indexes.as_deref().unwrap_or_default() {
+                statements.push(create_index(
+                    &definition.table.physical_name,
+                    index,
+                    &options,
+                ));
+            }
+        }
+    }
+    for table in &migration.tables {
+        statements.extend(alter_table(table, &options, &mut warnings));
+    }
+    for table in &migration.tables {
+        let table_name = &table.new.table.physical_name;
+        for key in &table.added_unique_keys {
+            let columns = key
+                .columns
+                .iter()
+                .map(|column| column.column_id.as_str())
+                .collect::<Vec<_>>();
+            statements.push(format!(
+                "ALTER TABLE {} ADD CONSTRAINT {} UNIQUE ({})",
+                identifier(table_name),
+                identifier(&key.name),
+                identifiers(&columns, identifier)
+            ));
+        }
+        if options.create_index {
+            for index in &table.created_indexes {
+                statements.push(create_index(table_name, index, &options));
+            }
+        }
+    }
+    if options.create_foreign_key {
+        let created_tables = migration.created_tables.iter().flat_map(|definition| {
+            definition
+                .foreign_keys
+                .iter()
+                .map(|foreign_key| (definition.table.physical_name.as_str(), foreign_key))
+        });
+        for (table_name, foreign_key) in created_tables.chain(migration.created_foreign_keys) {
+            statements.push(add_foreign_key(table_name, foreign_key));
+        }
+    }
+
+    let sql = statements
+        .into_iter()
+        .map(|statement| format!("{statement};\n"))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    Ddl { sql, warnings }
+}
+
+fn create_table(
+    definition: &TableDefinition,
+    options: &Options,
+    warnings: &mut Vec<Warning>,
+) -> String {
+    let table = definition.table;
+    let mut lines = definition
+        .columns
+        .iter()
+        .map(|column| {
+            let line = column_definition(&table.physical_name, column, options, warnings);
+            match column.comment(options) {
+                Some(comment) if options.inline_column_comment => {
+                    format!("{}\n  {line}", line_comment(&comment))
+                }
+                _ => line,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    lines.extend(primary_key(definition));
+
+    for key in table
+        .compound_unique_key_list
+        .compound_unique_keys
+        .as_deref()
+        .unwrap_or_default()
+    {
+        let columns = key
+            .columns
+            .iter()
+            .map(|column| column.column_id.as_str())
+            .collect::<Vec<_>>();
+        lines.push(format!(
+            "CONSTRAINT {} UNIQUE ({})",
+            identifier(&key.name),
+            identifiers(&columns, identifier)
+        ));
+    }
+
+    if let Some(constraint) = table.table_constraint.as_deref().map(str::trim)
+        && !constraint.is_empty()
+    {
+        lines.push(constraint.to_string());
+    }
+
+    let mut statement = String::new();
+    if options.inline_table_comment
+        && let Some(comment) = definition.comment(options)
+    {
+        statement.push_str(&line_comment(&comment));
+        statement.push('\n');
+    }
+    statement.push_str(&format!(
+        "CREATE TABLE {} (\n",
+        identifier(&table.physical_name)
+    ));
+    for (index, line) in lines.iter().enumerate() {
+        let separator = if index + 1 < lines.len() { "," } else { "" };
+        statement.push_str(&format!("  {line}{separator}\n"));
+    }
+    statement.push(')');
+    if let Some(option) = table.option.as_deref().map(str::trim)
+        && !option.is_empty()
+    {
+        statement.push(' ');
+        statement.push_str(option);
+    }
+    if options.create_comment
+        && let Some(comment) = definition.comment(options)
+    {
+        statement.push_str(&format!(" COMMENT = {}", string_literal(&comment)));
+    }
+
+    statement
+}
+
+fn primary_key(definition: &TableDefinition) -> Option<String> {
+    let primary_key = definition.primary_key();
+    if primary_key.is_empty() {
+        return None;
+    }
+
+    let constraint = match &definition.table.primary_key_name {
+        Some(name) if !name.is_empty() => format!("CONSTRAINT {} ", identifier(name)),
+        _ => String::new(),
+    };
+    Some(format!(
+        "{constraint}PRIMARY KEY ({})",
+        identifiers(&primary_key, identifier)
+    ))
+}
+
+fn alter_table(
+    table: &TableChanges,
+    options: &Options,
+    warnings: &mut Vec<Warning>,
+) -> Vec<String> {
+    let table_name = &table.new.table.physical_name;
+    let alter = |action: String| format!("ALTER TABLE {} {action}", identifier(table_name));
+    let mut statements = Vec::new();
+
+    if table.primary_key_changed && !table.old.primary_key().is_empty() {
+        statements.push(alter("DROP PRIMARY KEY".to_string()));
+    }
+    for (old_name, new_name) in &table.renamed_columns {
+        statements.push(alter(format!(
+            "RENAME COLUMN {} TO {}",
+            identifier(old_name),
+            identifier(new_name)
+        )));
+    }
+    for column in &table.dropped_columns {
+        statements.push(alter(format!(
+            "DROP COLUMN {}",
+            identifier(column.physical_name)
+        )));
+    }
+    for column in &table.added_columns {
+        statements.push(alter(format!(
+            "ADD COLUMN {}",
+            column_definition(table_name, column, options, warnings)
+        )));
+    }
+    for change in &table.modified_columns {
+        let comment_changed =
+            options.create_comment && change.old.comment(options) != change.new.comment(options);
+        if change.definition_changed() || comment_changed {
+            // `UNIQUE` would add another unique index to a column that already has one.
+            let column = ColumnDefinition {
+                unique_key: false,
+                ..*change.new
+            };
+            statements.push(alter(format!(
+                "MODIFY COLUMN {}",
+                column_definition(table_name, &column, options, warnings)
+            )));
+        }
+        if change.unique_key_changed() {
+            let column_name = identifier(change.new.physical_name);
+            statements.push(alter(if change.new.unique_key {
+                format!("ADD UNIQUE ({column_name})")
+            } else {
+                // MySQL names the index of an unnamed unique key after its first column.
+                format!("DROP INDEX {column_name}")
+            }));
+        }
+    }
+    if table.primary_key_changed
+        && let Some(primary_key) = primary_key(table.new)
+    {
+        statements.push(alter(format!("ADD {primary_key}")));
+    }
+    if options.create_comment {
+        let comment = table.new.comment(options);
+        if table.old.comment(options) != comment {
+            statements.push(alter(format!(
+                "COMMENT = {}",
+                string_literal(&comment.unwrap_or_default())
+            )));
+        }
+    }
+
+    statements
+}
+
+fn column_definition(
+    table_name: &str,
+    column: &ColumnDefinition,
+    options: &Options,
+    warnings: &mut Vec<Warning>,
+) -> String {
+    let mut definition = identifier(column.physical_name);
+
+    match column.column_type {
+        Some(column_type) => {
+            definition.push(' ');
+            definition.push_str(&type_name(column_type, column));
+        }
+        None => warnings.push(Warning::new(
+            table_name,
+            Some(column.physical_name),
+            WarningKind::MissingColumnType,
+        )),
+    }
+    if column.unsigned {
+        definition.push_str(" UNSIGNED");
+    }
+    if column.not_null || column.primary_key {
+        definition.push_str(" NOT NULL");
+    }
+    if let Some(default_value) = column.default_value {
+        definition.push_str(" DEFAULT ");
+        definition.push_str(&default_literal(default_value, string_literal));
+    }
+    if column.auto_increment {
+        definition.push_str(" AUTO_INCREMENT");
+    }
+    if column.unique_key {
+        definition.push_str(" UNIQUE");
+    }
+    if options.create_comment
+        && let Some(comment) = column.comment(options)
+    {
+        definition.push_str(" COMMENT ");
+        definition.push_str(&string_literal(&comment));
+    }
+
+    definition
+}
+
+fn type_name(column_type: ColumnType, column: &ColumnDefinition) -> String {
+    let name = match column_type {
+        ColumnType::Char | ColumnType::CharN => "char",
+        ColumnType::Int | ColumnType::IntN => "int",
+        ColumnType::TinyIntN => "tinyint",
+        ColumnType::SmallIntN => "smallint",
+        ColumnType::MediumIntN => "mediumint",
+        ColumnType::BigIntN => "bigint",
+        ColumnType::Binary | ColumnType::BinaryN => "binary",
+        ColumnType::VarCharN => "varchar",
+        ColumnType::VarBinaryN => "varbinary",
+        ColumnType::BitN => "bit",
+        ColumnType::DecimalP | ColumnType::DecimalPS => "decimal",
+        ColumnType::DoubleMD => "double",
+        ColumnType::FloatMD | ColumnType::FloatP => "float",
+        ColumnType::NumericP | ColumnType::NumericPS => "numeric",
+        ColumnType::RealMD => "real",
+        ColumnType::Year2 => "year(2)",
+        ColumnType::Year4 => "year(4)",
+        ColumnType::Enum | ColumnType::Set => {
+            let args = column.args.unwrap_or_default();
+            return format!("{}({args})", column_type.as_str());
+        }
+        _ => column_type.as_str(),
+    };
+
+    match (column.length, column.decimal) {
+        (Some(length), Some(decimal)) if column_type.supports_decimal() => {
+            format!("{name}({length},{decimal})")
+        }
+        (Some(length), _) if column_type.supports_length() => format!("{name}({length})"),
+        _ => name.to_string(),
+    }
+}
+
+fn create_index(table_name: &str, index: &Index, options: &Options) -> String {
+    let kind = if index.full_text == Some(true) {
+        "FULLTEXT "
+    } else if index.non_unique == Some(false) {
+        "UNIQUE "
+    } else {
+        ""
+    };
+    let columns = index
+        .columns
+        .iter()
+        .map(|column| {
+            if column.desc == Some(true) {
+                format!("{} DESC", identifier(&column.column_id))
+            } else {
+                identifier(&column.column_id)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let mut statement = format!(
+        "CREATE {kind}INDEX {} ON {} ({columns})",
+        identifier(&index.name),
+        identifier(table_name)
+    );
+    // Full-text indexes have no index type of their own.
+    if index.full_text != Some(true) && !index.index_type.trim().is_empty() {
+        statement.push_str(" USING ");
+        statement.push_str(index.index_type.trim());
+    }
+    if options.create_comment
+        && let Some(description) = index.description.as_deref()
+        && !description.is_empty()
+    {
+        statement.push_str(" COMMENT ");
+        statement.push_str(&string_literal(description));
+    }
+
+    statement
+}
+
+fn add_foreign_key(table_name: &str, foreign_key: &ForeignKey) -> String {
+    let mut statement = format!(
+        "ALTER TABLE {} ADD CONSTRAINT {} FOREIGN KEY ({}) REFERENCES {} ({})",
+        identifier(table_name),
+        identifier(foreign_key.name),
+        identifiers(&foreign_key.columns, identifier),
+        identifier(foreign_key.referenced_table),
+        identifiers(&foreign_key.referenced_columns, identifier)
+    );
+    if let Some(action) = foreign_key.on_delete {
+        statement.push_str(" ON DELETE ");
+        statement.push_str(referential_action(action));
+    }
+    if let Some(action) = foreign_key.on_update {
+        statement.push_str(" ON UPDATE ");
+        statement.push_str(referential_action(action));
+    }
+
+    statement
+}
+
+fn identifier(name: &str) -> String {
+    format!("`{}`", name.replace('`', "``"))
+}
+
+fn string_literal(value: &str) -> String {
+    format!("'{}'", value.replace('\\', "\\\\").replace('\'', "''"))
+}
@@ -0,0 +1,788 @@
+use crate::column_type::ColumnType;
+use crate::diff;
+use crate::dtos::diagram::Diagram;
+use crate::dtos::diagram::diagram_walkers::tables::indexes::Index;
+
+use super::migration::{ColumnChange, Migration, TableChanges};
+use super::{
+    ColumnDefinition, Ddl, ForeignKey, Options, TableDefinition, ViewDefinition, Warning,
+    WarningKind, create_view, default_literal, identifier, identifiers, is_table_constraint,
+    line_comment, referential_action, string_literal,
+};
+
+/// Generates PostgreSQL DDL for every table and view of the diagram, as configured by its export
+/// settings.
+///
+/// MySQL-only column types are mapped to their closest PostgreSQL equivalent, and whatever
+/// cannot be expressed (`unsigned`, `enum` values, ...) is left out of the script and reported as
+/// a warning. Columns that need an extension, such as PostGIS for `geometry`, are reported too.
+pub fn generate(diagram: &Diagram) -> Ddl {
+    let options = Options::new(diagram);
+    let definitions = super::table_definitions(diagram);
+    let mut warnings = Vec::new();
+    let views = super::view_definitions(diagram, &mut warnings);
+    let mut statements = Vec::new();
+
+    if options.drop_view {
+        statements.extend(views.iter().rev().map(|definition| {
+            format!(
+                "DROP VIEW IF EXISTS {}",
+                identifier(&definition.view.physical_name)
+            )
+        }));
+    }
+    if options.drop_index {
+        for definition in &definitions {
+            for index in definition.table.indexes.as_deref().unwrap_or_default() {
+                statements.push(format!("DROP INDEX IF EXISTS {}", identifier(&index.name)));
+            }
+        }
+    }
+    if options.drop_table {
+        statements.extend(super::dependency_order(&definitions).into_iter().rev().map(
+            |definition| {
+                format!(
+                    "DROP TABLE IF EXISTS {}",
+                    identifier(&definition.table.physical_name)
+                )
+            },
+        ));
+    }
+    if options.create_table {
+        for definition in &definitions {
+            statements.push(create_table(definition, &options, &mut warnings));
+            if options.create_comment {
+                statements.extend(comments(definition, &options));
+            }
+        }
+    }
+    if options.create_index {
+        for definition in &definitions {
+            for index in definition.table.indexes.as_deref().unwrap_or_default() {
+                statements.extend(create_index(
+                    &definition.table.physical_name,
+                    index,
+                    &options,
+                    &mut warnings,
+                ));
+            }
+        }
+    }
+    if options.create_foreign_key {
+        for definition in &definitions {
+            for foreign_key in &definition.foreign_keys {
+                statements.push(add_foreign_key(
+                    &definition.table.physical_name,
+                    foreign_key,
+                ));
+            }
+        }
+    }
+
+    if options.create_view {
+        for definition in &views {
+            statements.push(create_view(definition, identifier));
+            if options.create_comment {
+                statements.extend(view_comment(definition, &options));
+            }
+        }
+    }
+
+    let sql = statements
+        .into_iter()
+        .map(|statement| format!("{statement};\n"))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    Ddl { sql, warnings }
+}
+
+/// Generates the PostgreSQL statements that turn the tables of the `old` diagram into those of
+/// `new`.
+///
+/// Foreign keys that were removed, changed or stand in the way of a change are dropped first and
+/// added back last. Column changes are written as one `ALTER COLUMN` per changed property.
+/// Indexes, foreign keys and comments follow the export settings of the new diagram.
+pub fn migrate(old: &Diagram, new: &Diagram) -> Ddl {
+    let options = Options::new(new);
+    let old_definitions = super::table_definitions(old);
+    let new_definitions = super::table_definitions(new);
+    let migration = Migration::new(&diff::diff(old, new), &old_definitions, &new_definitions);
+    let mut warnings = Vec::new();
+    let mut statements = Vec::new();
+
+    if options.create_foreign_key {
+        for (table_name, foreign_key) in &migration.dropped_foreign_keys {
+            statements.push(format!(
+                "ALTER TABLE {} DROP CONSTRAINT {}",
+                identifier(table_name),
+                identifier(foreign_key.name)
+            ));
+        }
+    }
+    for table in &migration.tables {
+        if options.create_index {
+            for index in &table.dropped_indexes {
+                statements.push(format!("DROP INDEX {}", identifier(&index.name)));
+            }
+        }
+        for key in &table.dropped_unique_keys {
+            statements.push(format!(
+                "ALTER TABLE {} DROP CONSTRAINT {}",
+                identifier(&table.old.table.physical_name),
+                identifier(&key.name)
+            ));
+        }
+    }
+    for definition in &migration.dropped_tables {
+        statements.push(format!(
+            "DROP TABLE {}",
+            identifier(&definition.table.physical_name)
+        ));
+    }
+    for (old_name, new_name) in &migration.renamed_tables {
+        statements.push(format!(
+            "ALTER TABLE {} RENAME TO {}",
+            identifier(old_name),
+            identifier(new_name)
+        ));
+    }
+    for definition in &migration.created_tables {
+        statements.push(create_table(definition, &options, &mut warnings));
+        if options.create_comment {
+            statements.extend(comments(definition, &options));
+        }
+        if options.create_index {
+            for index in definition.table.indexes.as_deref().unwrap_or_default() {
+                statements.extend(create_index(
+                    &definition.table.physical_name,
+                    index,
+                    &options,
+                    &mut warnings,
+                ));
+            }
+        }
+    }
+    for table in &migration.tables {
+        statements.extend(alter_table(table, &options, &mut warnings));
+    }
+    for table in &migration.tables {
+        let table_name = &table.new.table.physical_name;
+        for key in &table.added_unique_keys {
+            let columns = key
+                .columns
+                .iter()
+                .map(|column| column.column_id.as_str())
+                .collect::<Vec<_>>();
+            statements.push(format!(
+                "ALTER TABLE {} ADD CONSTRAINT {} UNIQUE ({})",
+                identifier(table_name),
+                identifier(&key.name),
+                identifiers(&columns, identifier)
+            ));
+        }
+        if options.create_index {
+            for index in &table.created_indexes {
+                statements.extend(create_index(table_name, index, &options, &mut warnings));
+            }
+        }
+    }
+    if options.create_foreign_key {
+        let created_tables = migration.created_tables.iter().flat_map(|definition| {
+            definition
+                .foreign_keys
+                .iter()
+                .map(|foreign_key| (definition.table.physical_name.as_str(), foreign_key))
+        });
+        for (table_name, foreign_key) in created_tables.chain(migration.created_foreign_keys) {
+            statements.push(add_foreign_key(table_name, foreign_key));
+        }
+    }
+
+    let sql = statements
+        .into_iter()
+        .map(|statement| format!("{statement};\n"))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    Ddl { sql, warnings }
+}
+
+fn create_table(
+    definition: &TableDefinition,
+    options: &Options,
+    warnings: &mut Vec<Warning>,
+) -> String {
+    let table = definition.table;
+    let mut lines = definition
+        .columns
+        .iter()
+        .map(|column| {
+            let line = column_definition(&table.physical_name, column, warnings);
+            match column.comment(options) {
+                Some(comment) if options.inline_column_comment => {
+                    format!("{}\n  {line}", line_comment(&comment))
+                }
+                _ => line,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    lines.extend(primary_key(definition));
+
+    for key in table
+        .compound_unique_key_list
+        .compound_unique_keys
+        .as_deref()
+        .unwrap_or_default()
+    {
+        let columns = key
+            .columns
+            .iter()
+            .map(|column| column.column_id.as_str())
+            .collect::<Vec<_>>();
+        lines.push(format!(
+            "CONSTRAINT {} UNIQUE ({})",
+            identifier(&key.name),
+            identifiers(&columns, identifier)
+        ));
+    }
+
+    if let Some(constraint) = table.table_constraint.as_deref().map(str::trim)
+        && !constraint.is_empty()
+    {
+        if is_table_constraint(constraint) {
+            lines.push(constraint.to_string());
+        } else {
+            warnings.push(Warning::new(
+                &table.physical_name,
+                None,
+                WarningKind::TableConstraintIgnored {
+                    constraint: constraint.to_string(),
+                },
+            ));
+        }
+    }
+
+    let mut statement = String::new();
+    if options.inline_table_comment
+        && let Some(comment) = definition.comment(options)
+    {
+        statement.push_str(&line_comment(&comment));
+        statement.push('\n');
+    }
+    statement.push_str(&format!(
+        "CREATE TABLE {} (\n",
+        identifier(&table.physical_name)
+    ));
+    for (index, line) in lines.iter().enumerate() {
+        let separator = if index + 1 < lines.len() { "," } else { "" };
+        statement.push_str(&format!("  {line}{separator}\n"));
+    }
+    statement.push(')');
+
+    if let Some(option) = table.option.as_deref().map(str::trim)
+        && !option.is_empty()
+    {
+        if is_table_option(option) {
+            statement.push(' ');
+            statement.push_str(option);
+        } else {
+            warnings.push(Warning::new(
+                &table.physical_name,
+                None,
+                WarningKind::TableOptionIgnored {
+                    option: option.to_string(),
+                },
+            ));
+        }
+    }
+
+    statement
+}
+
+/// Table options are written for one database; only the clauses PostgreSQL accepts after the
+/// column list are kept.
+fn is_table_option(option: &str) -> bool {
+    let option = option.to_ascii_uppercase();
+
+    [
+        "INHERITS",
+        "PARTITION BY",
+        "USING",
+        "WITH ",
+        "WITH(",
+        "WITHOUT OIDS",
+        "ON COMMIT",
+        "TABLESPACE",
+    ]
+    .iter()
+    .any(|keyword| option.starts_with(keyword))
+}
+
+fn primary_key(definition: &TableDefinition) -> Option<String> {
+    let primary_key = definition.primary_key();
+    if primary_key.is_empty() {
+        return None;
+    }
+
+    let constraint = match &definition.table.primary_key_name {
+        Some(name) if !name.is_empty() => format!("CONSTRAINT {} ", identifier(name)),
+        _ => String::new(),
+    };
+    Some(format!(
+        "{constraint}PRIMARY KEY ({})",
+        identifiers(&primary_key, identifier)
+    ))
+}
+
+fn alter_table(
+    table: &TableChanges,
+    options: &Options,
+    warnings: &mut Vec<Warning>,
+) -> Vec<String> {
+    let table_name = &table.new.table.physical_name;
+    let alter = |action: String| format!("ALTER TABLE {} {action}", identifier(table_name));
+    let mut statements = Vec::new();
+
+    if table.primary_key_changed && !table.old.primary_key().is_empty() {
+        // An unnamed primary key is named after the table it was created in.
+        let name = match &table.old.table.primary_key_name {
+            Some(name) if !name.is_empty() => name.clone(),
+            _ => format!("{}_pkey", table.old.table.physical_name),
+        };
+        statements.push(alter(format!("DROP CONSTRAINT {}", identifier(&name))));
+    }
+    for (old_name, new_name) in &table.renamed_columns {
+        statements.push(alter(format!(
+            "RENAME COLUMN {} TO {}",
+            identifier(old_name),
+            identifier(new_name)
+        )));
+    }
+    for column in &table.dropped_columns {
+        statements.push(alter(format!(
+            "DROP COLUMN {}",
+            identifier(column.physical_name)
+        )));
+    }
+    for column in &table.added_columns {
+        statements.push(alter(format!(
+            "ADD COLUMN {}",
+            column_definition(table_name, column, warnings)
+        )));
+        if options.create_comment
+            && let Some(comment) = column.comment(options)
+        {
+            statements.push(format!(
+                "COMMENT ON COLUMN {}.{} IS {}",
+                identifier(table_name),
+                identifier(column.physical_name),
+                string_literal(&comment)
+            ));
+        }
+    }
+    for change in &table.modified_columns {
+        statements.extend(alter_column(table, change, options, warnings));
+    }
+    if table.primary_key_changed
+        && let Some(primary_key) = primary_key(table.new)
+    {
+        statements.push(alter(format!("ADD {primary_key}")));
+    }
+    if options.create_comment {
+        let comment = table.new.comment(options);
+        if table.old.comment(options) != comment {
+            statements.push(format!(
+                "COMMENT ON TABLE {} IS {}",
+                identifier(table_name),
+                comment
+                    .as_deref()
+                    .map_or("NULL".to_string(), string_literal)
+            ));
+        }
+    }
+
+    statements
+}
+
+fn alter_column(
+    table: &TableChanges,
+    change: &ColumnChange,
+    options: &Options,
+    warnings: &mut Vec<Warning>,
+) -> Vec<String> {
+    let table_name = &table.new.table.physical_name;
+    let (old, new) = (change.old, change.new);
+    let alter = |action: &str| {
+        format!(
+            "ALTER TABLE {} ALTER COLUMN {} {action}",
+            identifier(table_name),
+            identifier(new.physical_name)
+        )
+    };
+    let mut warn = |kind| warnings.push(Warning::new(table_name, Some(new.physical_name), kind));
+    let mut statements = Vec::new();
+
+    let mut type_warnings = Vec::new();
+    let old_type = old
+        .column_type
+        .map(|column_type| type_name(column_type, old, &mut |_| {}));
+    let new_type = new
+        .column_type
+        .map(|column_type| type_name(column_type, new, &mut |kind| type_warnings.push(kind)));
+    if new_type != old_type {
+        match new_type {
+            Some(new_type) => statements.push(alter(&format!("TYPE {new_type}"))),
+            None => warn(WarningKind::MissingColumnType),
+        }
+        type_warnings.into_iter().for_each(&mut warn);
+    }
+    if new.unsigned && !old.unsigned {
+        warn(WarningKind::UnsignedIgnored);
+    }
+
+    let not_null = new.not_null || new.primary_key;
+    if not_null != (old.not_null || old.primary_key) {
+        statements.push(alter(if not_null {
+            "SET NOT NULL"
+        } else {
+            "DROP NOT NULL"
+        }));
+    }
+
+    let old_identity = old.auto_increment && supports_identity(old.column_type);
+    let new_identity = new.auto_increment && supports_identity(new.column_type);
+    if new.auto_increment && !new_identity && !old.auto_increment {
+        warn(WarningKind::AutoIncrementUnsupported {
+            column_type: new.column_type,
+        });
+    }
+    if old_identity && !new_identity {
+        statements.push(alter("DROP IDENTITY"));
+    }
+    let old_default = old.default_value.filter(|_| !old_identity);
+    let new_default = new.default_value.filter(|_| !new_identity);
+    if new_identity
+        && let Some(default_value) = new.default_value
+        && new.default_value != old.default_value
+    {
+        warn(WarningKind::DefaultIgnored {
+            default_value: default_value.to_string(),
+        });
+    }
+    if new_default != old_default {
+        statements.push(match new_default {
+            Some(default_value) => alter(&format!(
+                "SET DEFAULT {}",
+                default_literal(default_value, string_literal)
+            )),
+            None => alter("DROP DEFAULT"),
+        });
+    }
+    if new_identity && !old_identity {
+        statements.push(alter("ADD GENERATED BY DEFAULT AS IDENTITY"));
+    }
+
+    if change.unique_key_changed() {
+        statements.push(if new.unique_key {
+            format!(
+                "ALTER TABLE {} ADD UNIQUE ({})",
+                identifier(table_name),
+                identifier(new.physical_name)
+            )
+        } else {
+            // An unnamed unique constraint is named after the table it was created in and its
+            // column.
+            format!(
+                "ALTER TABLE {} DROP CONSTRAINT {}",
+                identifier(table_name),
+                identifier(&format!(
+                    "{}_{}_key",
+                    table.old.table.physical_name, old.physical_name
+                ))
+            )
+        });
+    }
+
+    if options.create_comment {
+        let comment = new.comment(options);
+        if old.comment(options) != comment {
+            statements.push(format!(
+                "COMMENT ON COLUMN {}.{} IS {}",
+                identifier(table_name),
+                identifier(new.physical_name),
+                comment
+                    .as_deref()
+                    .map_or("NULL".to_string(), string_literal)
+            ));
+        }
+    }
+
+    statements
+}
+
+fn column_definition(
+    table_name: &str,
+    column: &ColumnDefinition,
+    warnings: &mut Vec<Warning>,
+) -> String {
+    let mut warn = |kind| warnings.push(Warning::new(table_name, Some(column.physical_name), kind));
+    let mut definition = identifier(column.physical_name);
+
+    match column.column_type {
+        Some(column_type) => {
+            definition.push(' ');
+            definition.push_str(&type_name(column_type, column, &mut warn));
+        }
+        None => warn(WarningKind::MissingColumnType),
+    }
+    if column.unsigned {
+        warn(WarningKind::UnsignedIgnored);
+    }
+    if column.not_null || column.primary_key {
+        definition.push_str(" NOT NULL");
+    }
+
+    let identity = column.auto_increment && supports_identity(column.column_type);
+    if column.auto_increment && !identity {
+        warn(WarningKind::AutoIncrementUnsupported {
+            column_type: column.column_type,
+        });
+    }
+    if let Some(default_value) = column.default_value {
+        if identity {
+            warn(WarningKind::DefaultIgnored {
+                default_value: default_value.to_string(),
+            });
+        } else {
+            definition.push_str(" DEFAULT ");
+            definition.push_str(&default_literal(default_value, string_literal));
+        }
+    }
+    if identity {
+        definition.push_str(" GENERATED BY DEFAULT AS IDENTITY");
+    }
+    if column.unique_key {
+        definition.push_str(" UNIQUE");
+    }
+
+    definition
+}
+
+fn type_name(
+    column_type: ColumnType,
+    column: &ColumnDefinition,
+    warn: &mut impl FnMut(WarningKind),
+) -> String {
+    let name = match column_type {
+        ColumnType::Char | ColumnType::CharN => "char",
+        ColumnType::VarCharN => "varchar",
+        ColumnType::Text | ColumnType::TinyText | ColumnType::MediumText | ColumnType::LongText => {
+            "text"
+        }
+        ColumnType::TinyInt
+        | ColumnType::TinyIntN
+        | ColumnType::SmallInt
+        | ColumnType::SmallIntN
+        | ColumnType::Year2
+        | ColumnType::Year4 => "smallint",
+        ColumnType::Int | ColumnType::IntN | ColumnType::MediumInt | ColumnType::MediumIntN => {
+            "integer"
+        }
+        ColumnType::BigInt | ColumnType::BigIntN => "bigint",
+        ColumnType::Date => "date",
+        ColumnType::Datetime | ColumnType::Timestamp => "timestamp",
+        ColumnType::Time => "time",
+        ColumnType::Boolean => "boolean",
+        ColumnType::Binary
+        | ColumnType::BinaryN
+        | ColumnType::VarBinaryN
+        | ColumnType::Blob
+        | ColumnType::TinyBlob
+        | ColumnType::MediumBlob
+        | ColumnType::LongBlob => "bytea",
+        ColumnType::BitN => "bit",
+        ColumnType::Decimal | ColumnType::DecimalP | ColumnType::DecimalPS => "decimal",
+        ColumnType::Numeric | ColumnType::NumericP | ColumnType::NumericPS => "numeric",
+        ColumnType::Double | ColumnType::DoubleMD => "double precision",
+        ColumnType::Float | ColumnType::FloatMD | ColumnType::Real | ColumnType::RealMD => "real",
+        ColumnType::FloatP => "float",
+        ColumnType::Enum | ColumnType::Set => {
+            if let Some(args) = column.args.filter(|args| !args.trim().is_empty()) {
+                warn(WarningKind::ArgsIgnored {
+                    column_type,
+                    args: args.to_string(),
+                });
+            }
+            "text"
+        }
+        ColumnType::Geometry => {
+            warn(WarningKind::ExtensionRequired {
+                extension: "PostGIS".to_string(),
+            });
+            "geometry"
+        }
+        ColumnType::Json => "json",
+    };
+
+    // Display widths (`int(11)`) only matter to MySQL; precisions have a PostgreSQL equivalent.
+    let keeps_precision = matches!(
+        column_type,
+        ColumnType::CharN
+            | ColumnType::VarCharN
+            | ColumnType::BitN
+            | ColumnType::DecimalP
+            | ColumnType::DecimalPS
+            | ColumnType::NumericP
+            | ColumnType::NumericPS
+            | ColumnType::FloatP
+    );
+    if !keeps_precision {
+        if matches!(
+            column_type,
+            ColumnType::DoubleMD | ColumnType::FloatMD | ColumnType::RealMD
+        ) && column.length.is_some()
+        {
+            warn(WarningKind::PrecisionIgnored { column_type });
+        }
+        return name.to_string();
+    }
+
+    match (column.length, column.decimal) {
+        (Some(length), Some(decimal)) if column_type.supports_decimal() => {
+            format!("{name}({length},{decimal})")
+        }
+        (Some(length), _) => format!("{name}({length})"),
+        _ => name.to_string(),
+    }
+}
+
+fn supports_identity(column_type: Option<ColumnType>) -> bool {
+    matches!(
+        column_type,
+        Some(
+            ColumnType::TinyInt
+                | ColumnType::TinyIntN
+                | ColumnType::SmallInt
+                | ColumnType::SmallIntN
+                | ColumnType::Int
+                | ColumnType::IntN
+                | ColumnType::MediumInt
+                | ColumnType::MediumIntN
+                | ColumnType::BigInt
+                | ColumnType::BigIntN
+        )
+    )
+}
+
+fn comments(definition: &TableDefinition, options: &Options) -> Vec<String> {
+    let table_name = identifier(&definition.table.physical_name);
+    let mut statements = Vec::new();
+
+    if let Some(comment) = definition.comment(options) {
+        statements.push(format!(
+            "COMMENT ON TABLE {table_name} IS {}",
+            string_literal(&comment)
+        ));
+    }
+    for column in &definition.columns {
+        if let Some(comment) = column.comment(options) {
+            statements.push(format!(
+                "COMMENT ON COLUMN {table_name}.{} IS {}",
+                identifier(column.physical_name),
+                string_literal(&comment)
+            ));
+        }
+    }
+
+    statements
+}
+
+fn create_index(
+    table_name: &str,
+    index: &Index,
+    options: &Options,
+    warnings: &mut Vec<Warning>,
+) -> Vec<String> {
+    if index.full_text == Some(true) {
+        warnings.push(Warning::new(
+            table_name,
+            None,
+            WarningKind::FullTextIndexUnsupported {
+                index: index.name.clone(),
+            },
+        ));
+    }
+
+    let unique = if index.non_unique == Some(false) {
+        "UNIQUE "
+    } else {
+        ""
+    };
+    let method = match index.index_type.trim() {
+        "" => String::new(),
+        index_type => format!(" USING {}", index_type.to_ascii_lowercase()),
+    };
+    let columns = index
+        .columns
+        .iter()
+        .map(|column| {
+            if column.desc == Some(true) {
+                format!("{} DESC", identifier(&column.column_id))
+            } else {
+                identifier(&column.column_id)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let mut statements = vec![format!(
+        "CREATE {unique}INDEX {} ON {}{method} ({columns})",
+        identifier(&index.name),
+        identifier(table_name)
+    )];
+    if options.create_comment
+        && let Some(description) = index.description.as_deref()
+        && !description.is_empty()
+    {
+        statements.push(format!(
+            "COMMENT ON INDEX {} IS {}",
+            identifier(&index.name),
+            string_literal(description)
+        ));
+    }
+
+    statements
+}
+
+fn add_foreign_key(table_name: &str, foreign_key: &ForeignKey) -> String {
+    let mut statement = format!(
+        "ALTER TABLE {} ADD CONSTRAINT {} FOREIGN KEY ({}) REFERENCES {} ({})",
+        identifier(table_name),
+        identifier(foreign_key.name),
+        identifiers(&foreign_key.columns, identifier),
+        identifier(foreign_key.referenced_table),
+        identifiers(&foreign_key.referenced_columns, identifier)
+    );
+    if let Some(action) = foreign_key.on_delete {
+        statement.push_str(" ON DELETE ");
+        statement.push_str(referential_action(action));
+    }
+    if let Some(action) = foreign_key.on_update {
+        statement.push_str(" ON UPDATE ");
+        statement.push_str(referential_action(action));
+    }
+
+    statement
+}
+
+fn view_comment(definition: &ViewDefinition, options: &Options) -> Option<String> {
+    definition.comment(options).map(|comment| {
+        format!(
+            "COMMENT ON VIEW {} IS {}",
+            identifier(&definition.view.physical_name),
+            string_literal(&comment)
+        )
+    })
+}
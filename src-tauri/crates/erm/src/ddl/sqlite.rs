@@ -0,0 +1,567 @@
+use crate::column_type::ColumnType;
+use crate::diff;
+use crate::dtos::diagram::Diagram;
+use crate::dtos::diagram::diagram_walkers::tables::Table;
+use crate::dtos::diagram::diagram_walkers::tables::indexes::Index;
+
+use super::migration::{Migration, TableChanges};
+use super::{
+    ColumnDefinition, Ddl, ForeignKey, Options, TableDefinition, Warning, WarningKind, create_view,
+    default_literal, identifier, identifiers, is_table_constraint, line_comment,
+    referential_action, string_literal,
+};
+
+/// Generates SQLite DDL for every table and view of the diagram, as configured by its export
+/// settings.
+///
+/// Column types are reduced to SQLite type affinities and comments can only be inlined. SQLite
+/// cannot add foreign keys to an existing table, so they are declared inside `CREATE TABLE`;
+/// SQLite only resolves them when rows are written, so the tables may still be created in
+/// diagram order.
+pub fn generate(diagram: &Diagram) -> Ddl {
+    let options = Options::new(diagram);
+    let definitions = super::table_definitions(diagram);
+    let mut warnings = Vec::new();
+
+    let definitions = definitions
+        .into_iter()
+        .filter(|definition| {
+            if definition.columns.is_empty() {
+                warnings.push(Warning::new(
+                    &definition.table.physical_name,
+                    None,
+                    WarningKind::TableWithoutColumns,
+                ));
+            }
+            !definition.columns.is_empty()
+        })
+        .collect::<Vec<_>>();
+    let views = super::view_definitions(diagram, &mut warnings);
+
+    let mut statements = Vec::new();
+    if options.drop_view {
+        statements.extend(views.iter().rev().map(|definition| {
+            format!(
+                "DROP VIEW IF EXISTS {}",
+                identifier(&definition.view.physical_name)
+            )
+        }));
+    }
+    if options.drop_index {
+        for definition in &definitions {
+            for index in definition.table.indexes.as_deref().unwrap_or_default() {
+                statements.push(format!("DROP INDEX IF EXISTS {}", identifier(&index.name)));
+            }
+        }
+    }
+    if options.drop_table {
+        statements.extend(super::dependency_order(&definitions).into_iter().rev().map(
+            |definition| {
+                format!(
+                    "DROP TABLE IF EXISTS {}",
+                    identifier(&definition.table.physical_name)
+                )
+            },
+        ));
+    }
+    if options.create_table {
+        for definition in &definitions {
+            statements.push(create_table(definition, &options, &mut warnings));
+        }
+    }
+    if options.create_index {
+        for definition in &definitions {
+            for index in definition.table.indexes.as_deref().unwrap_or_default() {
+                statements.push(create_index(
+                    &definition.table.physical_name,
+                    index,
+                    &mut warnings,
+                ));
+            }
+        }
+    }
+
+    if options.create_view {
+        for definition in &views {
+            statements.push(create_view(definition, identifier));
+        }
+    }
+
+    let sql = statements
+        .into_iter()
+        .map(|statement| format!("{statement};\n"))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    Ddl { sql, warnings }
+}
+
+/// Generates the SQLite statements that turn the tables of the `old` diagram into those of `new`.
+///
+/// `ALTER TABLE` can only rename a table and add or drop a column, so changes to existing columns,
+/// keys and foreign keys are reported as warnings for the table to be rebuilt by hand.
+pub fn migrate(old: &Diagram, new: &Diagram) -> Ddl {
+    let options = Options::new(new);
+    let old_definitions = super::table_definitions(old);
+    let new_definitions = super::table_definitions(new);
+    let migration = Migration::new(&diff::diff(old, new), &old_definitions, &new_definitions);
+    let mut warnings = Vec::new();
+    let mut statements = Vec::new();
+
+    if options.create_foreign_key {
+        let dropped = migration.dropped_foreign_keys.iter().copied();
+        let mut reported = Vec::new();
+        for (table_name, foreign_key) in dropped.chain(migration.created_foreign_keys) {
+            // A changed foreign key is both dropped and created again.
+            if reported.contains(&foreign_key.name) {
+                continue;
+            }
+            reported.push(foreign_key.name);
+            warnings.push(Warning::new(
+                table_name,
+                None,
+                WarningKind::ForeignKeyChangeUnsupported {
+                    foreign_key: foreign_key.name.to_string(),
+                },
+            ));
+        }
+    }
+    if options.create_index {
+        for table in &migration.tables {
+            for index in &table.dropped_indexes {
+                statements.push(format!("DROP INDEX {}", identifier(&index.name)));
+            }
+        }
+    }
+    for definition in &migration.dropped_tables {
+        statements.push(format!(
+            "DROP TABLE {}",
+            identifier(&definition.table.physical_name)
+        ));
+    }
+    for (old_name, new_name) in &migration.renamed_tables {
+        statements.push(format!(
+            "ALTER TABLE {} RENAME TO {}",
+            identifier(old_name),
+            identifier(new_name)
+        ));
+    }
+    for definition in &migration.created_tables {
+        if definition.columns.is_empty() {
+            warnings.push(Warning::new(
+                &definition.table.physical_name,
+                None,
+                WarningKind::TableWithoutColumns,
+            ));
+            continue;
+        }
+        statements.push(create_table(definition, &options, &mut warnings));
+        if options.create_index {
+            for index in definition.table.indexes.as_deref().unwrap_or_default() {
+                statements.push(create_index(
+                    &definition.table.physical_name,
+                    index,
+                    &mut warnings,
+                ));
+            }
+        }
+    }
+    for table in &migration.tables {
+        statements.extend(alter_table(table, &mut warnings));
+        if options.create_index {
+            for index in &table.created_indexes {
+                statements.push(create_index(
+                    &table.new.table.physical_name,
+                    index,
+                    &mut warnings,
+                ));
+            }
+        }
+    }
+
+    let sql = statements
+        .into_iter()
+        .map(|statement| format!("{statement};\n"))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    Ddl { sql, warnings }
+}
+
+fn create_table(
+    definition: &TableDefinition,
+    options: &Options,
+    warnings: &mut Vec<Warning>,
+) -> String {
+    let table = definition.table;
+    let primary_key = definition.primary_key();
+
+    // `AUTOINCREMENT` is only allowed on a single-column `INTEGER PRIMARY KEY`.
+    let autoincrement_column = match primary_key.as_slice() {
+        [column_name] => definition.columns.iter().find(|column| {
+            column.physical_name == *column_name
+                && column.auto_increment
+                && is_integer(column.column_type)
+        }),
+        _ => None,
+    }
+    .map(|column| column.physical_name);
+
+    let mut lines = definition
+        .columns
+        .iter()
+        .map(|column| {
+            let autoincrement = autoincrement_column == Some(column.physical_name);
+            let line = column_definition(table, column, autoincrement, warnings);
+            match column.comment(options) {
+                Some(comment) if options.inline_column_comment => {
+                    format!("{}\n  {line}", line_comment(&comment))
+                }
+                _ => line,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    if !primary_key.is_empty() && autoincrement_column.is_none() {
+        lines.push(format!(
+            "{}PRIMARY KEY ({})",
+            constraint_name(table.primary_key_name.as_deref()),
+            identifiers(&primary_key, identifier)
+        ));
+    }
+
+    for key in table
+        .compound_unique_key_list
+        .compound_unique_keys
+        .as_deref()
+        .unwrap_or_default()
+    {
+        let columns = key
+            .columns
+            .iter()
+            .map(|column| column.column_id.as_str())
+            .collect::<Vec<_>>();
+        lines.push(format!(
+            "CONSTRAINT {} UNIQUE ({})",
+            identifier(&key.name),
+            identifiers(&columns, identifier)
+        ));
+    }
+
+    if options.create_foreign_key {
+        lines.extend(definition.foreign_keys.iter().map(foreign_key));
+    }
+
+    if let Some(constraint) = table.table_constraint.as_deref().map(str::trim)
+        && !constraint.is_empty()
+    {
+        if is_table_constraint(constraint) {
+            lines.push(constraint.to_string());
+        } else {
+            warnings.push(Warning::new(
+                &table.physical_name,
+                None,
+                WarningKind::TableConstraintIgnored {
+                    constraint: constraint.to_string(),
+                },
+            ));
+        }
+    }
+
+    let mut statement = String::new();
+    if options.inline_table_comment
+        && let Some(comment) = definition.comment(options)
+    {
+        statement.push_str(&line_comment(&comment));
+        statement.push('\n');
+    }
+    statement.push_str(&format!(
+        "CREATE TABLE {} (\n",
+        identifier(&table.physical_name)
+    ));
+    for (index, line) in lines.iter().enumerate() {
+        let separator = if index + 1 < lines.len() { "," } else { "" };
+        statement.push_str(&format!("  {line}{separator}\n"));
+    }
+    statement.push(')');
+
+    if let Some(option) = table.option.as_deref().map(str::trim)
+        && !option.is_empty()
+    {
+        if is_table_option(option) {
+            statement.push(' ');
+            statement.push_str(option);
+        } else {
+            warnings.push(Warning::new(
+                &table.physical_name,
+                None,
+                WarningKind::TableOptionIgnored {
+                    option: option.to_string(),
+                },
+            ));
+        }
+    }
+
+    statement
+}
+
+fn alter_table(table: &TableChanges, warnings: &mut Vec<Warning>) -> Vec<String> {
+    let new_table = table.new.table;
+    let table_name = &new_table.physical_name;
+    let mut warn =
+        |column: Option<&str>, kind| warnings.push(Warning::new(table_name, column, kind));
+    let mut statements = Vec::new();
+
+    if table.primary_key_changed {
+        warn(None, WarningKind::PrimaryKeyChangeUnsupported);
+    }
+    for key in table
+        .dropped_unique_keys
+        .iter()
+        .chain(&table.added_unique_keys)
+    {
+        warn(
+            None,
+            WarningKind::UniqueKeyChangeUnsupported {
+                unique_key: key.name.clone(),
+            },
+        );
+    }
+    for (old_name, new_name) in &table.renamed_columns {
+        statements.push(format!(
+            "ALTER TABLE {} RENAME COLUMN {} TO {}",
+            identifier(table_name),
+            identifier(old_name),
+            identifier(new_name)
+        ));
+    }
+    for column in &table.dropped_columns {
+        statements.push(format!(
+            "ALTER TABLE {} DROP COLUMN {}",
+            identifier(table_name),
+            identifier(column.physical_name)
+        ));
+    }
+    let mut column_warnings = Vec::new();
+    for column in &table.added_columns {
+        // An added column cannot be a key column.
+        if column.primary_key || column.unique_key {
+            warn(
+                Some(column.physical_name),
+                WarningKind::ColumnChangeUnsupported,
+            );
+            continue;
+        }
+        statements.push(format!(
+            "ALTER TABLE {} ADD COLUMN {}",
+            identifier(table_name),
+            column_definition(new_table, column, false, &mut column_warnings)
+        ));
+    }
+    for change in &table.modified_columns {
+        // Comments are not kept by SQLite.
+        if change.definition_changed() || change.unique_key_changed() {
+            warn(
+                Some(change.new.physical_name),
+                WarningKind::ColumnChangeUnsupported,
+            );
+        }
+    }
+    warnings.extend(column_warnings);
+
+    statements
+}
+
+fn column_definition(
+    table: &Table,
+    column: &ColumnDefinition,
+    autoincrement: bool,
+    warnings: &mut Vec<Warning>,
+) -> String {
+    let mut warn = |kind| {
+        warnings.push(Warning::new(
+            &table.physical_name,
+            Some(column.physical_name),
+            kind,
+        ))
+    };
+    let mut definition = identifier(column.physical_name);
+
+    match column.column_type {
+        Some(column_type) => {
+            definition.push(' ');
+            definition.push_str(affinity(column_type));
+
+            if matches!(column_type, ColumnType::Enum | ColumnType::Set)
+                && let Some(args) = column.args.filter(|args| !args.trim().is_empty())
+            {
+                warn(WarningKind::ArgsIgnored {
+                    column_type,
+                    args: args.to_string(),
+                });
+            }
+        }
+        None => warn(WarningKind::MissingColumnType),
+    }
+    if column.unsigned {
+        warn(WarningKind::UnsignedIgnored);
+    }
+    if autoincrement {
+        definition.push(' ');
+        definition.push_str(&constraint_name(table.primary_key_name.as_deref()));
+        definition.push_str("PRIMARY KEY AUTOINCREMENT");
+    } else if column.auto_increment {
+        warn(WarningKind::AutoIncrementUnsupported {
+            column_type: column.column_type,
+        });
+    }
+    if column.not_null || column.primary_key {
+        definition.push_str(" NOT NULL");
+    }
+    if let Some(default_value) = column.default_value {
+        definition.push_str(" DEFAULT ");
+        definition.push_str(&default_literal(default_value, string_literal));
+    }
+    if column.unique_key {
+        definition.push_str(" UNIQUE");
+    }
+
+    definition
+}
+
+/// Maps a column type to the SQLite type affinity it would otherwise be given by name.
+fn affinity(column_type: ColumnType) -> &'static str {
+    match column_type {
+        ColumnType::Char
+        | ColumnType::CharN
+        | ColumnType::VarCharN
+        | ColumnType::Text
+        | ColumnType::TinyText
+        | ColumnType::MediumText
+        | ColumnType::LongText
+        | ColumnType::Enum
+        | ColumnType::Set
+        | ColumnType::Json => "TEXT",
+        ColumnType::Int
+        | ColumnType::IntN
+        | ColumnType::TinyInt
+        | ColumnType::TinyIntN
+        | ColumnType::SmallInt
+        | ColumnType::SmallIntN
+        | ColumnType::MediumInt
+        | ColumnType::MediumIntN
+        | ColumnType::BigInt
+        | ColumnType::BigIntN
+        | ColumnType::Boolean
+        | ColumnType::BitN
+        | ColumnType::Year2
+        | ColumnType::Year4 => "INTEGER",
+        ColumnType::Double
+        | ColumnType::DoubleMD
+        | ColumnType::Float
+        | ColumnType::FloatMD
+        | ColumnType::FloatP
+        | ColumnType::Real
+        | ColumnType::RealMD => "REAL",
+        ColumnType::Binary
+        | ColumnType::BinaryN
+        | ColumnType::VarBinaryN
+        | ColumnType::Blob
+        | ColumnType::TinyBlob
+        | ColumnType::MediumBlob
+        | ColumnType::LongBlob
+        | ColumnType::Geometry => "BLOB",
+        ColumnType::Decimal
+        | ColumnType::DecimalP
+        | ColumnType::DecimalPS
+        | ColumnType::Numeric
+        | ColumnType::NumericP
+        | ColumnType::NumericPS
+        | ColumnType::Date
+        | ColumnType::Datetime
+        | ColumnType::Time
+        | ColumnType::Timestamp => "NUMERIC",
+    }
+}
+
+fn is_integer(column_type: Option<ColumnType>) -> bool {
+    column_type.is_some_and(|column_type| affinity(column_type) == "INTEGER")
+        && column_type != Some(ColumnType::Boolean)
+}
+
+fn is_table_option(option: &str) -> bool {
+    option.split(',').all(|option| {
+        matches!(
+            option
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" ")
+                .to_ascii_uppercase()
+                .as_str(),
+            "WITHOUT ROWID" | "STRICT"
+        )
+    })
+}
+
+fn create_index(table_name: &str, index: &Index, warnings: &mut Vec<Warning>) -> String {
+    if index.full_text == Some(true) {
+        warnings.push(Warning::new(
+            table_name,
+            None,
+            WarningKind::FullTextIndexUnsupported {
+                index: index.name.clone(),
+            },
+        ));
+    }
+
+    let unique = if index.non_unique == Some(false) {
+        "UNIQUE "
+    } else {
+        ""
+    };
+    let columns = index
+        .columns
+        .iter()
+        .map(|column| {
+            if column.desc == Some(true) {
+                format!("{} DESC", identifier(&column.column_id))
+            } else {
+                identifier(&column.column_id)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    format!(
+        "CREATE {unique}INDEX {} ON {} ({columns})",
+        identifier(&index.name),
+        identifier(table_name)
+    )
+}
+
+fn foreign_key(foreign_key: &ForeignKey) -> String {
+    let mut constraint = format!(
+        "CONSTRAINT {} FOREIGN KEY ({}) REFERENCES {} ({})",
+        identifier(foreign_key.name),
+        identifiers(&foreign_key.columns, identifier),
+        identifier(foreign_key.referenced_table),
+        identifiers(&foreign_key.referenced_columns, identifier)
+    );
+    if let Some(action) = foreign_key.on_delete {
+        constraint.push_str(" ON DELETE ");
+        constraint.push_str(referential_action(action));
+    }
+    if let Some(action) = foreign_key.on_update {
+        constraint.push_str(" ON UPDATE ");
+        constraint.push_str(referential_action(action));
+    }
+
+    constraint
+}
+
+fn constraint_name(name: Option<&str>) -> String {
+    match name {
+        Some(name) if !name.is_empty() => format!("CONSTRAINT {} ", identifier(name)),
+        _ => String::new(),
+    }
+}
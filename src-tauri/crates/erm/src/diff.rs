@@ -0,0 +1,970 @@
+use std::ptr;
+
+use serde::Serialize;
+
+use crate::column_type::ColumnType;
+use crate::dtos::diagram::Diagram;
+use crate::dtos::diagram::column_groups::{self, ColumnGroup};
+use crate::dtos::diagram::diagram_walkers::tables::Table;
+use crate::dtos::diagram::diagram_walkers::tables::columns::{ColumnItem, NormalColumn};
+use crate::dtos::diagram::diagram_walkers::tables::compound_unique_key_list::CompoundUniqueKey;
+use crate::dtos::diagram::diagram_walkers::tables::connections::{
+    ChildCardinality, OnAction, ParentCardinality, Relationship,
+};
+use crate::dtos::diagram::diagram_walkers::tables::indexes::Index;
+
+/// Changes between two diagrams, in the order of the newer diagram.
+#[derive(Debug, Clone, PartialEq, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeSet {
+    pub changes: Vec<Change>,
+}
+
+impl ChangeSet {
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(
+    tag = "kind",
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase"
+)]
+pub enum Change {
+    TableAdded {
+        table: String,
+    },
+    TableRemoved {
+        table: String,
+    },
+    TableRenamed {
+        old_name: String,
+        new_name: String,
+    },
+    TableModified {
+        table: String,
+        property: TableProperty,
+        old: Option<ChangeValue>,
+        new: Option<ChangeValue>,
+    },
+    ColumnAdded {
+        table: String,
+        column: String,
+    },
+    ColumnRemoved {
+        table: String,
+        column: String,
+    },
+    ColumnRenamed {
+        table: String,
+        old_name: String,
+        new_name: String,
+    },
+    ColumnModified {
+        table: String,
+        column: String,
+        property: ColumnProperty,
+        old: Option<ChangeValue>,
+        new: Option<ChangeValue>,
+    },
+    ColumnGroupReferenceAdded {
+        table: String,
+        column_group: String,
+    },
+    ColumnGroupReferenceRemoved {
+        table: String,
+        column_group: String,
+    },
+    IndexAdded {
+        table: String,
+        index: String,
+    },
+    IndexRemoved {
+        table: String,
+        index: String,
+    },
+    IndexModified {
+        table: String,
+        index: String,
+        property: IndexProperty,
+        old: Option<ChangeValue>,
+        new: Option<ChangeValue>,
+    },
+    CompoundUniqueKeyAdded {
+        table: String,
+        compound_unique_key: String,
+    },
+    CompoundUniqueKeyRemoved {
+        table: String,
+        compound_unique_key: String,
+    },
+    CompoundUniqueKeyModified {
+        table: String,
+        compound_unique_key: String,
+        old_columns: Vec<String>,
+        new_columns: Vec<String>,
+    },
+    RelationshipAdded {
+        table: String,
+        relationship: String,
+    },
+    RelationshipRemoved {
+        table: String,
+        relationship: String,
+    },
+    RelationshipModified {
+        table: String,
+        relationship: String,
+        property: RelationshipProperty,
+        old: Option<ChangeValue>,
+        new: Option<ChangeValue>,
+    },
+    ColumnGroupAdded {
+        column_group: String,
+    },
+    ColumnGroupRemoved {
+        column_group: String,
+    },
+    ColumnGroupColumnAdded {
+        column_group: String,
+        column: String,
+    },
+    ColumnGroupColumnRemoved {
+        column_group: String,
+        column: String,
+    },
+    ColumnGroupColumnModified {
+        column_group: String,
+        column: String,
+        property: ColumnProperty,
+        old: Option<ChangeValue>,
+        new: Option<ChangeValue>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TableProperty {
+    LogicalName,
+    Description,
+    TableConstraint,
+    PrimaryKeyName,
+    Option,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ColumnProperty {
+    LogicalName,
+    Description,
+    Type,
+    Length,
+    Decimal,
+    Args,
+    Unsigned,
+    NotNull,
+    UniqueKey,
+    DefaultValue,
+    PrimaryKey,
+    AutoIncrement,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum IndexProperty {
+    Type,
+    Description,
+    FullText,
+    NonUnique,
+    Columns,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RelationshipProperty {
+    Source,
+    Target,
+    FkColumns,
+    ParentCardinality,
+    ChildCardinality,
+    ReferenceForPk,
+    OnDeleteAction,
+    OnUpdateAction,
+    ReferredSimpleUniqueColumn,
+    ReferredCompoundUniqueKey,
+}
+
+/// The value of a changed property.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum ChangeValue {
+    Bool(bool),
+    Number(u16),
+    Text(String),
+    List(Vec<String>),
+}
+
+impl From<bool> for ChangeValue {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<u16> for ChangeValue {
+    fn from(value: u16) -> Self {
+        Self::Number(value)
+    }
+}
+
+impl From<String> for ChangeValue {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<Vec<String>> for ChangeValue {
+    fn from(value: Vec<String>) -> Self {
+        Self::List(value)
+    }
+}
+
+impl From<ColumnType> for ChangeValue {
+    fn from(value: ColumnType) -> Self {
+        Self::Text(value.as_str().to_string())
+    }
+}
+
+impl From<ParentCardinality> for ChangeValue {
+    fn from(value: ParentCardinality) -> Self {
+        Self::Text(value.as_str().to_string())
+    }
+}
+
+impl From<ChildCardinality> for ChangeValue {
+    fn from(value: ChildCardinality) -> Self {
+        Self::Text(value.as_str().to_string())
+    }
+}
+
+impl From<OnAction> for ChangeValue {
+    fn from(value: OnAction) -> Self {
+        match value {
+            OnAction::NoAction => Self::Text("NO ACTION".to_string()),
+            action => Self::Text(action.as_str().to_string()),
+        }
+    }
+}
+
+/// Lists the schema changes that turn `old` into `new`.
+///
+/// Tables are matched by physical name; a removed table and an added table with the same
+/// columns are reported as a rename when neither has another table with those columns to pair
+/// with. Tables without columns are never paired. Columns are paired the same way within a table,
+/// by their definition. Layout-only properties such as positions, sizes and colors are ignored.
+pub fn diff(old: &Diagram, new: &Diagram) -> ChangeSet {
+    let mut changes = Vec::new();
+    let old_tables = tables(old);
+    let new_tables = tables(new);
+
+    let removed = old_tables
+        .iter()
+        .filter(|table| find_table(new_tables, &table.physical_name).is_none())
+        .collect::<Vec<_>>();
+    let added = new_tables
+        .iter()
+        .filter(|table| find_table(old_tables, &table.physical_name).is_none())
+        .collect::<Vec<_>>();
+
+    let renamed = added
+        .iter()
+        .filter_map(|new_table| {
+            let signature = column_signature(new_table);
+            if signature.is_empty() {
+                return None;
+            }
+            let candidates = removed
+                .iter()
+                .filter(|old_table| column_signature(old_table) == signature)
+                .collect::<Vec<_>>();
+            let rivals = added
+                .iter()
+                .filter(|other| column_signature(other) == signature)
+                .count();
+            match candidates.as_slice() {
+                [old_table] if rivals == 1 => Some((**old_table, *new_table)),
+                _ => None,
+            }
+        })
+        .collect::<Vec<(&Table, &Table)>>();
+
+    for old_table in &removed {
+        if renamed
+            .iter()
+            .all(|(renamed, _)| !ptr::eq(*renamed, *old_table))
+        {
+            changes.push(Change::TableRemoved {
+                table: old_table.physical_name.clone(),
+            });
+        }
+    }
+
+    for new_table in new_tables {
+        let old_table = find_table(old_tables, &new_table.physical_name).or_else(|| {
+            renamed
+                .iter()
+                .find(|(_, renamed)| ptr::eq(*renamed, new_table))
+                .map(|(old_table, _)| *old_table)
+        });
+
+        match old_table {
+            Some(old_table) => {
+                if old_table.physical_name != new_table.physical_name {
+                    changes.push(Change::TableRenamed {
+                        old_name: old_table.physical_name.clone(),
+                        new_name: new_table.physical_name.clone(),
+                    });
+                }
+                diff_table(old_table, new_table, &mut changes);
+            }
+            None => changes.push(Change::TableAdded {
+                table: new_table.physical_name.clone(),
+            }),
+        }
+    }
+
+    diff_column_groups(
+        old.column_groups.as_deref().unwrap_or_default(),
+        new.column_groups.as_deref().unwrap_or_default(),
+        &mut changes,
+    );
+
+    ChangeSet { changes }
+}
+
+fn diff_table(old: &Table, new: &Table, changes: &mut Vec<Change>) {
+    let table = &new.physical_name;
+    let mut property = |property, old: Option<ChangeValue>, new: Option<ChangeValue>| {
+        if old != new {
+            changes.push(Change::TableModified {
+                table: table.clone(),
+                property,
+                old,
+                new,
+            });
+        }
+    };
+
+    property(
+        TableProperty::LogicalName,
+        Some(old.logical_name.clone().into()),
+        Some(new.logical_name.clone().into()),
+    );
+    property(
+        TableProperty::Description,
+        Some(old.description.clone().into()),
+        Some(new.description.clone().into()),
+    );
+    property(
+        TableProperty::TableConstraint,
+        old.table_constraint.clone().map(Into::into),
+        new.table_constraint.clone().map(Into::into),
+    );
+    property(
+        TableProperty::PrimaryKeyName,
+        old.primary_key_name.clone().map(Into::into),
+        new.primary_key_name.clone().map(Into::into),
+    );
+    property(
+        TableProperty::Option,
+        old.option.clone().map(Into::into),
+        new.option.clone().map(Into::into),
+    );
+
+    diff_columns(table, old, new, changes);
+    diff_indexes(
+        table,
+        old.indexes.as_deref().unwrap_or_default(),
+        new.indexes.as_deref().unwrap_or_default(),
+        changes,
+    );
+    diff_compound_unique_keys(
+        table,
+        compound_unique_keys(old),
+        compound_unique_keys(new),
+        changes,
+    );
+    diff_relationships(
+        table,
+        old.connections.relationships.as_deref().unwrap_or_default(),
+        new.connections.relationships.as_deref().unwrap_or_default(),
+        changes,
+    );
+}
+
+fn diff_columns(table: &str, old: &Table, new: &Table, changes: &mut Vec<Change>) {
+    let old_items = old.columns.items.as_deref().unwrap_or_default();
+    let new_items = new.columns.items.as_deref().unwrap_or_default();
+    let renamed = renamed_columns(old_items, new_items);
+
+    for old_item in old_items {
+        let is_renamed = matches!(old_item, ColumnItem::Normal(column)
+            if renamed.iter().any(|(renamed, _)| ptr::eq(*renamed, column)));
+        if !is_renamed
+            && !new_items
+                .iter()
+                .any(|new_item| same_column_item(old_item, new_item))
+        {
+            changes.push(match old_item {
+                ColumnItem::Normal(column) => Change::ColumnRemoved {
+                    table: table.to_string(),
+                    column: column.physical_name.clone(),
+                },
+                ColumnItem::Group(group) => Change::ColumnGroupReferenceRemoved {
+                    table: table.to_string(),
+                    column_group: group.clone(),
+                },
+            });
+        }
+    }
+
+    for new_item in new_items {
+        let old_item = old_items
+            .iter()
+            .find(|old_item| same_column_item(old_item, new_item));
+        let renamed_from = match new_item {
+            ColumnItem::Normal(column) => renamed
+                .iter()
+                .find(|(_, renamed)| ptr::eq(*renamed, column))
+                .map(|(old_column, _)| *old_column),
+            ColumnItem::Group(_) => None,
+        };
+
+        match (old_item, new_item) {
+            (Some(ColumnItem::Normal(old_column)), ColumnItem::Normal(new_column)) => {
+                diff_column(old_column, new_column, |property, old, new| {
+                    changes.push(Change::ColumnModified {
+                        table: table.to_string(),
+                        column: new_column.physical_name.clone(),
+                        property,
+                        old,
+                        new,
+                    })
+                });
+            }
+            (Some(_), _) => {}
+            (None, ColumnItem::Normal(new_column)) => match renamed_from {
+                Some(old_column) => {
+                    changes.push(Change::ColumnRenamed {
+                        table: table.to_string(),
+                        old_name: old_column.physical_name.clone(),
+                        new_name: new_column.physical_name.clone(),
+                    });
+                    diff_column(old_column, new_column, |property, old, new| {
+                        changes.push(Change::ColumnModified {
+                            table: table.to_string(),
+                            column: new_column.physical_name.clone(),
+                            property,
+                            old,
+                            new,
+                        })
+                    });
+                }
+                None => changes.push(Change::ColumnAdded {
+                    table: table.to_string(),
+                    column: new_column.physical_name.clone(),
+                }),
+            },
+            (None, ColumnItem::Group(group)) => changes.push(Change::ColumnGroupReferenceAdded {
+                table: table.to_string(),
+                column_group: group.clone(),
+            }),
+        }
+    }
+}
+
+/// Pairs a removed column with an added one when they only differ by their names and no other
+/// removed or added column has the same definition, the way [`diff`] pairs renamed tables.
+fn renamed_columns<'c>(
+    old_items: &'c [ColumnItem],
+    new_items: &'c [ColumnItem],
+) -> Vec<(&'c NormalColumn, &'c NormalColumn)> {
+    let unmatched = |items: &'c [ColumnItem], others: &'c [ColumnItem]| {
+        items
+            .iter()
+            .filter(|item| !others.iter().any(|other| same_column_item(item, other)))
+            .filter_map(|item| match item {
+                ColumnItem::Normal(column) => Some(column),
+                ColumnItem::Group(_) => None,
+            })
+            .collect::<Vec<_>>()
+    };
+    let removed = unmatched(old_items, new_items);
+    let added = unmatched(new_items, old_items);
+
+    added
+        .iter()
+        .filter_map(|new_column| {
+            let candidates = removed
+                .iter()
+                .filter(|old_column| same_column_definition(old_column, new_column))
+                .collect::<Vec<_>>();
+            let rivals = added
+                .iter()
+                .filter(|other| same_column_definition(other, new_column))
+                .count();
+            match candidates.as_slice() {
+                [old_column] if rivals == 1 => Some((**old_column, *new_column)),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// Compares everything but the names and the description of two columns.
+fn same_column_definition(a: &NormalColumn, b: &NormalColumn) -> bool {
+    a.column_type == b.column_type
+        && a.length == b.length
+        && a.decimal == b.decimal
+        && a.args == b.args
+        && a.unsigned == b.unsigned
+        && a.not_null == b.not_null
+        && a.unique_key == b.unique_key
+        && a.default_value == b.default_value
+        && a.primary_key == b.primary_key
+        && a.auto_increment == b.auto_increment
+        && a.referred_column == b.referred_column
+        && a.relationship == b.relationship
+}
+
+fn diff_column(
+    old: &NormalColumn,
+    new: &NormalColumn,
+    mut push: impl FnMut(ColumnProperty, Option<ChangeValue>, Option<ChangeValue>),
+) {
+    let mut property = |property, old: Option<ChangeValue>, new: Option<ChangeValue>| {
+        if old != new {
+            push(property, old, new);
+        }
+    };
+
+    property(
+        ColumnProperty::LogicalName,
+        old.logical_name.clone().map(Into::into),
+        new.logical_name.clone().map(Into::into),
+    );
+    property(
+        ColumnProperty::Description,
+        old.description.clone().map(Into::into),
+        new.description.clone().map(Into::into),
+    );
+    property(
+        ColumnProperty::Type,
+        old.column_type.map(Into::into),
+        new.column_type.map(Into::into),
+    );
+    property(
+        ColumnProperty::Length,
+        old.length.map(Into::into),
+        new.length.map(Into::into),
+    );
+    property(
+        ColumnProperty::Decimal,
+        old.decimal.map(Into::into),
+        new.decimal.map(Into::into),
+    );
+    property(
+        ColumnProperty::Args,
+        old.args.clone().map(Into::into),
+        new.args.clone().map(Into::into),
+    );
+    property(
+        ColumnProperty::Unsigned,
+        old.unsigned.map(Into::into),
+        new.unsigned.map(Into::into),
+    );
+    property(
+        ColumnProperty::NotNull,
+        old.not_null.map(Into::into),
+        new.not_null.map(Into::into),
+    );
+    property(
+        ColumnProperty::UniqueKey,
+        old.unique_key.map(Into::into),
+        new.unique_key.map(Into::into),
+    );
+    property(
+        ColumnProperty::DefaultValue,
+        old.default_value.clone().map(Into::into),
+        new.default_value.clone().map(Into::into),
+    );
+    property(
+        ColumnProperty::PrimaryKey,
+        old.primary_key.map(Into::into),
+        new.primary_key.map(Into::into),
+    );
+    property(
+        ColumnProperty::AutoIncrement,
+        old.auto_increment.map(Into::into),
+        new.auto_increment.map(Into::into),
+    );
+}
+
+fn diff_indexes(table: &str, old: &[Index], new: &[Index], changes: &mut Vec<Change>) {
+    for old_index in old {
+        if !new.iter().any(|index| index.name == old_index.name) {
+            changes.push(Change::IndexRemoved {
+                table: table.to_string(),
+                index: old_index.name.clone(),
+            });
+        }
+    }
+
+    for new_index in new {
+        let Some(old_index) = old.iter().find(|index| index.name == new_index.name) else {
+            changes.push(Change::IndexAdded {
+                table: table.to_string(),
+                index: new_index.name.clone(),
+            });
+            continue;
+        };
+
+        let mut property = |property, old: Option<ChangeValue>, new: Option<ChangeValue>| {
+            if old != new {
+                changes.push(Change::IndexModified {
+                    table: table.to_string(),
+                    index: new_index.name.clone(),
+                    property,
+                    old,
+                    new,
+                });
+            }
+        };
+
+        property(
+            IndexProperty::Type,
+            Some(old_index.index_type.clone().into()),
+            Some(new_index.index_type.clone().into()),
+        );
+        property(
+            IndexProperty::Description,
+            old_index.description.clone().map(Into::into),
+            new_index.description.clone().map(Into::into),
+        );
+        property(
+            IndexProperty::FullText,
+            old_index.full_text.map(Into::into),
+            new_index.full_text.map(Into::into),
+        );
+        property(
+            IndexProperty::NonUnique,
+            old_index.non_unique.map(Into::into),
+            new_index.non_unique.map(Into::into),
+        );
+        property(
+            IndexProperty::Columns,
+            Some(index_columns(old_index).into()),
+            Some(index_columns(new_index).into()),
+        );
+    }
+}
+
+fn diff_compound_unique_keys(
+    table: &str,
+    old: &[CompoundUniqueKey],
+    new: &[CompoundUniqueKey],
+    changes: &mut Vec<Change>,
+) {
+    let columns = |key: &CompoundUniqueKey| {
+        key.columns
+            .iter()
+            .map(|column| column.column_id.clone())
+            .collect::<Vec<_>>()
+    };
+
+    for old_key in old {
+        if !new.iter().any(|key| key.name == old_key.name) {
+            changes.push(Change::CompoundUniqueKeyRemoved {
+                table: table.to_string(),
+                compound_unique_key: old_key.name.clone(),
+            });
+        }
+    }
+
+    for new_key in new {
+        match old.iter().find(|key| key.name == new_key.name) {
+            Some(old_key) => {
+                if columns(old_key) != columns(new_key) {
+                    changes.push(Change::CompoundUniqueKeyModified {
+                        table: table.to_string(),
+                        compound_unique_key: new_key.name.clone(),
+                        old_columns: columns(old_key),
+                        new_columns: columns(new_key),
+                    });
+                }
+            }
+            None => changes.push(Change::CompoundUniqueKeyAdded {
+                table: table.to_string(),
+                compound_unique_key: new_key.name.clone(),
+            }),
+        }
+    }
+}
+
+fn diff_relationships(
+    table: &str,
+    old: &[Relationship],
+    new: &[Relationship],
+    changes: &mut Vec<Change>,
+) {
+    for old_relationship in old {
+        if !new.iter().any(|r| r.name == old_relationship.name) {
+            changes.push(Change::RelationshipRemoved {
+                table: table.to_string(),
+                relationship: old_relationship.name.clone(),
+            });
+        }
+    }
+
+    for new_relationship in new {
+        let Some(old_relationship) = old.iter().find(|r| r.name == new_relationship.name) else {
+            changes.push(Change::RelationshipAdded {
+                table: table.to_string(),
+                relationship: new_relationship.name.clone(),
+            });
+            continue;
+        };
+
+        let mut property = |property, old: Option<ChangeValue>, new: Option<ChangeValue>| {
+            if old != new {
+                changes.push(Change::RelationshipModified {
+                    table: table.to_string(),
+                    relationship: new_relationship.name.clone(),
+                    property,
+                    old,
+                    new,
+                });
+            }
+        };
+        let (old, new) = (old_relationship, new_relationship);
+
+        property(
+            RelationshipProperty::Source,
+            Some(old.source.clone().into()),
+            Some(new.source.clone().into()),
+        );
+        property(
+            RelationshipProperty::Target,
+            Some(old.target.clone().into()),
+            Some(new.target.clone().into()),
+        );
+        property(
+            RelationshipProperty::FkColumns,
+            Some(fk_columns(old).into()),
+            Some(fk_columns(new).into()),
+        );
+        property(
+            RelationshipProperty::ParentCardinality,
+            Some(old.parent_cardinality.clone().into()),
+            Some(new.parent_cardinality.clone().into()),
+        );
+        property(
+            RelationshipProperty::ChildCardinality,
+            Some(old.child_cardinality.clone().into()),
+            Some(new.child_cardinality.clone().into()),
+        );
+        property(
+            RelationshipProperty::ReferenceForPk,
+            Some(old.reference_for_pk.into()),
+            Some(new.reference_for_pk.into()),
+        );
+        property(
+            RelationshipProperty::OnDeleteAction,
+            Some(on_action(&old.on_delete_action)),
+            Some(on_action(&new.on_delete_action)),
+        );
+        property(
+            RelationshipProperty::OnUpdateAction,
+            Some(on_action(&old.on_update_action)),
+            Some(on_action(&new.on_update_action)),
+        );
+        property(
+            RelationshipProperty::ReferredSimpleUniqueColumn,
+            old.referred_simple_unique_column.clone().map(Into::into),
+            new.referred_simple_unique_column.clone().map(Into::into),
+        );
+        property(
+            RelationshipProperty::ReferredCompoundUniqueKey,
+            old.referred_compound_unique_key.clone().map(Into::into),
+            new.referred_compound_unique_key.clone().map(Into::into),
+        );
+    }
+}
+
+fn diff_column_groups(old: &[ColumnGroup], new: &[ColumnGroup], changes: &mut Vec<Change>) {
+    for old_group in old {
+        if !new
+            .iter()
+            .any(|group| group.column_group_name == old_group.column_group_name)
+        {
+            changes.push(Change::ColumnGroupRemoved {
+                column_group: old_group.column_group_name.clone(),
+            });
+        }
+    }
+
+    for new_group in new {
+        let column_group = &new_group.column_group_name;
+        let Some(old_group) = old
+            .iter()
+            .find(|group| group.column_group_name == *column_group)
+        else {
+            changes.push(Change::ColumnGroupAdded {
+                column_group: column_group.clone(),
+            });
+            continue;
+        };
+
+        let old_columns = old_group
+            .columns
+            .normal_columns
+            .as_deref()
+            .unwrap_or_default();
+        let new_columns = new_group
+            .columns
+            .normal_columns
+            .as_deref()
+            .unwrap_or_default();
+
+        for old_column in old_columns {
+            if !new_columns
+                .iter()
+                .any(|column| column.physical_name == old_column.physical_name)
+            {
+                changes.push(Change::ColumnGroupColumnRemoved {
+                    column_group: column_group.clone(),
+                    column: old_column.physical_name.clone(),
+                });
+            }
+        }
+
+        for new_column in new_columns {
+            match old_columns
+                .iter()
+                .find(|column| column.physical_name == new_column.physical_name)
+            {
+                Some(old_column) => diff_column(
+                    &group_column(old_column),
+                    &group_column(new_column),
+                    |property, old, new| {
+                        changes.push(Change::ColumnGroupColumnModified {
+                            column_group: column_group.clone(),
+                            column: new_column.physical_name.clone(),
+                            property,
+                            old,
+                            new,
+                        })
+                    },
+                ),
+                None => changes.push(Change::ColumnGroupColumnAdded {
+                    column_group: column_group.clone(),
+                    column: new_column.physical_name.clone(),
+                }),
+            }
+        }
+    }
+}
+
+fn tables(diagram: &Diagram) -> &[Table] {
+    diagram
+        .diagram_walkers
+        .as_ref()
+        .and_then(|walkers| walkers.tables.as_deref())
+        .unwrap_or_default()
+}
+
+fn find_table<'a>(tables: &'a [Table], physical_name: &str) -> Option<&'a Table> {
+    tables
+        .iter()
+        .find(|table| table.physical_name == physical_name)
+}
+
+fn compound_unique_keys(table: &Table) -> &[CompoundUniqueKey] {
+    table
+        .compound_unique_key_list
+        .compound_unique_keys
+        .as_deref()
+        .unwrap_or_default()
+}
+
+/// Describes the columns of a table closely enough to recognize it after a rename.
+fn column_signature(table: &Table) -> Vec<(String, Option<ColumnType>, Option<u16>)> {
+    table
+        .columns
+        .items
+        .iter()
+        .flatten()
+        .map(|item| match item {
+            ColumnItem::Normal(column) => (
+                column.physical_name.clone(),
+                column.column_type,
+                column.length,
+            ),
+            ColumnItem::Group(group) => (group.clone(), None, None),
+        })
+        .collect()
+}
+
+fn same_column_item(a: &ColumnItem, b: &ColumnItem) -> bool {
+    match (a, b) {
+        (ColumnItem::Normal(a), ColumnItem::Normal(b)) => a.physical_name == b.physical_name,
+        (ColumnItem::Group(a), ColumnItem::Group(b)) => a == b,
+        _ => false,
+    }
+}
+
+fn index_columns(index: &Index) -> Vec<String> {
+    index
+        .columns
+        .iter()
+        .map(|column| match column.desc {
+            Some(true) => format!("{} DESC", column.column_id),
+            _ => column.column_id.clone(),
+        })
+        .collect()
+}
+
+fn fk_columns(relationship: &Relationship) -> Vec<String> {
+    relationship
+        .fk_columns
+        .fk_column
+        .iter()
+        .map(|column| column.fk_column_name.clone())
+        .collect()
+}
+
+fn on_action(action: &Option<OnAction>) -> ChangeValue {
+    action.clone().unwrap_or(OnAction::NoAction).into()
+}
+
+/// Views a column group column as a table column so both share one comparison.
+fn group_column(column: &column_groups::NormalColumn) -> NormalColumn {
+    NormalColumn {
+        physical_name: column.physical_name.clone(),
+        logical_name: column.logical_name.clone(),
+        description: column.description.clone(),
+        column_type: Some(column.column_type),
+        length: column.length,
+        decimal: column.decimal,
+        args: column.args.clone(),
+        unsigned: column.unsigned,
+        not_null: column.not_null,
+        unique_key: column.unique_key,
+        default_value: column.default_value.clone(),
+        primary_key: None,
+        auto_increment: None,
+        referred_column: None,
+        relationship: None,
+    }
+}
@@ -2,20 +2,29 @@ pub mod column_groups;
 pub mod diagram_settings;
 pub mod diagram_walkers;
 pub mod page_settings;
+pub mod virtual_diagrams;
+
+pub use crate::entities::diagram::unknown_elements::{UnknownElement, UnknownElements};
+pub use crate::entities::diagram::xml_layout::{ChildLayout, XmlLayout};
+
+use std::collections::HashSet;
 
 use column_groups::ColumnGroup;
 use diagram_settings::DiagramSettings;
 use diagram_walkers::DiagramWalkers;
+use diagram_walkers::tables::Table;
 use page_settings::PageSettings;
 use serde::{Deserialize, Serialize};
+use virtual_diagrams::VirtualDiagram;
 
 use crate::validation::Validate;
 use crate::validation::diagram::{
     validate_column_group_column_length_and_decimal, validate_column_group_references,
     validate_duplicate_column_group_column_physical_names, validate_duplicate_column_group_names,
+    validate_virtual_table_references,
 };
 
-#[derive(Debug, PartialEq, Serialize, Deserialize, Validate)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct Color {
     pub r: u8,
@@ -33,12 +42,23 @@ impl From<crate::entities::diagram::Color> for Color {
     }
 }
 
-#[derive(Debug, PartialEq, Serialize, Deserialize, Validate)]
+impl From<Color> for crate::entities::diagram::Color {
+    fn from(dto: Color) -> Self {
+        Self {
+            r: dto.r,
+            g: dto.g,
+            b: dto.b,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate)]
 #[validate(rules(
     validate_duplicate_column_group_names,
     validate_duplicate_column_group_column_physical_names,
     validate_column_group_column_length_and_decimal,
-    validate_column_group_references
+    validate_column_group_references,
+    validate_virtual_table_references
 ))]
 #[serde(rename_all = "camelCase")]
 pub struct Diagram {
@@ -80,8 +100,109 @@ pub struct Diagram {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub diagram_walkers: Option<DiagramWalkers>,
 
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub virtual_diagrams: Option<Vec<VirtualDiagram>>,
+
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub column_groups: Option<Vec<ColumnGroup>>,
+
+    #[serde(default, skip_serializing_if = "UnknownElements::is_empty")]
+    pub unknown_elements: UnknownElements,
+
+    #[serde(default, skip_serializing_if = "XmlLayout::is_empty")]
+    pub xml_layout: XmlLayout,
+}
+
+impl Diagram {
+    /// Returns the diagram as its virtual diagram named `name` shows it: only the tables placed on
+    /// it, at their positions there, and its notes and groups in place of the diagram's own.
+    /// Relationships and comment connections to walkers left out are dropped, as are views and
+    /// images.
+    ///
+    /// The projection is meant for display; saving it would lose everything it leaves out.
+    pub fn project(&self, name: &str) -> Option<Diagram> {
+        let virtual_diagram = self
+            .virtual_diagrams
+            .iter()
+            .flatten()
+            .find(|virtual_diagram| virtual_diagram.vdiagram_name.as_deref() == Some(name))?;
+        let tables = self
+            .diagram_walkers
+            .as_ref()
+            .and_then(|walkers| walkers.tables.as_deref())
+            .unwrap_or_default();
+
+        let mut projected_tables = virtual_diagram
+            .tables
+            .iter()
+            .flatten()
+            .filter_map(|virtual_table| {
+                let table = tables.iter().find(|table| {
+                    virtual_table.table_id == format!("table.{}", table.physical_name)
+                })?;
+
+                Some(Table {
+                    height: virtual_table.height.or(table.height),
+                    width: virtual_table.width.or(table.width),
+                    font_name: virtual_table
+                        .font_name
+                        .clone()
+                        .unwrap_or_else(|| table.font_name.clone()),
+                    font_size: virtual_table.font_size.unwrap_or(table.font_size),
+                    x: virtual_table.x.unwrap_or(table.x),
+                    y: virtual_table.y.unwrap_or(table.y),
+                    ..table.clone()
+                })
+            })
+            .collect::<Vec<_>>();
+        let mut projected_notes = virtual_diagram.notes.clone();
+
+        let walker_references = projected_tables
+            .iter()
+            .map(|table| format!("table.{}", table.physical_name))
+            .chain(
+                projected_notes
+                    .iter()
+                    .flatten()
+                    .filter_map(|note| note.id.clone()),
+            )
+            .collect::<HashSet<_>>();
+        let connections = projected_tables
+            .iter_mut()
+            .map(|table| &mut table.connections)
+            .chain(
+                projected_notes
+                    .iter_mut()
+                    .flatten()
+                    .filter_map(|note| note.connections.as_mut()),
+            );
+        for connections in connections {
+            if let Some(relationships) = &mut connections.relationships {
+                relationships.retain(|relationship| {
+                    walker_references.contains(&relationship.source)
+                        && walker_references.contains(&relationship.target)
+                });
+            }
+            if let Some(comment_connections) = &mut connections.comment_connections {
+                comment_connections.retain(|connection| {
+                    walker_references.contains(&connection.source)
+                        && walker_references.contains(&connection.target)
+                });
+            }
+        }
+
+        Some(Diagram {
+            current_ermodel: Some(name.to_string()),
+            diagram_walkers: Some(DiagramWalkers {
+                tables: Some(projected_tables),
+                views: None,
+                notes: projected_notes,
+                groups: virtual_diagram.groups.clone(),
+                images: None,
+            }),
+            ..self.clone()
+        })
+    }
 }
 
 impl From<crate::entities::diagram::Diagram> for Diagram {
@@ -100,10 +221,48 @@ impl From<crate::entities::diagram::Diagram> for Diagram {
             font_size: entity.font_size,
             diagram_settings: entity.diagram_settings.into(),
             diagram_walkers: entity.diagram_walkers.map(Into::into),
+            virtual_diagrams: entity
+                .virtual_diagrams
+                .and_then(|diagrams| diagrams.virtual_diagrams)
+                .map(|v| v.into_iter().map(Into::into).collect()),
             column_groups: entity
                 .column_groups
                 .and_then(|groups| groups.column_groups)
                 .map(|v| v.into_iter().map(Into::into).collect()),
+            unknown_elements: entity.unknown_elements,
+            xml_layout: entity.xml_layout,
+        }
+    }
+}
+
+impl From<Diagram> for crate::entities::diagram::Diagram {
+    fn from(dto: Diagram) -> Self {
+        Self {
+            presenter: dto.presenter,
+            page_settings: dto.page_settings.map(Into::into),
+            category_index: dto.category_index,
+            current_ermodel: dto.current_ermodel,
+            zoom: dto.zoom,
+            x: dto.x,
+            y: dto.y,
+            default_color: dto.default_color.map(Into::into),
+            color: dto.color.map(Into::into),
+            font_name: dto.font_name,
+            font_size: dto.font_size,
+            diagram_settings: dto.diagram_settings.into(),
+            diagram_walkers: dto.diagram_walkers.map(Into::into),
+            virtual_diagrams: dto.virtual_diagrams.map(|v| {
+                crate::entities::diagram::virtual_diagrams::VirtualDiagrams {
+                    virtual_diagrams: Some(v.into_iter().map(Into::into).collect()),
+                }
+            }),
+            column_groups: dto.column_groups.map(|v| {
+                crate::entities::diagram::column_groups::ColumnGroups {
+                    column_groups: Some(v.into_iter().map(Into::into).collect()),
+                }
+            }),
+            unknown_elements: dto.unknown_elements,
+            xml_layout: dto.xml_layout,
         }
     }
 }
@@ -4,7 +4,7 @@ use crate::entities::diagram::column_groups as entities;
 use crate::validation::Validate;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, PartialEq, Serialize, Deserialize, Default, Validate)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct NormalColumn {
     pub physical_name: String,
@@ -57,7 +57,25 @@ impl From<entities::NormalColumn> for NormalColumn {
     }
 }
 
-#[derive(Debug, PartialEq, Serialize, Deserialize, Validate)]
+impl From<NormalColumn> for entities::NormalColumn {
+    fn from(dto: NormalColumn) -> Self {
+        Self {
+            physical_name: dto.physical_name,
+            logical_name: dto.logical_name,
+            description: dto.description,
+            column_type: dto.column_type,
+            length: dto.length,
+            decimal: dto.decimal,
+            args: dto.args,
+            not_null: dto.not_null,
+            unique_key: dto.unique_key,
+            unsigned: dto.unsigned,
+            default_value: dto.default_value,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct Columns {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -74,7 +92,17 @@ impl From<entities::Columns> for Columns {
     }
 }
 
-#[derive(Debug, PartialEq, Serialize, Deserialize, Validate)]
+impl From<Columns> for entities::Columns {
+    fn from(dto: Columns) -> Self {
+        Self {
+            normal_columns: dto
+                .normal_columns
+                .map(|v| v.into_iter().map(Into::into).collect()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct ColumnGroup {
     pub column_group_name: String,
@@ -89,3 +117,12 @@ impl From<entities::ColumnGroup> for ColumnGroup {
         }
     }
 }
+
+impl From<ColumnGroup> for entities::ColumnGroup {
+    fn from(dto: ColumnGroup) -> Self {
+        Self {
+            column_group_name: dto.column_group_name,
+            columns: dto.columns.into(),
+        }
+    }
+}
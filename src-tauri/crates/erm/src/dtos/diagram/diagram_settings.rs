@@ -2,17 +2,170 @@ use crate::entities::diagram::diagram_settings as entities;
 use crate::validation::Validate;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, PartialEq, Serialize, Deserialize, Validate)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate, Default)]
 #[serde(rename_all = "camelCase")]
-pub struct ExportSettings {}
+pub struct ExportSettings {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category_name_to_export: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ddl_output: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub excel_output: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub excel_template: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image_output: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub put_diagram_on_excel: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub use_logical_name_as_sheet: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub open_after_saved: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub create_comment: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub create_foreign_key: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub create_index: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub create_sequence: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub create_table: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub create_tablespace: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub create_trigger: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub create_view: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub drop_index: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub drop_sequence: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub drop_table: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub drop_tablespace: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub drop_trigger: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub drop_view: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inline_column_comment: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inline_table_comment: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment_value_description: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment_value_logical_name: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment_value_logical_name_description: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment_replace_line_feed: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment_replace_string: Option<String>,
+}
 
 impl From<entities::ExportSettings> for ExportSettings {
-    fn from(_: entities::ExportSettings) -> Self {
-        Self {}
+    fn from(entity: entities::ExportSettings) -> Self {
+        Self {
+            category_name_to_export: entity.category_name_to_export,
+            ddl_output: entity.ddl_output,
+            excel_output: entity.excel_output,
+            excel_template: entity.excel_template,
+            image_output: entity.image_output,
+            put_diagram_on_excel: entity.put_diagram_on_excel,
+            use_logical_name_as_sheet: entity.use_logical_name_as_sheet,
+            open_after_saved: entity.open_after_saved,
+            create_comment: entity.create_comment,
+            create_foreign_key: entity.create_foreign_key,
+            create_index: entity.create_index,
+            create_sequence: entity.create_sequence,
+            create_table: entity.create_table,
+            create_tablespace: entity.create_tablespace,
+            create_trigger: entity.create_trigger,
+            create_view: entity.create_view,
+            drop_index: entity.drop_index,
+            drop_sequence: entity.drop_sequence,
+            drop_table: entity.drop_table,
+            drop_tablespace: entity.drop_tablespace,
+            drop_trigger: entity.drop_trigger,
+            drop_view: entity.drop_view,
+            inline_column_comment: entity.inline_column_comment,
+            inline_table_comment: entity.inline_table_comment,
+            comment_value_description: entity.comment_value_description,
+            comment_value_logical_name: entity.comment_value_logical_name,
+            comment_value_logical_name_description: entity.comment_value_logical_name_description,
+            comment_replace_line_feed: entity.comment_replace_line_feed,
+            comment_replace_string: entity.comment_replace_string,
+        }
+    }
+}
+
+impl From<ExportSettings> for entities::ExportSettings {
+    fn from(dto: ExportSettings) -> Self {
+        Self {
+            category_name_to_export: dto.category_name_to_export,
+            ddl_output: dto.ddl_output,
+            excel_output: dto.excel_output,
+            excel_template: dto.excel_template,
+            image_output: dto.image_output,
+            put_diagram_on_excel: dto.put_diagram_on_excel,
+            use_logical_name_as_sheet: dto.use_logical_name_as_sheet,
+            open_after_saved: dto.open_after_saved,
+            create_comment: dto.create_comment,
+            create_foreign_key: dto.create_foreign_key,
+            create_index: dto.create_index,
+            create_sequence: dto.create_sequence,
+            create_table: dto.create_table,
+            create_tablespace: dto.create_tablespace,
+            create_trigger: dto.create_trigger,
+            create_view: dto.create_view,
+            drop_index: dto.drop_index,
+            drop_sequence: dto.drop_sequence,
+            drop_table: dto.drop_table,
+            drop_tablespace: dto.drop_tablespace,
+            drop_trigger: dto.drop_trigger,
+            drop_view: dto.drop_view,
+            inline_column_comment: dto.inline_column_comment,
+            inline_table_comment: dto.inline_table_comment,
+            comment_value_description: dto.comment_value_description,
+            comment_value_logical_name: dto.comment_value_logical_name,
+            comment_value_logical_name_description: dto.comment_value_logical_name_description,
+            comment_replace_line_feed: dto.comment_replace_line_feed,
+            comment_replace_string: dto.comment_replace_string,
+        }
     }
 }
 
-#[derive(Debug, PartialEq, Serialize, Deserialize, Validate)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct CategorySettings {}
 
@@ -22,7 +175,13 @@ impl From<entities::CategorySettings> for CategorySettings {
     }
 }
 
-#[derive(Debug, PartialEq, Serialize, Deserialize, Validate)]
+impl From<CategorySettings> for entities::CategorySettings {
+    fn from(_: CategorySettings) -> Self {
+        Self {}
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct ModelProperties {}
 
@@ -32,7 +191,13 @@ impl From<entities::ModelProperties> for ModelProperties {
     }
 }
 
-#[derive(Debug, PartialEq, Serialize, Deserialize, Validate)]
+impl From<ModelProperties> for entities::ModelProperties {
+    fn from(_: ModelProperties) -> Self {
+        Self {}
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct TableProperties {}
 
@@ -42,7 +207,13 @@ impl From<entities::TableProperties> for TableProperties {
     }
 }
 
-#[derive(Debug, PartialEq, Serialize, Deserialize, Validate)]
+impl From<TableProperties> for entities::TableProperties {
+    fn from(_: TableProperties) -> Self {
+        Self {}
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct EnvironmentSettings {}
 
@@ -52,17 +223,70 @@ impl From<entities::EnvironmentSettings> for EnvironmentSettings {
     }
 }
 
-#[derive(Debug, PartialEq, Serialize, Deserialize, Validate)]
+impl From<EnvironmentSettings> for entities::EnvironmentSettings {
+    fn from(_: EnvironmentSettings) -> Self {
+        Self {}
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate, Default)]
 #[serde(rename_all = "camelCase")]
-pub struct DesignSettings {}
+pub struct ConstraintPrefix {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_prefix: Option<String>,
+}
+
+impl From<entities::ConstraintPrefix> for ConstraintPrefix {
+    fn from(entity: entities::ConstraintPrefix) -> Self {
+        Self {
+            default_prefix: entity.default_prefix,
+        }
+    }
+}
+
+impl From<ConstraintPrefix> for entities::ConstraintPrefix {
+    fn from(dto: ConstraintPrefix) -> Self {
+        Self {
+            default_prefix: dto.default_prefix,
+        }
+    }
+}
+
+/// The prefixes new constraint names start with.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct DesignSettings {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub foreign_key: Option<ConstraintPrefix>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unique: Option<ConstraintPrefix>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub index: Option<ConstraintPrefix>,
+}
 
 impl From<entities::DesignSettings> for DesignSettings {
-    fn from(_: entities::DesignSettings) -> Self {
-        Self {}
+    fn from(entity: entities::DesignSettings) -> Self {
+        Self {
+            foreign_key: entity.foreign_key.map(Into::into),
+            unique: entity.unique.map(Into::into),
+            index: entity.index.map(Into::into),
+        }
     }
 }
 
-#[derive(Debug, PartialEq, Serialize, Deserialize, Validate)]
+impl From<DesignSettings> for entities::DesignSettings {
+    fn from(dto: DesignSettings) -> Self {
+        Self {
+            foreign_key: dto.foreign_key.map(Into::into),
+            unique: dto.unique.map(Into::into),
+            index: dto.index.map(Into::into),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct DiagramSettings {
     pub database: String,
@@ -117,3 +341,32 @@ impl From<entities::DiagramSettings> for DiagramSettings {
         }
     }
 }
+
+impl From<DiagramSettings> for entities::DiagramSettings {
+    fn from(dto: DiagramSettings) -> Self {
+        Self {
+            database: dto.database,
+            capital: dto.capital,
+            table_style: dto.table_style,
+            notation: dto.notation,
+            notation_level: dto.notation_level,
+            notation_expand_group: dto.notation_expand_group,
+            view_mode: dto.view_mode,
+            outline_view_mode: dto.outline_view_mode,
+            view_order_by: dto.view_order_by,
+            auto_ime_change: dto.auto_ime_change,
+            validate_physical_name: dto.validate_physical_name,
+            use_bezier_curve: dto.use_bezier_curve,
+            suspend_validator: dto.suspend_validator,
+            title_font_em: dto.title_font_em,
+            master_data_base_path: dto.master_data_base_path,
+            use_view_object: dto.use_view_object,
+            export_settings: dto.export_settings.into(),
+            category_settings: dto.category_settings.into(),
+            model_properties: dto.model_properties.into(),
+            table_properties: dto.table_properties.into(),
+            environment_settings: dto.environment_settings.map(Into::into),
+            design_settings: dto.design_settings.map(Into::into),
+        }
+    }
+}
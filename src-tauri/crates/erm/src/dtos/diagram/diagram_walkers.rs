@@ -1,25 +1,113 @@
+pub mod groups;
+pub mod images;
+pub mod notes;
 pub mod tables;
+pub mod views;
 
 use crate::entities::diagram::diagram_walkers as entities;
 use crate::validation::Validate;
 use crate::validation::diagram::diagram_walkers::{
-    validate_cross_table_references, validate_duplicate_relationship_names,
-    validate_duplicate_table_physical_names,
+    validate_comment_connections, validate_cross_table_references,
+    validate_duplicate_relationship_names, validate_duplicate_table_physical_names,
+    validate_duplicate_view_physical_names, validate_group_members, validate_image_data,
+    validate_view_column_references,
 };
+use groups::WalkerGroup;
+use images::Image;
+use notes::WalkerNote;
 use serde::{Deserialize, Serialize};
 use tables::Table;
+use views::View;
 
-#[derive(Debug, PartialEq, Serialize, Deserialize, Validate)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate)]
 #[validate(rules(
     validate_duplicate_table_physical_names,
     validate_duplicate_relationship_names,
-    validate_cross_table_references
+    validate_cross_table_references,
+    validate_duplicate_view_physical_names,
+    validate_view_column_references,
+    validate_comment_connections,
+    validate_group_members,
+    validate_image_data
 ))]
 #[serde(rename_all = "camelCase")]
 pub struct DiagramWalkers {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[validate(path = "table")]
     pub tables: Option<Vec<Table>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(path = "view")]
+    pub views: Option<Vec<View>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(path = "walker_note")]
+    pub notes: Option<Vec<WalkerNote>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(path = "walker_group")]
+    pub groups: Option<Vec<WalkerGroup>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(path = "image")]
+    pub images: Option<Vec<Image>>,
+}
+
+impl DiagramWalkers {
+    /// Moves the group named `name` by the given offset, taking its members along as dragging
+    /// the group on the canvas does. Coordinates stop at the canvas edges.
+    ///
+    /// Returns `false` when no group has that name.
+    pub fn move_group(&mut self, name: &str, dx: i32, dy: i32) -> bool {
+        let Some(group) = self
+            .groups
+            .iter_mut()
+            .flatten()
+            .find(|group| group.walker_group_name.as_deref() == Some(name))
+        else {
+            return false;
+        };
+
+        group.x = group.x.map(|x| offset(x, dx));
+        group.y = group.y.map(|y| offset(y, dy));
+        let members = group.members.clone().unwrap_or_default();
+
+        for table in self.tables.iter_mut().flatten() {
+            if members.contains(&format!("table.{}", table.physical_name)) {
+                table.x = offset(table.x, dx);
+                table.y = offset(table.y, dy);
+            }
+        }
+        for view in self.views.iter_mut().flatten() {
+            if members.contains(&format!("view.{}", view.physical_name)) {
+                view.x = view.x.map(|x| offset(x, dx));
+                view.y = view.y.map(|y| offset(y, dy));
+            }
+        }
+        for note in self.notes.iter_mut().flatten() {
+            if note.id.as_ref().is_some_and(|id| members.contains(id)) {
+                note.x = note.x.map(|x| offset(x, dx));
+                note.y = note.y.map(|y| offset(y, dy));
+            }
+        }
+
+        true
+    }
+
+    /// Removes the group named `name`. Its members stay on the diagram, ungrouped.
+    pub fn remove_group(&mut self, name: &str) -> Option<WalkerGroup> {
+        let groups = self.groups.as_mut()?;
+        let index = groups
+            .iter()
+            .position(|group| group.walker_group_name.as_deref() == Some(name))?;
+
+        Some(groups.remove(index))
+    }
+}
+
+fn offset(coordinate: u16, delta: i32) -> u16 {
+    let moved = (i32::from(coordinate) + delta).clamp(0, i32::from(u16::MAX));
+    u16::try_from(moved).unwrap_or(u16::MAX)
 }
 
 impl From<entities::DiagramWalkers> for DiagramWalkers {
@@ -28,6 +116,30 @@ impl From<entities::DiagramWalkers> for DiagramWalkers {
             tables: entity
                 .tables
                 .map(|v| v.into_iter().map(Into::into).collect()),
+            views: entity
+                .views
+                .map(|v| v.into_iter().map(Into::into).collect()),
+            notes: entity
+                .notes
+                .map(|v| v.into_iter().map(Into::into).collect()),
+            groups: entity
+                .groups
+                .map(|v| v.into_iter().map(Into::into).collect()),
+            images: entity
+                .images
+                .map(|v| v.into_iter().map(Into::into).collect()),
+        }
+    }
+}
+
+impl From<DiagramWalkers> for entities::DiagramWalkers {
+    fn from(dto: DiagramWalkers) -> Self {
+        Self {
+            tables: dto.tables.map(|v| v.into_iter().map(Into::into).collect()),
+            views: dto.views.map(|v| v.into_iter().map(Into::into).collect()),
+            notes: dto.notes.map(|v| v.into_iter().map(Into::into).collect()),
+            groups: dto.groups.map(|v| v.into_iter().map(Into::into).collect()),
+            images: dto.images.map(|v| v.into_iter().map(Into::into).collect()),
         }
     }
 }
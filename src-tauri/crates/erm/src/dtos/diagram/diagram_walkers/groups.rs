@@ -0,0 +1,81 @@
+use crate::entities::diagram::diagram_walkers::groups as entities;
+use serde::{Deserialize, Serialize};
+
+use super::tables::Color;
+use super::tables::connections::Connections;
+use crate::validation::Validate;
+
+/// A frame around walkers that belong together, e.g. the tables of one bounded context.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct WalkerGroup {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub height: Option<u16>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub width: Option<u16>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub font_name: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub font_size: Option<u16>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub x: Option<u16>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub y: Option<u16>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<Color>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub connections: Option<Connections>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub walker_group_name: Option<String>,
+
+    /// The walkers in the group, e.g. `table.MEMBERS`, `view.ACTIVE_MEMBERS` or `note.1`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub members: Option<Vec<String>>,
+}
+
+impl From<entities::WalkerGroup> for WalkerGroup {
+    fn from(entity: entities::WalkerGroup) -> Self {
+        Self {
+            id: entity.id,
+            height: entity.height,
+            width: entity.width,
+            font_name: entity.font_name,
+            font_size: entity.font_size,
+            x: entity.x,
+            y: entity.y,
+            color: entity.color.map(Into::into),
+            connections: entity.connections.map(Into::into),
+            walker_group_name: entity.walker_group_name,
+            members: entity.members,
+        }
+    }
+}
+
+impl From<WalkerGroup> for entities::WalkerGroup {
+    fn from(dto: WalkerGroup) -> Self {
+        Self {
+            id: dto.id,
+            height: dto.height,
+            width: dto.width,
+            font_name: dto.font_name,
+            font_size: dto.font_size,
+            x: dto.x,
+            y: dto.y,
+            color: dto.color.map(Into::into),
+            connections: dto.connections.map(Into::into),
+            walker_group_name: dto.walker_group_name,
+            members: dto.members,
+        }
+    }
+}
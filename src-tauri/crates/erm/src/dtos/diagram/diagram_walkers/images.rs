@@ -0,0 +1,173 @@
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+use crate::entities::diagram::diagram_walkers::images as entities;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use serde::{Deserialize, Serialize};
+
+use super::tables::Color;
+use super::tables::connections::Connections;
+use crate::validation::Validate;
+
+/// The formats an inserted image is recognized as, by the signature at the start of its data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub enum ImageFormat {
+    Png,
+    Jpeg,
+    Gif,
+}
+
+impl ImageFormat {
+    pub fn sniff(bytes: &[u8]) -> Option<Self> {
+        if bytes.starts_with(b"\x89PNG\r\n\x1a\n") {
+            Some(Self::Png)
+        } else if bytes.starts_with(b"\xff\xd8\xff") {
+            Some(Self::Jpeg)
+        } else if bytes.starts_with(b"GIF87a") || bytes.starts_with(b"GIF89a") {
+            Some(Self::Gif)
+        } else {
+            None
+        }
+    }
+
+    pub fn mime_type(self) -> &'static str {
+        match self {
+            Self::Png => "image/png",
+            Self::Jpeg => "image/jpeg",
+            Self::Gif => "image/gif",
+        }
+    }
+}
+
+/// An image inserted into the diagram.
+///
+/// Its data can be large, so it is never sent to the frontend with the rest of the diagram;
+/// [`crate::open_image`] reads it on demand instead, and saving restores it from the file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct Image {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub height: Option<u16>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub width: Option<u16>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub font_name: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub font_size: Option<u16>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub x: Option<u16>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub y: Option<u16>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<Color>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub connections: Option<Connections>,
+
+    /// The image file, base64 encoded.
+    #[serde(default, skip_serializing)]
+    pub data: String,
+
+    /// Identifies `data` when the image is read, so that saving a diagram sent back without it
+    /// restores it from the file however the images were reordered or deleted since.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data_digest: Option<String>,
+
+    /// Sniffed from `data` when the image is read, or `None` when it is not a known format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub format: Option<ImageFormat>,
+
+    pub hue: i32,
+
+    pub saturation: i32,
+
+    pub brightness: i32,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alpha: Option<i32>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fix_aspect_ratio: Option<bool>,
+}
+
+impl Image {
+    /// Decodes `data`, ignoring the line breaks some writers wrap it with.
+    pub fn bytes(&self) -> Result<Vec<u8>, base64::DecodeError> {
+        let data = self
+            .data
+            .chars()
+            .filter(|c| !c.is_ascii_whitespace())
+            .collect::<String>();
+
+        STANDARD.decode(data)
+    }
+}
+
+impl From<entities::Image> for Image {
+    fn from(entity: entities::Image) -> Self {
+        let mut image = Self {
+            id: entity.id,
+            height: entity.height,
+            width: entity.width,
+            font_name: entity.font_name,
+            font_size: entity.font_size,
+            x: entity.x,
+            y: entity.y,
+            color: entity.color.map(Into::into),
+            connections: entity.connections.map(Into::into),
+            data_digest: Some(digest(&entity.data)),
+            data: entity.data,
+            format: None,
+            hue: entity.hue,
+            saturation: entity.saturation,
+            brightness: entity.brightness,
+            alpha: entity.alpha,
+            fix_aspect_ratio: entity.fix_aspect_ratio,
+        };
+        image.format = image
+            .bytes()
+            .ok()
+            .and_then(|bytes| ImageFormat::sniff(&bytes));
+
+        image
+    }
+}
+
+impl From<Image> for entities::Image {
+    fn from(dto: Image) -> Self {
+        Self {
+            id: dto.id,
+            height: dto.height,
+            width: dto.width,
+            font_name: dto.font_name,
+            font_size: dto.font_size,
+            x: dto.x,
+            y: dto.y,
+            color: dto.color.map(Into::into),
+            connections: dto.connections.map(Into::into),
+            data: dto.data,
+            hue: dto.hue,
+            saturation: dto.saturation,
+            brightness: dto.brightness,
+            alpha: dto.alpha,
+            fix_aspect_ratio: dto.fix_aspect_ratio,
+        }
+    }
+}
+
+fn digest(data: &str) -> String {
+    let mut hasher = DefaultHasher::new();
+    data.hash(&mut hasher);
+
+    format!("{:016x}", hasher.finish())
+}
@@ -0,0 +1,76 @@
+use crate::entities::diagram::diagram_walkers::notes as entities;
+use serde::{Deserialize, Serialize};
+
+use super::tables::Color;
+use super::tables::connections::Connections;
+use crate::validation::Validate;
+
+/// A sticky note, which comment connections link to the walkers it explains.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct WalkerNote {
+    /// The identifier comment connections refer to, e.g. `note.1`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub height: Option<u16>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub width: Option<u16>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub font_name: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub font_size: Option<u16>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub x: Option<u16>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub y: Option<u16>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<Color>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub connections: Option<Connections>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note_text: Option<String>,
+}
+
+impl From<entities::WalkerNote> for WalkerNote {
+    fn from(entity: entities::WalkerNote) -> Self {
+        Self {
+            id: entity.id,
+            height: entity.height,
+            width: entity.width,
+            font_name: entity.font_name,
+            font_size: entity.font_size,
+            x: entity.x,
+            y: entity.y,
+            color: entity.color.map(Into::into),
+            connections: entity.connections.map(Into::into),
+            note_text: entity.note_text,
+        }
+    }
+}
+
+impl From<WalkerNote> for entities::WalkerNote {
+    fn from(dto: WalkerNote) -> Self {
+        Self {
+            id: dto.id,
+            height: dto.height,
+            width: dto.width,
+            font_name: dto.font_name,
+            font_size: dto.font_size,
+            x: dto.x,
+            y: dto.y,
+            color: dto.color.map(Into::into),
+            connections: dto.connections.map(Into::into),
+            note_text: dto.note_text,
+        }
+    }
+}
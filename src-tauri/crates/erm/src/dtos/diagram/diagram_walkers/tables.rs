@@ -13,12 +13,15 @@ use serde::{Deserialize, Serialize};
 use crate::validation::Validate;
 use crate::validation::diagram::diagram_walkers::tables::{
     validate_auto_increment_columns_are_key_columns, validate_column_length_and_decimal,
-    validate_compound_unique_key_column_references, validate_duplicate_column_physical_names,
+    validate_compound_unique_key_column_references,
+    validate_compound_unique_key_columns_are_not_null, validate_duplicate_column_physical_names,
     validate_duplicate_compound_unique_key_names, validate_duplicate_index_names,
-    validate_index_column_references, validate_local_relationship_consistency,
+    validate_foreign_key_columns_are_indexed, validate_index_column_references,
+    validate_local_relationship_consistency, validate_logical_names_are_not_empty,
+    validate_table_has_primary_key,
 };
 
-#[derive(Debug, PartialEq, Serialize, Deserialize, Validate)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct Color {
     pub r: u8,
@@ -36,7 +39,17 @@ impl From<entities::Color> for Color {
     }
 }
 
-#[derive(Debug, PartialEq, Serialize, Deserialize, Validate)]
+impl From<Color> for entities::Color {
+    fn from(dto: Color) -> Self {
+        Self {
+            r: dto.r,
+            g: dto.g,
+            b: dto.b,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate)]
 #[validate(rules(
     validate_duplicate_column_physical_names,
     validate_duplicate_index_names,
@@ -45,7 +58,11 @@ impl From<entities::Color> for Color {
     validate_column_length_and_decimal,
     validate_index_column_references,
     validate_compound_unique_key_column_references,
-    validate_local_relationship_consistency
+    validate_local_relationship_consistency,
+    validate_table_has_primary_key,
+    validate_foreign_key_columns_are_indexed,
+    validate_compound_unique_key_columns_are_not_null,
+    validate_logical_names_are_not_empty
 ))]
 #[serde(rename_all = "camelCase")]
 pub struct Table {
@@ -116,3 +133,30 @@ impl From<entities::Table> for Table {
         }
     }
 }
+
+impl From<Table> for entities::Table {
+    fn from(dto: Table) -> Self {
+        Self {
+            physical_name: dto.physical_name,
+            logical_name: dto.logical_name,
+            description: dto.description,
+            height: dto.height,
+            width: dto.width,
+            font_name: dto.font_name,
+            font_size: dto.font_size,
+            x: dto.x,
+            y: dto.y,
+            color: dto.color.into(),
+            connections: dto.connections.into(),
+            table_constraint: dto.table_constraint,
+            primary_key_name: dto.primary_key_name,
+            option: dto.option,
+            columns: dto.columns.into(),
+            indexes: entities::indexes::Indexes {
+                indexes: dto.indexes.map(|v| v.into_iter().map(Into::into).collect()),
+            },
+            compound_unique_key_list: dto.compound_unique_key_list.into(),
+            table_properties: entities::TableProperties {},
+        }
+    }
+}
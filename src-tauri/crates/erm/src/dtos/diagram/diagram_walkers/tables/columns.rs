@@ -4,7 +4,7 @@ use crate::entities::diagram::diagram_walkers::tables::columns as entities;
 use crate::validation::Validate;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, PartialEq, Serialize, Deserialize, Default, Validate)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct NormalColumn {
     pub physical_name: String,
@@ -74,14 +74,36 @@ impl From<entities::NormalColumn> for NormalColumn {
     }
 }
 
-#[derive(Debug, PartialEq, Serialize, Deserialize, Validate)]
+impl From<NormalColumn> for entities::NormalColumn {
+    fn from(dto: NormalColumn) -> Self {
+        Self {
+            physical_name: dto.physical_name,
+            logical_name: dto.logical_name,
+            description: dto.description,
+            column_type: dto.column_type,
+            length: dto.length,
+            decimal: dto.decimal,
+            args: dto.args,
+            unsigned: dto.unsigned,
+            not_null: dto.not_null,
+            unique_key: dto.unique_key,
+            default_value: dto.default_value,
+            primary_key: dto.primary_key,
+            auto_increment: dto.auto_increment,
+            referred_column: dto.referred_column,
+            relationship: dto.relationship,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate)]
 #[serde(untagged)]
 pub enum ColumnItem {
     Normal(NormalColumn),
     Group(String),
 }
 
-#[derive(Debug, PartialEq, Serialize, Deserialize, Validate)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct Columns {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -102,3 +124,18 @@ impl From<entities::Columns> for Columns {
         }
     }
 }
+
+impl From<Columns> for entities::Columns {
+    fn from(dto: Columns) -> Self {
+        Self {
+            items: dto.items.map(|v| {
+                v.into_iter()
+                    .map(|item| match item {
+                        ColumnItem::Normal(column) => entities::ColumnItem::Normal(column.into()),
+                        ColumnItem::Group(column) => entities::ColumnItem::Group(column),
+                    })
+                    .collect()
+            }),
+        }
+    }
+}
@@ -2,7 +2,7 @@ use crate::entities::diagram::diagram_walkers::tables::compound_unique_key_list
 use crate::validation::Validate;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, PartialEq, Serialize, Deserialize, Validate)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct Column {
     pub column_id: String,
@@ -16,7 +16,15 @@ impl From<entities::Column> for Column {
     }
 }
 
-#[derive(Debug, PartialEq, Serialize, Deserialize, Validate)]
+impl From<Column> for entities::Column {
+    fn from(dto: Column) -> Self {
+        Self {
+            column_id: dto.column_id,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct CompoundUniqueKey {
     pub name: String,
@@ -32,7 +40,18 @@ impl From<entities::CompoundUniqueKey> for CompoundUniqueKey {
     }
 }
 
-#[derive(Debug, PartialEq, Serialize, Deserialize, Validate)]
+impl From<CompoundUniqueKey> for entities::CompoundUniqueKey {
+    fn from(dto: CompoundUniqueKey) -> Self {
+        Self {
+            name: dto.name,
+            columns: entities::Columns {
+                columns: dto.columns.into_iter().map(Into::into).collect(),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct CompoundUniqueKeyList {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -48,3 +67,13 @@ impl From<entities::CompoundUniqueKeyList> for CompoundUniqueKeyList {
         }
     }
 }
+
+impl From<CompoundUniqueKeyList> for entities::CompoundUniqueKeyList {
+    fn from(dto: CompoundUniqueKeyList) -> Self {
+        Self {
+            compound_unique_keys: dto
+                .compound_unique_keys
+                .map(|v| v.into_iter().map(Into::into).collect()),
+        }
+    }
+}
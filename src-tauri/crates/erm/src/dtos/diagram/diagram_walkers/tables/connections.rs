@@ -6,7 +6,7 @@ use crate::entities::diagram::diagram_walkers::tables::connections as entities;
 use crate::validation::Validate;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, PartialEq, Serialize, Deserialize, Validate)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct Bendpoint {
     pub relative: bool,
@@ -24,7 +24,17 @@ impl From<entities::Bendpoint> for Bendpoint {
     }
 }
 
-#[derive(Debug, PartialEq, Serialize, Deserialize, Validate)]
+impl From<Bendpoint> for entities::Bendpoint {
+    fn from(dto: Bendpoint) -> Self {
+        Self {
+            relative: dto.relative,
+            x: dto.x,
+            y: dto.y,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct FkColumn {
     pub fk_column_name: String,
@@ -38,7 +48,15 @@ impl From<entities::FkColumn> for FkColumn {
     }
 }
 
-#[derive(Debug, PartialEq, Serialize, Deserialize, Default, Validate)]
+impl From<FkColumn> for entities::FkColumn {
+    fn from(dto: FkColumn) -> Self {
+        Self {
+            fk_column_name: dto.fk_column_name,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct FkColumns {
     #[serde(default)]
@@ -53,7 +71,15 @@ impl From<entities::FkColumns> for FkColumns {
     }
 }
 
-#[derive(Debug, PartialEq, Serialize, Deserialize, Validate)]
+impl From<FkColumns> for entities::FkColumns {
+    fn from(dto: FkColumns) -> Self {
+        Self {
+            fk_column: dto.fk_column.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct Relationship {
     pub name: String,
@@ -107,11 +133,76 @@ impl From<entities::Relationship> for Relationship {
     }
 }
 
-#[derive(Debug, PartialEq, Serialize, Deserialize, Default, Validate)]
+impl From<Relationship> for entities::Relationship {
+    fn from(dto: Relationship) -> Self {
+        Self {
+            name: dto.name,
+            source: dto.source,
+            target: dto.target,
+            bendpoints: dto
+                .bendpoints
+                .map(|v| v.into_iter().map(Into::into).collect()),
+            fk_columns: dto.fk_columns.into(),
+            parent_cardinality: dto.parent_cardinality,
+            child_cardinality: dto.child_cardinality,
+            reference_for_pk: dto.reference_for_pk,
+            on_delete_action: dto.on_delete_action,
+            on_update_action: dto.on_update_action,
+            referred_simple_unique_column: dto.referred_simple_unique_column,
+            referred_compound_unique_key: dto.referred_compound_unique_key,
+        }
+    }
+}
+
+/// A line from a note to the walker it comments on, e.g. from `note.1` to `table.MEMBERS`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentConnection {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    pub source: String,
+
+    pub target: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bendpoints: Option<Vec<Bendpoint>>,
+}
+
+impl From<entities::CommentConnection> for CommentConnection {
+    fn from(entity: entities::CommentConnection) -> Self {
+        Self {
+            id: entity.id,
+            source: entity.source,
+            target: entity.target,
+            bendpoints: entity
+                .bendpoints
+                .map(|v| v.into_iter().map(Into::into).collect()),
+        }
+    }
+}
+
+impl From<CommentConnection> for entities::CommentConnection {
+    fn from(dto: CommentConnection) -> Self {
+        Self {
+            id: dto.id,
+            source: dto.source,
+            target: dto.target,
+            bendpoints: dto
+                .bendpoints
+                .map(|v| v.into_iter().map(Into::into).collect()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct Connections {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub relationships: Option<Vec<Relationship>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment_connections: Option<Vec<CommentConnection>>,
 }
 
 impl From<entities::Connections> for Connections {
@@ -120,6 +211,22 @@ impl From<entities::Connections> for Connections {
             relationships: entity
                 .relationships
                 .map(|v| v.into_iter().map(Into::into).collect()),
+            comment_connections: entity
+                .comment_connections
+                .map(|v| v.into_iter().map(Into::into).collect()),
+        }
+    }
+}
+
+impl From<Connections> for entities::Connections {
+    fn from(dto: Connections) -> Self {
+        Self {
+            relationships: dto
+                .relationships
+                .map(|v| v.into_iter().map(Into::into).collect()),
+            comment_connections: dto
+                .comment_connections
+                .map(|v| v.into_iter().map(Into::into).collect()),
         }
     }
 }
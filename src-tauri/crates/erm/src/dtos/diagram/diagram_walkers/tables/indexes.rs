@@ -2,7 +2,7 @@ use crate::entities::diagram::diagram_walkers::tables::indexes as entities;
 use crate::validation::Validate;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, PartialEq, Serialize, Deserialize, Validate)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct Column {
     pub column_id: String,
@@ -20,7 +20,16 @@ impl From<entities::Column> for Column {
     }
 }
 
-#[derive(Debug, PartialEq, Serialize, Deserialize, Validate)]
+impl From<Column> for entities::Column {
+    fn from(dto: Column) -> Self {
+        Self {
+            column_id: dto.column_id,
+            desc: dto.desc,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct Index {
     pub name: String,
@@ -51,3 +60,18 @@ impl From<entities::Index> for Index {
         }
     }
 }
+
+impl From<Index> for entities::Index {
+    fn from(dto: Index) -> Self {
+        Self {
+            name: dto.name,
+            index_type: dto.index_type,
+            description: dto.description,
+            full_text: dto.full_text,
+            non_unique: dto.non_unique,
+            columns: entities::Columns {
+                columns: dto.columns.into_iter().map(Into::into).collect(),
+            },
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use crate::entities::diagram::diagram_walkers::views as entities;
+use serde::{Deserialize, Serialize};
+
+use super::tables::Color;
+use super::tables::columns::Columns;
+use super::tables::connections::Connections;
+use crate::validation::Validate;
+use crate::validation::diagram::diagram_walkers::views::{
+    validate_duplicate_view_column_physical_names, validate_view_column_length_and_decimal,
+};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate)]
+#[validate(rules(
+    validate_duplicate_view_column_physical_names,
+    validate_view_column_length_and_decimal
+))]
+#[serde(rename_all = "camelCase")]
+pub struct View {
+    pub physical_name: String,
+
+    pub logical_name: String,
+
+    pub description: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub height: Option<u16>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub width: Option<u16>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub font_name: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub font_size: Option<u16>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub x: Option<u16>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub y: Option<u16>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<Color>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub connections: Option<Connections>,
+
+    /// The query of the view, without `CREATE VIEW`.
+    pub sql: String,
+
+    pub columns: Columns,
+}
+
+impl From<entities::View> for View {
+    fn from(entity: entities::View) -> Self {
+        Self {
+            physical_name: entity.physical_name,
+            logical_name: entity.logical_name,
+            description: entity.description,
+            height: entity.height,
+            width: entity.width,
+            font_name: entity.font_name,
+            font_size: entity.font_size,
+            x: entity.x,
+            y: entity.y,
+            color: entity.color.map(Into::into),
+            connections: entity.connections.map(Into::into),
+            sql: entity.sql,
+            columns: entity.columns.into(),
+        }
+    }
+}
+
+impl From<View> for entities::View {
+    fn from(dto: View) -> Self {
+        Self {
+            physical_name: dto.physical_name,
+            logical_name: dto.logical_name,
+            description: dto.description,
+            height: dto.height,
+            width: dto.width,
+            font_name: dto.font_name,
+            font_size: dto.font_size,
+            x: dto.x,
+            y: dto.y,
+            color: dto.color.map(Into::into),
+            connections: dto.connections.map(Into::into),
+            sql: dto.sql,
+            columns: dto.columns.into(),
+            view_properties: entities::ViewProperties {},
+        }
+    }
+}
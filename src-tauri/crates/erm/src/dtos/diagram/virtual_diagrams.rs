@@ -0,0 +1,125 @@
+use crate::entities::diagram::virtual_diagrams as entities;
+use serde::{Deserialize, Serialize};
+
+use super::Color;
+use super::diagram_walkers::groups::WalkerGroup;
+use super::diagram_walkers::notes::WalkerNote;
+use crate::validation::Validate;
+
+/// A named sub-view of the diagram showing some of its tables, each at its own position, along
+/// with notes and groups of its own.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct VirtualDiagram {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vdiagram_id: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vdiagram_name: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<Color>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tables: Option<Vec<VirtualTable>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notes: Option<Vec<WalkerNote>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub groups: Option<Vec<WalkerGroup>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct VirtualTable {
+    /// The table shown, e.g. `table.MEMBERS`.
+    pub table_id: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub x: Option<u16>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub y: Option<u16>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub width: Option<u16>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub height: Option<u16>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub font_name: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub font_size: Option<u16>,
+}
+
+impl From<entities::VirtualDiagram> for VirtualDiagram {
+    fn from(entity: entities::VirtualDiagram) -> Self {
+        Self {
+            vdiagram_id: entity.vdiagram_id,
+            vdiagram_name: entity.vdiagram_name,
+            color: entity.color.map(Into::into),
+            tables: entity
+                .vtables
+                .and_then(|tables| tables.vtables)
+                .map(|v| v.into_iter().map(Into::into).collect()),
+            notes: entity
+                .walker_notes
+                .and_then(|notes| notes.notes)
+                .map(|v| v.into_iter().map(Into::into).collect()),
+            groups: entity
+                .walker_groups
+                .and_then(|groups| groups.groups)
+                .map(|v| v.into_iter().map(Into::into).collect()),
+        }
+    }
+}
+
+impl From<VirtualDiagram> for entities::VirtualDiagram {
+    fn from(dto: VirtualDiagram) -> Self {
+        Self {
+            vdiagram_id: dto.vdiagram_id,
+            vdiagram_name: dto.vdiagram_name,
+            color: dto.color.map(Into::into),
+            vtables: dto.tables.map(|v| entities::VirtualTables {
+                vtables: Some(v.into_iter().map(Into::into).collect()),
+            }),
+            walker_notes: dto.notes.map(|v| entities::WalkerNotes {
+                notes: Some(v.into_iter().map(Into::into).collect()),
+            }),
+            walker_groups: dto.groups.map(|v| entities::WalkerGroups {
+                groups: Some(v.into_iter().map(Into::into).collect()),
+            }),
+        }
+    }
+}
+
+impl From<entities::VirtualTable> for VirtualTable {
+    fn from(entity: entities::VirtualTable) -> Self {
+        Self {
+            table_id: entity.table_id,
+            x: entity.x,
+            y: entity.y,
+            width: entity.width,
+            height: entity.height,
+            font_name: entity.font_name,
+            font_size: entity.font_size,
+        }
+    }
+}
+
+impl From<VirtualTable> for entities::VirtualTable {
+    fn from(dto: VirtualTable) -> Self {
+        Self {
+            table_id: dto.table_id,
+            x: dto.x,
+            y: dto.y,
+            width: dto.width,
+            height: dto.height,
+            font_name: dto.font_name,
+            font_size: dto.font_size,
+        }
+    }
+}
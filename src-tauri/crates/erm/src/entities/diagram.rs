@@ -2,21 +2,27 @@ pub mod column_groups;
 pub mod diagram_settings;
 pub mod diagram_walkers;
 pub mod page_settings;
+pub mod unknown_elements;
+pub mod virtual_diagrams;
+pub mod xml_layout;
 
 use column_groups::ColumnGroups;
 use diagram_settings::DiagramSettings;
 use diagram_walkers::DiagramWalkers;
 use page_settings::PageSettings;
 use serde::{Deserialize, Serialize};
+use unknown_elements::UnknownElements;
+use virtual_diagrams::VirtualDiagrams;
+use xml_layout::XmlLayout;
 
-#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Color {
     pub r: u8,
     pub g: u8,
     pub b: u8,
 }
 
-#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Diagram {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub presenter: Option<String>,
@@ -56,6 +62,17 @@ pub struct Diagram {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub diagram_walkers: Option<DiagramWalkers>,
 
+    /// Older files name this element `ermodels`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "vdiagrams", alias = "ermodels")]
+    pub virtual_diagrams: Option<VirtualDiagrams>,
+
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub column_groups: Option<ColumnGroups>,
+
+    #[serde(skip)]
+    pub unknown_elements: UnknownElements,
+
+    #[serde(skip)]
+    pub xml_layout: XmlLayout,
 }
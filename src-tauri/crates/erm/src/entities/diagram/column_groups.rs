@@ -1,16 +1,13 @@
 use crate::column_type::ColumnType;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
 pub struct NormalColumn {
     pub physical_name: String,
 
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub logical_name: Option<String>,
 
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub description: Option<String>,
-
     #[serde(rename = "type")]
     pub column_type: ColumnType,
 
@@ -20,36 +17,39 @@ pub struct NormalColumn {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub decimal: Option<u16>,
 
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unsigned: Option<bool>,
+
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub args: Option<String>,
 
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub not_null: Option<bool>,
+    pub description: Option<String>,
 
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub unique_key: Option<bool>,
+    pub not_null: Option<bool>,
 
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub unsigned: Option<bool>,
+    pub unique_key: Option<bool>,
 
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub default_value: Option<String>,
 }
 
-#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Columns {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[serde(rename = "normal_column")]
     pub normal_columns: Option<Vec<NormalColumn>>,
 }
 
-#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ColumnGroup {
     pub column_group_name: String,
     pub columns: Columns,
 }
 
-#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ColumnGroups {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[serde(rename = "column_group")]
@@ -1,24 +1,130 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, PartialEq, Serialize, Deserialize)]
-pub struct ExportSettings {}
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct ExportSettings {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category_name_to_export: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ddl_output: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub excel_output: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub excel_template: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image_output: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub put_diagram_on_excel: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub use_logical_name_as_sheet: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub open_after_saved: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub create_comment: Option<bool>,
+
+    #[serde(
+        default,
+        rename = "create_foreignKey",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub create_foreign_key: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub create_index: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub create_sequence: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub create_table: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub create_tablespace: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub create_trigger: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub create_view: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub drop_index: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub drop_sequence: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub drop_table: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub drop_tablespace: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub drop_trigger: Option<bool>,
 
-#[derive(Debug, PartialEq, Serialize, Deserialize)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub drop_view: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inline_column_comment: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inline_table_comment: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment_value_description: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment_value_logical_name: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment_value_logical_name_description: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment_replace_line_feed: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment_replace_string: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CategorySettings {}
 
-#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ModelProperties {}
 
-#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TableProperties {}
 
-#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EnvironmentSettings {}
 
-#[derive(Debug, PartialEq, Serialize, Deserialize)]
-pub struct DesignSettings {}
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct ConstraintPrefix {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_prefix: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct DesignSettings {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub foreign_key: Option<ConstraintPrefix>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unique: Option<ConstraintPrefix>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub index: Option<ConstraintPrefix>,
+}
 
-#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DiagramSettings {
     pub database: String,
     pub capital: bool,
@@ -1,11 +1,35 @@
+pub mod groups;
+pub mod images;
+pub mod notes;
 pub mod tables;
+pub mod views;
 
+use groups::WalkerGroup;
+use images::Image;
+use notes::WalkerNote;
 use serde::{Deserialize, Serialize};
 use tables::Table;
+use views::View;
 
-#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DiagramWalkers {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[serde(rename = "table")]
     pub tables: Option<Vec<Table>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "view")]
+    pub views: Option<Vec<View>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "walker_note", alias = "note")]
+    pub notes: Option<Vec<WalkerNote>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "walker_group", alias = "group")]
+    pub groups: Option<Vec<WalkerGroup>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "image", alias = "inserted_image")]
+    pub images: Option<Vec<Image>>,
 }
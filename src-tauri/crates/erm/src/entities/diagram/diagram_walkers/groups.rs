@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+
+use super::tables::Color;
+use super::tables::connections::Connections;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WalkerGroup {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub height: Option<u16>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub width: Option<u16>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub font_name: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub font_size: Option<u16>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub x: Option<u16>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub y: Option<u16>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<Color>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub connections: Option<Connections>,
+
+    /// Older files name this element `name`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(alias = "name")]
+    pub walker_group_name: Option<String>,
+
+    /// Older files name these elements `node_element`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "diagram_walker", alias = "node_element")]
+    pub members: Option<Vec<String>>,
+}
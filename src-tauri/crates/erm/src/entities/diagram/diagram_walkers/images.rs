@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+
+use super::tables::Color;
+use super::tables::connections::Connections;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Image {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub height: Option<u16>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub width: Option<u16>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub font_name: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub font_size: Option<u16>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub x: Option<u16>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub y: Option<u16>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<Color>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub connections: Option<Connections>,
+
+    /// The image file, base64 encoded.
+    pub data: String,
+
+    pub hue: i32,
+
+    pub saturation: i32,
+
+    pub brightness: i32,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alpha: Option<i32>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fix_aspect_ratio: Option<bool>,
+}
@@ -2,16 +2,13 @@ use crate::column_type::ColumnType;
 use serde::de::IntoDeserializer;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
 pub struct NormalColumn {
     pub physical_name: String,
 
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub logical_name: Option<String>,
 
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub description: Option<String>,
-
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[serde(deserialize_with = "deserialize_optional_column_type")]
     #[serde(rename = "type")]
@@ -23,35 +20,38 @@ pub struct NormalColumn {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub decimal: Option<u16>,
 
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unsigned: Option<bool>,
+
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub args: Option<String>,
 
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub unsigned: Option<bool>,
+    pub referred_column: Option<String>,
 
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub not_null: Option<bool>,
+    pub relationship: Option<String>,
 
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub unique_key: Option<bool>,
+    pub description: Option<String>,
 
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub default_value: Option<String>,
+    pub not_null: Option<bool>,
 
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub primary_key: Option<bool>,
 
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub auto_increment: Option<bool>,
+    pub unique_key: Option<bool>,
 
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub referred_column: Option<String>,
+    pub auto_increment: Option<bool>,
 
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub relationship: Option<String>,
+    pub default_value: Option<String>,
 }
 
-#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum ColumnItem {
     #[serde(rename = "normal_column")]
     Normal(NormalColumn),
@@ -60,7 +60,7 @@ pub enum ColumnItem {
     Group(String),
 }
 
-#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Columns {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[serde(rename = "$value")]
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum OnAction {
     Restrict,
     Cascade,
@@ -51,7 +51,7 @@ impl<'de> Deserialize<'de> for OnAction {
     }
 }
 
-#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum ParentCardinality {
     #[serde(rename = "1")]
     One,
@@ -68,7 +68,7 @@ impl ParentCardinality {
     }
 }
 
-#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum ChildCardinality {
     #[serde(rename = "1..n")]
     OneOrMore,
@@ -91,25 +91,25 @@ impl ChildCardinality {
     }
 }
 
-#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Bendpoint {
     pub relative: bool,
     pub x: u16,
     pub y: u16,
 }
 
-#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FkColumn {
     pub fk_column_name: String,
 }
 
-#[derive(Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
 pub struct FkColumns {
     #[serde(default)]
     pub fk_column: Vec<FkColumn>,
 }
 
-#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Relationship {
     pub name: String,
 
@@ -135,16 +135,34 @@ pub struct Relationship {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub on_update_action: Option<OnAction>,
 
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub referred_compound_unique_key: Option<String>,
+
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub referred_simple_unique_column: Option<String>,
+}
 
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CommentConnection {
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub referred_compound_unique_key: Option<String>,
+    pub id: Option<String>,
+
+    pub source: String,
+
+    pub target: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "bendpoint")]
+    pub bendpoints: Option<Vec<Bendpoint>>,
 }
 
-#[derive(Debug, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
 pub struct Connections {
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     #[serde(rename = "relationship")]
     pub relationships: Option<Vec<Relationship>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "comment_connection")]
+    pub comment_connections: Option<Vec<CommentConnection>>,
 }
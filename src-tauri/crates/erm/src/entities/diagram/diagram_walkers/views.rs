@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+
+use super::tables::Color;
+use super::tables::columns::Columns;
+use super::tables::connections::Connections;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ViewProperties {}
+
+/// Unlike tables, views may leave out their position, color and connections.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct View {
+    pub physical_name: String,
+
+    pub logical_name: String,
+
+    pub description: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub height: Option<u16>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub width: Option<u16>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub font_name: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub font_size: Option<u16>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub x: Option<u16>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub y: Option<u16>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<Color>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub connections: Option<Connections>,
+
+    pub sql: String,
+
+    pub columns: Columns,
+
+    pub view_properties: ViewProperties,
+}
@@ -0,0 +1,34 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+/// XML elements the model does not understand, kept so that saving does not drop them.
+///
+/// Elements are grouped by the key path of their parent element (e.g.
+/// `diagram/diagram_walkers/table[MEMBERS]`) and listed in document order.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct UnknownElements(BTreeMap<String, Vec<UnknownElement>>);
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnknownElement {
+    /// Key of the sibling element that preceded this one, or `None` when it came first.
+    pub anchor: Option<String>,
+
+    /// The element and its whole subtree, serialized as XML.
+    pub xml: String,
+}
+
+impl UnknownElements {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn get(&self, path: &str) -> &[UnknownElement] {
+        self.0.get(path).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    pub fn push(&mut self, path: impl Into<String>, element: UnknownElement) {
+        self.0.entry(path.into()).or_default().push(element);
+    }
+}
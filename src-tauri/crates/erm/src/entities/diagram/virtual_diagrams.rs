@@ -0,0 +1,83 @@
+use serde::{Deserialize, Serialize};
+
+use super::Color;
+use super::diagram_walkers::groups::WalkerGroup;
+use super::diagram_walkers::notes::WalkerNote;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VirtualDiagrams {
+    /// Older files name these elements `ermodel`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "vdiagram", alias = "ermodel")]
+    pub virtual_diagrams: Option<Vec<VirtualDiagram>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VirtualDiagram {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vdiagram_id: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(alias = "name")]
+    pub vdiagram_name: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<Color>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vtables: Option<VirtualTables>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(alias = "notes")]
+    pub walker_notes: Option<WalkerNotes>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(alias = "groups")]
+    pub walker_groups: Option<WalkerGroups>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VirtualTables {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "vtable")]
+    pub vtables: Option<Vec<VirtualTable>>,
+}
+
+/// A table placed on a virtual diagram, at its own position.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VirtualTable {
+    #[serde(alias = "id")]
+    pub table_id: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub x: Option<u16>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub y: Option<u16>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub width: Option<u16>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub height: Option<u16>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub font_name: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub font_size: Option<u16>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WalkerNotes {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "walker_note", alias = "note")]
+    pub notes: Option<Vec<WalkerNote>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WalkerGroups {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "walker_group", alias = "group")]
+    pub groups: Option<Vec<WalkerGroup>>,
+}
@@ -0,0 +1,49 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+/// How the file a diagram was read from laid out the modelled elements, kept so that saving in
+/// the preserved format writes them back the same way.
+///
+/// Entries are grouped by the key path of the parent element, like unknown elements, and only
+/// record where the file differs from how the model writes it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct XmlLayout(BTreeMap<String, ChildLayout>);
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ChildLayout {
+    /// Keys of the child elements in the order the file listed them.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub order: Vec<String>,
+
+    /// Tag names the file used in place of the modelled ones (e.g. `note` for `walker_note`), by
+    /// the key of the child element.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub names: BTreeMap<String, String>,
+
+    /// Whether empty child elements were written across two lines, by key, where the file did
+    /// not follow ERFlute's convention of doing so for containers only.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub expanded: BTreeMap<String, bool>,
+}
+
+impl XmlLayout {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn get(&self, path: &str) -> Option<&ChildLayout> {
+        self.0.get(path)
+    }
+
+    pub fn insert(&mut self, path: impl Into<String>, layout: ChildLayout) {
+        self.0.insert(path.into(), layout);
+    }
+}
+
+impl ChildLayout {
+    pub fn is_empty(&self) -> bool {
+        self.order.is_empty() && self.names.is_empty() && self.expanded.is_empty()
+    }
+}
@@ -1,13 +1,117 @@
+use serde::Serialize;
+use serde::ser::{SerializeStruct, Serializer};
 use thiserror::Error;
 
+pub use crate::source::SourceSpan;
+pub use crate::validation::rules::{RULES, Rule};
+pub use crate::validation::{Severity, ValidationError, ValidationErrorTarget, ValidationErrors};
+
 #[derive(Debug, Error)]
 pub enum Error {
     #[error("Io error: {0}")]
     Io(#[from] std::io::Error),
 
-    #[error("Parse error: {0}")]
-    Parse(#[from] quick_xml::de::DeError),
+    #[error(
+        "Parse error: {error}{}",
+        span.map(|span| format!(" (at {span})")).unwrap_or_default()
+    )]
+    Parse {
+        error: quick_xml::de::DeError,
+        /// Where in the file the error was found, when it is known.
+        span: Option<SourceSpan>,
+    },
+
+    #[error("SQL parse error: {0}")]
+    SqlParse(#[from] sqlparser::parser::ParserError),
+
+    #[error("SQLite error: {0}")]
+    Sqlite(#[from] rusqlite::Error),
+
+    #[error("Serialize error: {0}")]
+    Serialize(#[from] quick_xml::se::SeError),
 
     #[error("{0}")]
-    Validation(#[from] crate::validation::ValidationError),
+    Validation(#[from] crate::validation::ValidationErrors),
+
+    #[error("{0}")]
+    Merge(#[from] crate::merge::MergeConflicts),
+
+    #[error("Unknown virtual diagram: {0}")]
+    UnknownVirtualDiagram(String),
+
+    /// An image was sent back without its data, and the file being saved over has no image it
+    /// was read from.
+    #[error("Missing image data: {0}")]
+    MissingImageData(String),
+}
+
+impl From<quick_xml::de::DeError> for Error {
+    fn from(error: quick_xml::de::DeError) -> Self {
+        Self::Parse { error, span: None }
+    }
+}
+
+impl From<quick_xml::Error> for Error {
+    fn from(error: quick_xml::Error) -> Self {
+        quick_xml::de::DeError::from(error).into()
+    }
+}
+
+impl Error {
+    /// Returns a code identifying the kind of error, which stays the same across releases.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Io(_) => "E_IO",
+            Self::Parse { .. } => "E_PARSE",
+            Self::SqlParse(_) => "E_SQL_PARSE",
+            Self::Sqlite(_) => "E_SQLITE",
+            Self::Serialize(_) => "E_SERIALIZE",
+            Self::Validation(_) => "E_VALIDATION",
+            Self::Merge(_) => "E_MERGE_CONFLICT",
+            Self::UnknownVirtualDiagram(_) => "E_UNKNOWN_VIRTUAL_DIAGRAM",
+            Self::MissingImageData(_) => "E_MISSING_IMAGE_DATA",
+        }
+    }
+
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Io(_) => "io",
+            Self::Parse { .. } => "parse",
+            Self::SqlParse(_) => "sqlParse",
+            Self::Sqlite(_) => "sqlite",
+            Self::Serialize(_) => "serialize",
+            Self::Validation(_) => "validation",
+            Self::Merge(_) => "merge",
+            Self::UnknownVirtualDiagram(_) => "unknownVirtualDiagram",
+            Self::MissingImageData(_) => "missingImageData",
+        }
+    }
+}
+
+/// Serializes the error for the frontend as its code, kind and message, along with the location
+/// of parse errors, every validation error and every merge conflict.
+impl Serialize for Error {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let span = match self {
+            Self::Parse { span, .. } => *span,
+            _ => None,
+        };
+        let errors = match self {
+            Self::Validation(errors) => errors.errors.as_slice(),
+            _ => &[],
+        };
+        let conflicts = match self {
+            Self::Merge(conflicts) => conflicts.conflicts.as_slice(),
+            _ => &[],
+        };
+
+        let mut state = serializer.serialize_struct("Error", 6)?;
+        state.serialize_field("code", self.code())?;
+        state.serialize_field("kind", self.kind())?;
+        state.serialize_field("message", &self.to_string())?;
+        state.serialize_field("span", &span)?;
+        state.serialize_field("errors", errors)?;
+        state.serialize_field("conflicts", conflicts)?;
+        state.end()
+    }
 }
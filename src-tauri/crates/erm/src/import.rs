@@ -0,0 +1,463 @@
+pub mod ddl;
+pub mod sqlite;
+
+use std::collections::HashSet;
+
+use crate::dtos::diagram::Diagram;
+use crate::dtos::diagram::diagram_settings::{
+    CategorySettings, DiagramSettings, ExportSettings, ModelProperties, TableProperties,
+};
+use crate::dtos::diagram::diagram_walkers::DiagramWalkers;
+use crate::dtos::diagram::diagram_walkers::tables::columns::{ColumnItem, Columns, NormalColumn};
+use crate::dtos::diagram::diagram_walkers::tables::compound_unique_key_list::{
+    self, CompoundUniqueKey, CompoundUniqueKeyList,
+};
+use crate::dtos::diagram::diagram_walkers::tables::connections::{
+    ChildCardinality, Connections, FkColumn, FkColumns, OnAction, ParentCardinality, Relationship,
+};
+use crate::dtos::diagram::diagram_walkers::tables::indexes::{self, Index};
+use crate::dtos::diagram::diagram_walkers::tables::{Color, Table};
+use crate::entities::diagram::unknown_elements::UnknownElements;
+use crate::entities::diagram::xml_layout::XmlLayout;
+
+const TABLES_PER_ROW: usize = 4;
+const GRID_ORIGIN: usize = 50;
+const GRID_WIDTH: usize = 300;
+const GRID_HEIGHT: usize = 250;
+
+#[derive(Default)]
+struct Importer {
+    tables: Vec<Table>,
+    foreign_keys: Vec<PendingForeignKey>,
+}
+
+/// A foreign key waiting for the end of the import, when every table it may refer to is known.
+struct PendingForeignKey {
+    table: String,
+    name: Option<String>,
+    columns: Vec<String>,
+    referenced_table: String,
+    referenced_columns: Vec<String>,
+    on_delete: Option<OnAction>,
+    on_update: Option<OnAction>,
+}
+
+impl Importer {
+    /// Adds a table, unless one with the same name was added before.
+    fn add_table(&mut self, table: Table) -> bool {
+        if self.table(&table.physical_name).is_some() {
+            return false;
+        }
+
+        self.tables.push(table);
+        true
+    }
+
+    /// The physical names of every table and of their columns.
+    fn physical_names(&self) -> impl Iterator<Item = &str> {
+        self.tables.iter().flat_map(|table| {
+            std::iter::once(table.physical_name.as_str()).chain(
+                table
+                    .columns
+                    .items
+                    .iter()
+                    .flatten()
+                    .filter_map(|item| match item {
+                        ColumnItem::Normal(column) => Some(column.physical_name.as_str()),
+                        ColumnItem::Group(_) => None,
+                    }),
+            )
+        })
+    }
+
+    /// Resolves the pending foreign keys and lays the tables out on a grid.
+    fn finish(mut self, database: &str) -> Diagram {
+        for foreign_key in std::mem::take(&mut self.foreign_keys) {
+            self.foreign_key(foreign_key);
+        }
+
+        for (index, table) in self.tables.iter_mut().enumerate() {
+            table.x = grid_position(GRID_WIDTH, index % TABLES_PER_ROW);
+            table.y = grid_position(GRID_HEIGHT, index / TABLES_PER_ROW);
+        }
+
+        // Follow the case of the imported names, and leave mixed-case schemas unchecked.
+        let any_name = |predicate: fn(char) -> bool| {
+            self.physical_names()
+                .any(|name| name.chars().any(predicate))
+        };
+        let capital = !any_name(char::is_lowercase);
+        let validate_physical_name = capital || !any_name(char::is_uppercase);
+
+        Diagram {
+            presenter: None,
+            page_settings: None,
+            category_index: None,
+            current_ermodel: None,
+            zoom: None,
+            x: None,
+            y: None,
+            default_color: None,
+            color: None,
+            font_name: None,
+            font_size: None,
+            diagram_settings: DiagramSettings {
+                database: database.to_string(),
+                capital,
+                table_style: String::new(),
+                notation: "IE".to_string(),
+                notation_level: 0,
+                notation_expand_group: true,
+                view_mode: 1,
+                outline_view_mode: 1,
+                view_order_by: 1,
+                auto_ime_change: false,
+                validate_physical_name,
+                use_bezier_curve: false,
+                suspend_validator: false,
+                title_font_em: None,
+                master_data_base_path: None,
+                use_view_object: false,
+                export_settings: ExportSettings::default(),
+                category_settings: CategorySettings {},
+                model_properties: ModelProperties {},
+                table_properties: TableProperties {},
+                environment_settings: None,
+                design_settings: None,
+            },
+            diagram_walkers: Some(DiagramWalkers {
+                tables: Some(self.tables),
+                views: None,
+                notes: None,
+                groups: None,
+                images: None,
+            }),
+            virtual_diagrams: None,
+            column_groups: None,
+            unknown_elements: UnknownElements::default(),
+            xml_layout: XmlLayout::default(),
+        }
+    }
+
+    fn foreign_key(&mut self, foreign_key: PendingForeignKey) {
+        let Some(parent) = self.table(&foreign_key.referenced_table) else {
+            return;
+        };
+        let primary_key = normal_columns(parent)
+            .filter(|column| column.primary_key == Some(true))
+            .map(|column| column.physical_name.clone())
+            .collect::<Vec<_>>();
+        let referenced_columns = if foreign_key.referenced_columns.is_empty() {
+            primary_key.clone()
+        } else {
+            foreign_key.referenced_columns.clone()
+        };
+        if referenced_columns.is_empty()
+            || referenced_columns.len() != foreign_key.columns.len()
+            || !referenced_columns
+                .iter()
+                .all(|column_name| find_normal_column(parent, column_name).is_some())
+        {
+            return;
+        }
+
+        let Some(child) = self.table(&foreign_key.table) else {
+            return;
+        };
+        // A column can only belong to one relationship.
+        if !foreign_key.columns.iter().all(|column_name| {
+            find_normal_column(child, column_name)
+                .is_some_and(|column| column.relationship.is_none())
+        }) {
+            return;
+        }
+        let child_unique = match foreign_key.columns.as_slice() {
+            [column_name] => find_normal_column(child, column_name)
+                .is_some_and(|column| column.unique_key == Some(true)),
+            _ => false,
+        };
+        let parent_required = foreign_key.columns.iter().all(|column_name| {
+            find_normal_column(child, column_name).is_some_and(|column| {
+                column.not_null == Some(true) || column.primary_key == Some(true)
+            })
+        });
+
+        let name = self.unique_relationship_name(foreign_key.name.unwrap_or_else(|| {
+            format!(
+                "{}_{}_fkey",
+                foreign_key.table,
+                foreign_key.columns.join("_")
+            )
+        }));
+        let parent_name = foreign_key.referenced_table;
+
+        let reference_for_pk = same_columns(&referenced_columns, &primary_key);
+        let mut referred_simple_unique_column = None;
+        let mut referred_compound_unique_key = None;
+        if !reference_for_pk {
+            let Some(parent) = self.table_mut(&parent_name) else {
+                return;
+            };
+            match referenced_columns.as_slice() {
+                [column_name] => {
+                    if let Some(column) = normal_column_mut(parent, column_name) {
+                        column.unique_key = Some(true);
+                    }
+                    referred_simple_unique_column =
+                        Some(format!("table.{parent_name}.{column_name}"));
+                }
+                _ => {
+                    let key_name = compound_unique_key_name(parent, &referenced_columns)
+                        .unwrap_or_else(|| {
+                            let key_name =
+                                format!("{parent_name}_{}_key", referenced_columns.join("_"));
+                            add_compound_unique_key(
+                                parent,
+                                key_name.clone(),
+                                referenced_columns.clone(),
+                            );
+                            key_name
+                        });
+                    referred_compound_unique_key = Some(key_name);
+                }
+            }
+        }
+
+        let Some(child) = self.table_mut(&foreign_key.table) else {
+            return;
+        };
+        for (column_name, referenced_column) in foreign_key.columns.iter().zip(&referenced_columns)
+        {
+            if let Some(column) = normal_column_mut(child, column_name) {
+                column.referred_column = Some(format!("table.{parent_name}.{referenced_column}"));
+                column.relationship = Some(name.clone());
+            }
+        }
+        child
+            .connections
+            .relationships
+            .get_or_insert_with(Vec::new)
+            .push(Relationship {
+                name,
+                source: format!("table.{parent_name}"),
+                target: format!("table.{}", foreign_key.table),
+                bendpoints: None,
+                fk_columns: FkColumns {
+                    fk_column: foreign_key
+                        .columns
+                        .into_iter()
+                        .map(|fk_column_name| FkColumn { fk_column_name })
+                        .collect(),
+                },
+                parent_cardinality: if parent_required {
+                    ParentCardinality::One
+                } else {
+                    ParentCardinality::ZeroOrOne
+                },
+                child_cardinality: if child_unique {
+                    ChildCardinality::ZeroOrOne
+                } else {
+                    ChildCardinality::ZeroOrMore
+                },
+                reference_for_pk,
+                on_delete_action: foreign_key.on_delete,
+                on_update_action: foreign_key.on_update,
+                referred_simple_unique_column,
+                referred_compound_unique_key,
+            });
+    }
+
+    fn unique_relationship_name(&self, name: String) -> String {
+        let names = self
+            .tables
+            .iter()
+            .flat_map(|table| table.connections.relationships.iter().flatten())
+            .map(|relationship| relationship.name.as_str())
+            .collect::<HashSet<_>>();
+        unique_name(name, &names)
+    }
+
+    fn table(&self, name: &str) -> Option<&Table> {
+        self.tables.iter().find(|table| table.physical_name == name)
+    }
+
+    fn table_mut(&mut self, name: &str) -> Option<&mut Table> {
+        self.tables
+            .iter_mut()
+            .find(|table| table.physical_name == name)
+    }
+}
+
+fn new_table(physical_name: String, logical_name: String, description: String) -> Table {
+    Table {
+        physical_name,
+        logical_name,
+        description,
+        height: None,
+        width: None,
+        font_name: String::new(),
+        font_size: 9,
+        x: 0,
+        y: 0,
+        color: Color {
+            r: 128,
+            g: 128,
+            b: 192,
+        },
+        connections: Connections::default(),
+        table_constraint: None,
+        primary_key_name: None,
+        option: None,
+        columns: Columns {
+            items: Some(Vec::new()),
+        },
+        indexes: None,
+        compound_unique_key_list: CompoundUniqueKeyList {
+            compound_unique_keys: None,
+        },
+    }
+}
+
+fn add_index(
+    table: &mut Table,
+    name: Option<String>,
+    index_type: Option<String>,
+    unique: bool,
+    full_text: bool,
+    columns: Vec<(String, bool)>,
+) {
+    if columns.is_empty()
+        || !columns
+            .iter()
+            .all(|(column_name, _)| find_normal_column(table, column_name).is_some())
+    {
+        return;
+    }
+
+    let indexes = table.indexes.get_or_insert_with(Vec::new);
+    let name = name.unwrap_or_else(|| {
+        let column_names = columns
+            .iter()
+            .map(|(column_name, _)| column_name.as_str())
+            .collect::<Vec<_>>();
+        format!("{}_{}_idx", table.physical_name, column_names.join("_"))
+    });
+    let name = unique_name(
+        name,
+        &indexes.iter().map(|index| index.name.as_str()).collect(),
+    );
+
+    indexes.push(Index {
+        name,
+        index_type: index_type.unwrap_or_default().to_ascii_uppercase(),
+        description: None,
+        full_text: Some(full_text),
+        non_unique: Some(!unique),
+        columns: columns
+            .into_iter()
+            .map(|(column_id, desc)| indexes::Column {
+                column_id,
+                desc: desc.then_some(true),
+            })
+            .collect(),
+    });
+}
+
+fn add_compound_unique_key(table: &mut Table, name: String, columns: Vec<String>) {
+    if !columns
+        .iter()
+        .all(|column_name| find_normal_column(table, column_name).is_some())
+    {
+        return;
+    }
+
+    let keys = table
+        .compound_unique_key_list
+        .compound_unique_keys
+        .get_or_insert_with(Vec::new);
+    let name = unique_name(name, &keys.iter().map(|key| key.name.as_str()).collect());
+    keys.push(CompoundUniqueKey {
+        name,
+        columns: columns
+            .into_iter()
+            .map(|column_id| compound_unique_key_list::Column { column_id })
+            .collect(),
+    });
+}
+
+fn compound_unique_key_name(table: &Table, columns: &[String]) -> Option<String> {
+    table
+        .compound_unique_key_list
+        .compound_unique_keys
+        .iter()
+        .flatten()
+        .find(|key| {
+            let key_columns = key
+                .columns
+                .iter()
+                .map(|column| column.column_id.clone())
+                .collect::<Vec<_>>();
+            same_columns(&key_columns, columns)
+        })
+        .map(|key| key.name.clone())
+}
+
+/// Splits a comment the way the DDL generators join the logical name and the description.
+fn split_comment(comment: &str) -> (String, String) {
+    match comment.split_once(": ") {
+        Some((logical_name, description)) => (logical_name.to_string(), description.to_string()),
+        None => (comment.to_string(), String::new()),
+    }
+}
+
+fn non_empty(value: String) -> Option<String> {
+    (!value.is_empty()).then_some(value)
+}
+
+fn unique_name(name: String, names: &HashSet<&str>) -> String {
+    if !names.contains(name.as_str()) {
+        return name;
+    }
+
+    (2..)
+        .map(|suffix| format!("{name}_{suffix}"))
+        .find(|candidate| !names.contains(candidate.as_str()))
+        .expect("an unused suffix exists")
+}
+
+fn same_columns(left: &[String], right: &[String]) -> bool {
+    left.len() == right.len()
+        && left.iter().collect::<HashSet<_>>() == right.iter().collect::<HashSet<_>>()
+}
+
+fn grid_position(step: usize, index: usize) -> u16 {
+    u16::try_from(GRID_ORIGIN + step * index).unwrap_or(u16::MAX)
+}
+
+fn normal_columns(table: &Table) -> impl Iterator<Item = &NormalColumn> {
+    table
+        .columns
+        .items
+        .iter()
+        .flatten()
+        .filter_map(|item| match item {
+            ColumnItem::Normal(column) => Some(column),
+            ColumnItem::Group(_) => None,
+        })
+}
+
+fn find_normal_column<'a>(table: &'a Table, column_name: &str) -> Option<&'a NormalColumn> {
+    normal_columns(table).find(|column| column.physical_name == column_name)
+}
+
+fn normal_column_mut<'a>(table: &'a mut Table, column_name: &str) -> Option<&'a mut NormalColumn> {
+    table
+        .columns
+        .items
+        .iter_mut()
+        .flatten()
+        .find_map(|item| match item {
+            ColumnItem::Normal(column) if column.physical_name == column_name => Some(column),
+            _ => None,
+        })
+}
@@ -0,0 +1,539 @@
+use sqlparser::ast::{
+    AlterTableOperation, ColumnDef, ColumnOption, CommentDef, CommentObject, CreateIndex,
+    CreateTable, DataType, EnumMember, ExactNumberInfo, Expr, Ident, ObjectName, ReferentialAction,
+    Statement, TableConstraint, Value,
+};
+use sqlparser::dialect::{MySqlDialect, PostgreSqlDialect};
+use sqlparser::parser::Parser;
+
+use crate::column_type::ColumnType;
+use crate::dtos::diagram::Diagram;
+use crate::dtos::diagram::diagram_walkers::tables::columns::{ColumnItem, NormalColumn};
+use crate::dtos::diagram::diagram_walkers::tables::connections::OnAction;
+use crate::errors::Error;
+use crate::validation;
+
+use super::{
+    Importer, PendingForeignKey, add_compound_unique_key, add_index, new_table, non_empty,
+    normal_column_mut, split_comment,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dialect {
+    MySql,
+    PostgreSql,
+}
+
+impl Dialect {
+    fn database(self) -> &'static str {
+        match self {
+            Self::MySql => "MySQL",
+            Self::PostgreSql => "PostgreSQL",
+        }
+    }
+}
+
+/// Parses a DDL script into a new diagram whose tables are laid out on a grid.
+///
+/// `CREATE TABLE`, `ALTER TABLE ... ADD CONSTRAINT`, `CREATE INDEX` and `COMMENT ON` statements
+/// are read and every other statement is skipped. Foreign keys are resolved once the whole script
+/// has been read, so they may refer to tables declared further down; those referring to tables or
+/// columns the script does not declare are dropped.
+pub fn parse(sql: &str, dialect: Dialect) -> Result<Diagram, Error> {
+    let statements = match dialect {
+        Dialect::MySql => Parser::parse_sql(&MySqlDialect {}, sql)?,
+        Dialect::PostgreSql => Parser::parse_sql(&PostgreSqlDialect {}, sql)?,
+    };
+
+    let mut importer = Importer::default();
+    for statement in statements {
+        importer.statement(statement);
+    }
+
+    let diagram = importer.finish(dialect.database());
+    validation::validate(&diagram)?;
+    Ok(diagram)
+}
+
+impl Importer {
+    fn statement(&mut self, statement: Statement) {
+        match statement {
+            Statement::CreateTable(create_table) => self.create_table(create_table),
+            Statement::AlterTable {
+                name, operations, ..
+            } => {
+                let table_name = object_name(&name);
+                for operation in operations {
+                    if let AlterTableOperation::AddConstraint(constraint) = operation {
+                        self.table_constraint(&table_name, constraint);
+                    }
+                }
+            }
+            Statement::CreateIndex(create_index) => self.create_index(create_index),
+            Statement::Comment {
+                object_type,
+                object_name,
+                comment,
+                ..
+            } => self.comment(object_type, &object_name, comment.as_deref()),
+            _ => {}
+        }
+    }
+
+    fn create_table(&mut self, create_table: CreateTable) {
+        let physical_name = object_name(&create_table.name);
+        let (logical_name, description) = create_table
+            .comment
+            .as_ref()
+            .map(|comment| match comment {
+                CommentDef::WithEq(comment)
+                | CommentDef::WithoutEq(comment)
+                | CommentDef::AfterColumnDefsWithoutEq(comment) => split_comment(comment),
+            })
+            .unwrap_or_default();
+
+        // A table declared twice keeps its first definition.
+        if !self.add_table(new_table(physical_name.clone(), logical_name, description)) {
+            return;
+        }
+
+        for column in create_table.columns {
+            self.column(&physical_name, column);
+        }
+        for constraint in create_table.constraints {
+            self.table_constraint(&physical_name, constraint);
+        }
+    }
+
+    fn column(&mut self, table_name: &str, column: ColumnDef) {
+        let mut normal_column = NormalColumn {
+            physical_name: column.name.value,
+            ..Default::default()
+        };
+        set_data_type(&mut normal_column, &column.data_type);
+
+        let mut primary_key_name = None;
+        for option in column.options {
+            match option.option {
+                ColumnOption::NotNull => normal_column.not_null = Some(true),
+                ColumnOption::Default(expr) => normal_column.default_value = default_value(&expr),
+                ColumnOption::Unique { is_primary, .. } => {
+                    if is_primary {
+                        normal_column.primary_key = Some(true);
+                        primary_key_name = option.name.map(|name| name.value);
+                    } else {
+                        normal_column.unique_key = Some(true);
+                    }
+                }
+                ColumnOption::ForeignKey {
+                    foreign_table,
+                    referred_columns,
+                    on_delete,
+                    on_update,
+                    ..
+                } => self.foreign_keys.push(PendingForeignKey {
+                    table: table_name.to_string(),
+                    name: option.name.map(|name| name.value),
+                    columns: vec![normal_column.physical_name.clone()],
+                    referenced_table: object_name(&foreign_table),
+                    referenced_columns: identifiers(referred_columns),
+                    on_delete: on_delete.map(on_action),
+                    on_update: on_update.map(on_action),
+                }),
+                ColumnOption::DialectSpecific(tokens)
+                    if tokens
+                        .iter()
+                        .any(|token| token.to_string().eq_ignore_ascii_case("AUTO_INCREMENT")) =>
+                {
+                    normal_column.auto_increment = Some(true);
+                }
+                ColumnOption::Identity(_)
+                | ColumnOption::Generated {
+                    generation_expr: None,
+                    ..
+                } => normal_column.auto_increment = Some(true),
+                ColumnOption::Comment(comment) => {
+                    let (logical_name, description) = split_comment(&comment);
+                    normal_column.logical_name = non_empty(logical_name);
+                    normal_column.description = non_empty(description);
+                }
+                _ => {}
+            }
+        }
+
+        let Some(table) = self.table_mut(table_name) else {
+            return;
+        };
+        if primary_key_name.is_some() {
+            table.primary_key_name = primary_key_name;
+        }
+        table
+            .columns
+            .items
+            .get_or_insert_with(Vec::new)
+            .push(ColumnItem::Normal(normal_column));
+    }
+
+    fn table_constraint(&mut self, table_name: &str, constraint: TableConstraint) {
+        if let TableConstraint::ForeignKey {
+            name,
+            columns,
+            foreign_table,
+            referred_columns,
+            on_delete,
+            on_update,
+            ..
+        } = constraint
+        {
+            self.foreign_keys.push(PendingForeignKey {
+                table: table_name.to_string(),
+                name: name.map(|name| name.value),
+                columns: identifiers(columns),
+                referenced_table: object_name(&foreign_table),
+                referenced_columns: identifiers(referred_columns),
+                on_delete: on_delete.map(on_action),
+                on_update: on_update.map(on_action),
+            });
+            return;
+        }
+
+        let Some(table) = self.table_mut(table_name) else {
+            return;
+        };
+        match constraint {
+            TableConstraint::PrimaryKey { name, columns, .. } => {
+                if name.is_some() {
+                    table.primary_key_name = name.map(|name| name.value);
+                }
+                for column in &columns {
+                    if let Some(column) = normal_column_mut(table, &column.value) {
+                        column.primary_key = Some(true);
+                    }
+                }
+            }
+            TableConstraint::Unique {
+                name,
+                index_name,
+                columns,
+                ..
+            } => {
+                let columns = identifiers(columns);
+                match (name.or(index_name), columns.as_slice()) {
+                    (None, [column_name]) => {
+                        if let Some(column) = normal_column_mut(table, column_name) {
+                            column.unique_key = Some(true);
+                        }
+                    }
+                    (name, _) => {
+                        let name = name.map(|name| name.value).unwrap_or_else(|| {
+                            format!("{}_{}_key", table.physical_name, columns.join("_"))
+                        });
+                        add_compound_unique_key(table, name, columns);
+                    }
+                }
+            }
+            TableConstraint::Index {
+                name,
+                index_type,
+                columns,
+                ..
+            } => {
+                let columns = identifiers(columns);
+                add_index(
+                    table,
+                    name.map(|name| name.value),
+                    index_type.map(|index_type| index_type.to_string()),
+                    false,
+                    false,
+                    columns.into_iter().map(|column| (column, false)).collect(),
+                );
+            }
+            TableConstraint::FulltextOrSpatial {
+                fulltext: true,
+                opt_index_name,
+                columns,
+                ..
+            } => {
+                let columns = identifiers(columns);
+                add_index(
+                    table,
+                    opt_index_name.map(|name| name.value),
+                    None,
+                    false,
+                    true,
+                    columns.into_iter().map(|column| (column, false)).collect(),
+                );
+            }
+            TableConstraint::Check { .. } => {
+                let constraint = constraint.to_string();
+                table.table_constraint = Some(match table.table_constraint.take() {
+                    Some(existing) => format!("{existing},\n{constraint}"),
+                    None => constraint,
+                });
+            }
+            _ => {}
+        }
+    }
+
+    fn create_index(&mut self, create_index: CreateIndex) {
+        let table_name = object_name(&create_index.table_name);
+        let Some(table) = self.table_mut(&table_name) else {
+            return;
+        };
+
+        // Indexes on expressions cannot be represented in the diagram.
+        let Some(columns) = create_index
+            .columns
+            .iter()
+            .map(|column| {
+                let column_name = match &column.expr {
+                    Expr::Identifier(ident) => ident.value.clone(),
+                    Expr::CompoundIdentifier(idents) => idents.last()?.value.clone(),
+                    _ => return None,
+                };
+                Some((column_name, column.asc == Some(false)))
+            })
+            .collect::<Option<Vec<_>>>()
+        else {
+            return;
+        };
+
+        add_index(
+            table,
+            create_index.name.as_ref().map(object_name),
+            create_index.using.map(|using| using.value),
+            create_index.unique,
+            false,
+            columns,
+        );
+    }
+
+    fn comment(&mut self, object_type: CommentObject, name: &ObjectName, comment: Option<&str>) {
+        let (logical_name, description) = comment.map(split_comment).unwrap_or_default();
+
+        match (object_type, name.0.as_slice()) {
+            (CommentObject::Table, [.., table_name]) => {
+                if let Some(table) = self.table_mut(&table_name.value) {
+                    table.logical_name = logical_name;
+                    table.description = description;
+                }
+            }
+            (CommentObject::Column, [.., table_name, column_name]) => {
+                if let Some(column) = self
+                    .table_mut(&table_name.value)
+                    .and_then(|table| normal_column_mut(table, &column_name.value))
+                {
+                    column.logical_name = non_empty(logical_name);
+                    column.description = non_empty(description);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+pub(super) fn set_data_type(column: &mut NormalColumn, data_type: &DataType) {
+    let (column_type, length, decimal) = match data_type {
+        DataType::Char(length) | DataType::Character(length) => match character_length(length) {
+            Some(length) => (ColumnType::CharN, Some(length), None),
+            None => (ColumnType::Char, None, None),
+        },
+        DataType::Varchar(length)
+        | DataType::CharacterVarying(length)
+        | DataType::CharVarying(length) => (ColumnType::VarCharN, character_length(length), None),
+        DataType::Text => (ColumnType::Text, None, None),
+        DataType::TinyText => (ColumnType::TinyText, None, None),
+        DataType::MediumText => (ColumnType::MediumText, None, None),
+        DataType::LongText => (ColumnType::LongText, None, None),
+        DataType::Int(length)
+        | DataType::Integer(length)
+        | DataType::Int4(length)
+        | DataType::UnsignedInt(length)
+        | DataType::UnsignedInteger(length)
+        | DataType::UnsignedInt4(length) => {
+            with_display_width(ColumnType::Int, ColumnType::IntN, length)
+        }
+        DataType::TinyInt(length) | DataType::UnsignedTinyInt(length) => {
+            with_display_width(ColumnType::TinyInt, ColumnType::TinyIntN, length)
+        }
+        DataType::SmallInt(length)
+        | DataType::Int2(length)
+        | DataType::UnsignedSmallInt(length)
+        | DataType::UnsignedInt2(length) => {
+            with_display_width(ColumnType::SmallInt, ColumnType::SmallIntN, length)
+        }
+        DataType::MediumInt(length) | DataType::UnsignedMediumInt(length) => {
+            with_display_width(ColumnType::MediumInt, ColumnType::MediumIntN, length)
+        }
+        DataType::BigInt(length)
+        | DataType::Int8(length)
+        | DataType::UnsignedBigInt(length)
+        | DataType::UnsignedInt8(length) => {
+            with_display_width(ColumnType::BigInt, ColumnType::BigIntN, length)
+        }
+        DataType::Date => (ColumnType::Date, None, None),
+        DataType::Datetime(_) => (ColumnType::Datetime, None, None),
+        DataType::Timestamp(..) => (ColumnType::Timestamp, None, None),
+        DataType::Time(..) => (ColumnType::Time, None, None),
+        DataType::Bool | DataType::Boolean => (ColumnType::Boolean, None, None),
+        DataType::Binary(length) => match length.and_then(to_u16) {
+            Some(length) => (ColumnType::BinaryN, Some(length), None),
+            None => (ColumnType::Binary, None, None),
+        },
+        DataType::Varbinary(length) => (ColumnType::VarBinaryN, length.and_then(to_u16), None),
+        DataType::Blob(_) | DataType::Bytea => (ColumnType::Blob, None, None),
+        DataType::TinyBlob => (ColumnType::TinyBlob, None, None),
+        DataType::MediumBlob => (ColumnType::MediumBlob, None, None),
+        DataType::LongBlob => (ColumnType::LongBlob, None, None),
+        DataType::Bit(length) => (ColumnType::BitN, length.and_then(to_u16), None),
+        DataType::Decimal(info) | DataType::Dec(info) => exact_number(
+            ColumnType::Decimal,
+            ColumnType::DecimalP,
+            ColumnType::DecimalPS,
+            info,
+        ),
+        DataType::Numeric(info) => exact_number(
+            ColumnType::Numeric,
+            ColumnType::NumericP,
+            ColumnType::NumericPS,
+            info,
+        ),
+        DataType::Float(precision) => match precision.and_then(to_u16) {
+            Some(precision) => (ColumnType::FloatP, Some(precision), None),
+            None => (ColumnType::Float, None, None),
+        },
+        DataType::Real | DataType::Float4 => (ColumnType::Real, None, None),
+        DataType::Double | DataType::DoublePrecision | DataType::Float8 => {
+            (ColumnType::Double, None, None)
+        }
+        DataType::Enum(members, _) => {
+            column.args = Some(quoted_values(members.iter().map(|member| match member {
+                EnumMember::Name(name) | EnumMember::NamedValue(name, _) => name.as_str(),
+            })));
+            (ColumnType::Enum, None, None)
+        }
+        DataType::Set(values) => {
+            column.args = Some(quoted_values(values.iter().map(String::as_str)));
+            (ColumnType::Set, None, None)
+        }
+        DataType::JSON | DataType::JSONB => (ColumnType::Json, None, None),
+        DataType::Custom(name, _) => {
+            let column_type = match object_name(name).to_ascii_lowercase().as_str() {
+                "year" => ColumnType::Year4,
+                "geometry" => ColumnType::Geometry,
+                "smallserial" | "serial2" => ColumnType::SmallInt,
+                "serial" | "serial4" => ColumnType::Int,
+                "bigserial" | "serial8" => ColumnType::BigInt,
+                _ => return,
+            };
+            if matches!(
+                column_type,
+                ColumnType::SmallInt | ColumnType::Int | ColumnType::BigInt
+            ) {
+                column.auto_increment = Some(true);
+            }
+            (column_type, None, None)
+        }
+        // Types the diagram cannot express are left without a column type.
+        _ => return,
+    };
+
+    column.column_type = Some(column_type);
+    column.length = length;
+    column.decimal = decimal;
+    if matches!(
+        data_type,
+        DataType::UnsignedTinyInt(_)
+            | DataType::UnsignedSmallInt(_)
+            | DataType::UnsignedInt2(_)
+            | DataType::UnsignedMediumInt(_)
+            | DataType::UnsignedInt(_)
+            | DataType::UnsignedInteger(_)
+            | DataType::UnsignedInt4(_)
+            | DataType::UnsignedBigInt(_)
+            | DataType::UnsignedInt8(_)
+    ) {
+        column.unsigned = Some(true);
+    }
+}
+
+fn with_display_width(
+    column_type: ColumnType,
+    column_type_with_width: ColumnType,
+    width: &Option<u64>,
+) -> (ColumnType, Option<u16>, Option<u16>) {
+    match width.and_then(to_u16) {
+        Some(width) => (column_type_with_width, Some(width), None),
+        None => (column_type, None, None),
+    }
+}
+
+fn exact_number(
+    column_type: ColumnType,
+    column_type_with_precision: ColumnType,
+    column_type_with_scale: ColumnType,
+    info: &ExactNumberInfo,
+) -> (ColumnType, Option<u16>, Option<u16>) {
+    match *info {
+        ExactNumberInfo::None => (column_type, None, None),
+        ExactNumberInfo::Precision(precision) => {
+            (column_type_with_precision, to_u16(precision), None)
+        }
+        ExactNumberInfo::PrecisionAndScale(precision, scale) => {
+            (column_type_with_scale, to_u16(precision), to_u16(scale))
+        }
+    }
+}
+
+fn character_length(length: &Option<sqlparser::ast::CharacterLength>) -> Option<u16> {
+    match length {
+        Some(sqlparser::ast::CharacterLength::IntegerLength { length, .. }) => to_u16(*length),
+        _ => None,
+    }
+}
+
+/// Writes `enum` and `set` values the way they are declared, e.g. `'a','b'`.
+fn quoted_values<'a>(values: impl Iterator<Item = &'a str>) -> String {
+    values
+        .map(|value| format!("'{}'", value.replace('\'', "''")))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn to_u16(value: u64) -> Option<u16> {
+    u16::try_from(value).ok()
+}
+
+/// String defaults are stored unquoted; everything else keeps its SQL text.
+pub(super) fn default_value(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Value(Value::Null) => None,
+        Expr::Value(Value::Boolean(value)) => Some(value.to_string().to_ascii_uppercase()),
+        Expr::Value(Value::SingleQuotedString(value))
+        | Expr::Value(Value::DoubleQuotedString(value)) => Some(value.clone()),
+        Expr::Cast { expr, .. } => default_value(expr),
+        expr => Some(expr.to_string()),
+    }
+}
+
+fn on_action(action: ReferentialAction) -> OnAction {
+    match action {
+        ReferentialAction::Restrict => OnAction::Restrict,
+        ReferentialAction::Cascade => OnAction::Cascade,
+        ReferentialAction::SetNull => OnAction::SetNull,
+        ReferentialAction::SetDefault => OnAction::SetDefault,
+        ReferentialAction::NoAction => OnAction::NoAction,
+    }
+}
+
+/// Names a table by its last part, dropping any schema qualifier.
+fn object_name(name: &ObjectName) -> String {
+    name.0
+        .last()
+        .map(|ident| ident.value.clone())
+        .unwrap_or_default()
+}
+
+fn identifiers(idents: Vec<Ident>) -> Vec<String> {
+    idents.into_iter().map(|ident| ident.value).collect()
+}
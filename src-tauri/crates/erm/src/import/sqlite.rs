@@ -0,0 +1,247 @@
+use rusqlite::{Connection, OpenFlags};
+use sqlparser::dialect::SQLiteDialect;
+use sqlparser::parser::Parser;
+
+use crate::dtos::diagram::Diagram;
+use crate::dtos::diagram::diagram_walkers::tables::columns::{ColumnItem, NormalColumn};
+use crate::dtos::diagram::diagram_walkers::tables::connections::OnAction;
+use crate::errors::Error;
+use crate::validation;
+
+use super::ddl::{default_value, set_data_type};
+use super::{
+    Importer, PendingForeignKey, add_compound_unique_key, add_index, new_table, normal_column_mut,
+};
+
+/// Reads the schema of a SQLite database file into a new diagram whose tables are laid out on a
+/// grid.
+///
+/// Tables come from `sqlite_master`, and their columns, indexes and foreign keys from the
+/// `table_info`, `index_list` and `foreign_key_list` pragmas. The database is opened read-only.
+pub fn open(filename: &str) -> Result<Diagram, Error> {
+    let connection = Connection::open_with_flags(filename, OpenFlags::SQLITE_OPEN_READ_ONLY)?;
+
+    let mut importer = Importer::default();
+    for (table_name, sql) in tables(&connection)? {
+        importer.add_table(new_table(table_name.clone(), String::new(), String::new()));
+        columns(&connection, &mut importer, &table_name, &sql)?;
+        indexes(&connection, &mut importer, &table_name)?;
+        foreign_keys(&connection, &mut importer, &table_name)?;
+    }
+
+    let diagram = importer.finish("SQLite");
+    validation::validate(&diagram)?;
+    Ok(diagram)
+}
+
+fn tables(connection: &Connection) -> Result<Vec<(String, String)>, Error> {
+    let mut statement = connection.prepare(
+        "SELECT name, sql FROM sqlite_master \
+         WHERE type = 'table' AND name NOT LIKE 'sqlite\\_%' ESCAPE '\\' \
+         ORDER BY rowid",
+    )?;
+    let tables = statement
+        .query_map([], |row| {
+            Ok((
+                row.get(0)?,
+                row.get::<_, Option<String>>(1)?.unwrap_or_default(),
+            ))
+        })?
+        .collect::<Result<_, _>>()?;
+
+    Ok(tables)
+}
+
+fn columns(
+    connection: &Connection,
+    importer: &mut Importer,
+    table_name: &str,
+    sql: &str,
+) -> Result<(), Error> {
+    let mut statement = connection.prepare(
+        "SELECT name, type, \"notnull\", dflt_value, pk FROM pragma_table_info(?1) ORDER BY cid",
+    )?;
+    let rows = statement
+        .query_map([table_name], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, bool>(2)?,
+                row.get::<_, Option<String>>(3)?,
+                row.get::<_, i64>(4)?,
+            ))
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // SQLite only accepts `AUTOINCREMENT` on a single `INTEGER PRIMARY KEY` column.
+    let primary_key_count = rows.iter().filter(|row| row.4 > 0).count();
+    let autoincrement =
+        primary_key_count == 1 && sql.to_ascii_uppercase().contains("AUTOINCREMENT");
+
+    let Some(table) = importer.table_mut(table_name) else {
+        return Ok(());
+    };
+    let items = table.columns.items.get_or_insert_with(Vec::new);
+    for (name, declared_type, not_null, default, primary_key) in rows {
+        let mut column = NormalColumn {
+            physical_name: name,
+            not_null: not_null.then_some(true),
+            default_value: default.as_deref().and_then(parse_default_value),
+            primary_key: (primary_key > 0).then_some(true),
+            ..Default::default()
+        };
+        if let Ok(data_type) = Parser::new(&SQLiteDialect {})
+            .try_with_sql(&declared_type)
+            .and_then(|mut parser| parser.parse_data_type())
+        {
+            set_data_type(&mut column, &data_type);
+        }
+        if autoincrement && primary_key > 0 {
+            column.auto_increment = Some(true);
+        }
+        items.push(ColumnItem::Normal(column));
+    }
+
+    Ok(())
+}
+
+fn indexes(
+    connection: &Connection,
+    importer: &mut Importer,
+    table_name: &str,
+) -> Result<(), Error> {
+    let mut statement = connection
+        .prepare("SELECT name, \"unique\", origin FROM pragma_index_list(?1) ORDER BY seq DESC")?;
+    let indexes = statement
+        .query_map([table_name], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, bool>(1)?,
+                row.get::<_, String>(2)?,
+            ))
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    for (name, unique, origin) in indexes {
+        // The primary key is already known from `table_info`.
+        if origin == "pk" {
+            continue;
+        }
+
+        let mut statement = connection.prepare(
+            "SELECT name, \"desc\" FROM pragma_index_xinfo(?1) WHERE key = 1 ORDER BY seqno",
+        )?;
+        let columns = statement
+            .query_map([&name], |row| {
+                Ok((row.get::<_, Option<String>>(0)?, row.get::<_, bool>(1)?))
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        // Indexes on expressions cannot be represented in the diagram.
+        let Some(columns) = columns
+            .into_iter()
+            .map(|(column_name, desc)| column_name.map(|column_name| (column_name, desc)))
+            .collect::<Option<Vec<_>>>()
+        else {
+            continue;
+        };
+
+        let Some(table) = importer.table_mut(table_name) else {
+            continue;
+        };
+        if origin == "u" {
+            let column_names = columns
+                .into_iter()
+                .map(|(column_name, _)| column_name)
+                .collect::<Vec<_>>();
+            match column_names.as_slice() {
+                [column_name] => {
+                    if let Some(column) = normal_column_mut(table, column_name) {
+                        column.unique_key = Some(true);
+                    }
+                }
+                _ => {
+                    // SQLite names the index of a `UNIQUE` constraint after the table, not the
+                    // constraint.
+                    let key_name = format!("{table_name}_{}_key", column_names.join("_"));
+                    add_compound_unique_key(table, key_name, column_names);
+                }
+            }
+        } else {
+            add_index(table, Some(name), None, unique, false, columns);
+        }
+    }
+
+    Ok(())
+}
+
+fn foreign_keys(
+    connection: &Connection,
+    importer: &mut Importer,
+    table_name: &str,
+) -> Result<(), Error> {
+    let mut statement = connection.prepare(
+        "SELECT id, \"table\", \"from\", \"to\", on_update, on_delete \
+         FROM pragma_foreign_key_list(?1) ORDER BY id DESC, seq",
+    )?;
+    let rows = statement
+        .query_map([table_name], |row| {
+            Ok((
+                row.get::<_, i64>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, Option<String>>(3)?,
+                row.get::<_, String>(4)?,
+                row.get::<_, String>(5)?,
+            ))
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut current_id = None;
+    for (id, referenced_table, column, referenced_column, on_update, on_delete) in rows {
+        if current_id != Some(id) {
+            current_id = Some(id);
+            importer.foreign_keys.push(PendingForeignKey {
+                table: table_name.to_string(),
+                // SQLite does not keep foreign key constraint names.
+                name: None,
+                columns: Vec::new(),
+                referenced_table,
+                referenced_columns: Vec::new(),
+                on_delete: on_action(&on_delete),
+                on_update: on_action(&on_update),
+            });
+        }
+
+        let Some(foreign_key) = importer.foreign_keys.last_mut() else {
+            continue;
+        };
+        foreign_key.columns.push(column);
+        // Without referenced columns, the foreign key refers to the primary key of the parent.
+        if let Some(referenced_column) = referenced_column {
+            foreign_key.referenced_columns.push(referenced_column);
+        }
+    }
+
+    Ok(())
+}
+
+fn parse_default_value(value: &str) -> Option<String> {
+    match Parser::new(&SQLiteDialect {})
+        .try_with_sql(value)
+        .and_then(|mut parser| parser.parse_expr())
+    {
+        Ok(expr) => default_value(&expr),
+        Err(_) => Some(value.to_string()),
+    }
+}
+
+/// `NO ACTION` is what SQLite reports when a foreign key has no action, so it is left unset.
+fn on_action(action: &str) -> Option<OnAction> {
+    match action {
+        "RESTRICT" => Some(OnAction::Restrict),
+        "CASCADE" => Some(OnAction::Cascade),
+        "SET NULL" => Some(OnAction::SetNull),
+        "SET DEFAULT" => Some(OnAction::SetDefault),
+        _ => None,
+    }
+}
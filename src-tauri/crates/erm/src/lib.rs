@@ -1,16 +1,168 @@
+mod canonical;
 pub mod column_type;
+pub mod ddl;
+pub mod diff;
 pub mod dtos;
 pub mod entities;
 pub mod errors;
+pub mod import;
+pub mod lint;
+pub mod merge;
 mod reader;
+mod schema;
+mod source;
 mod validation;
+mod writer;
+mod xml_tree;
 
 use dtos::diagram::Diagram;
-use errors::Error;
-use reader::read_file;
+use dtos::diagram::diagram_walkers::images::ImageFormat;
+use errors::{Error, ValidationError, ValidationErrors};
+use reader::read;
+use serde::Serialize;
+use writer::write_file;
 
-pub fn open(filename: &str) -> Result<Diagram, Error> {
-    let diagram = Diagram::from(read_file(&filename)?);
-    validation::validate(&diagram)?;
-    Ok(diagram)
+pub use diff::diff;
+pub use merge::{merge, merge_with_settings};
+pub use validation::settings::{Suppression, ValidationSettings};
+pub use writer::Format;
+
+/// A diagram read from a file, with the validation warnings that did not keep it from opening.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenedDiagram {
+    pub diagram: Diagram,
+    pub warnings: Vec<ValidationError>,
+}
+
+/// Opens a diagram, validated with the settings read by [`ValidationSettings::read`].
+pub fn open(filename: &str) -> Result<OpenedDiagram, Error> {
+    open_with_settings(filename, &ValidationSettings::read(filename)?)
+}
+
+/// Opens a diagram, validated with `settings` instead of those beside the file.
+pub fn open_with_settings(
+    filename: &str,
+    settings: &ValidationSettings,
+) -> Result<OpenedDiagram, Error> {
+    let content = std::fs::read_to_string(filename)?;
+    let (diagram, source_map) = read(&content)?;
+    let diagram = Diagram::from(diagram);
+    let warnings =
+        validation::validate_diagram(&diagram, settings).map_err(|errors| ValidationErrors {
+            errors: source_map.locate(errors.errors),
+        })?;
+
+    Ok(OpenedDiagram {
+        diagram,
+        warnings: source_map.locate(warnings),
+    })
+}
+
+/// Opens a diagram as its virtual diagram named `name` shows it; see [`Diagram::project`].
+pub fn open_virtual_diagram(filename: &str, name: &str) -> Result<OpenedDiagram, Error> {
+    let OpenedDiagram { diagram, warnings } = open(filename)?;
+    let diagram = diagram
+        .project(name)
+        .ok_or_else(|| Error::UnknownVirtualDiagram(name.to_string()))?;
+
+    Ok(OpenedDiagram { diagram, warnings })
+}
+
+/// The data of an inserted image, which is left out of the diagram returned by [`open`].
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImageData {
+    pub format: ImageFormat,
+    /// The image file, base64 encoded.
+    pub data: String,
+}
+
+/// Opens a diagram and returns the data of its inserted image at `index`, or `None` when the
+/// diagram has fewer images.
+pub fn open_image(filename: &str, index: usize) -> Result<Option<ImageData>, Error> {
+    let images = open(filename)?
+        .diagram
+        .diagram_walkers
+        .and_then(|walkers| walkers.images);
+
+    Ok(images
+        .and_then(|mut images| (index < images.len()).then(|| images.swap_remove(index)))
+        .and_then(|image| {
+            Some(ImageData {
+                format: image.format?,
+                data: image.data,
+            })
+        }))
+}
+
+pub fn save(diagram: &Diagram, filename: &str) -> Result<(), Error> {
+    save_with_format(diagram, filename, Format::Preserve)
+}
+
+pub fn save_with_format(diagram: &Diagram, filename: &str, format: Format) -> Result<(), Error> {
+    save_with_settings(
+        diagram,
+        filename,
+        format,
+        &ValidationSettings::read(filename)?,
+    )
+}
+
+/// Saves a diagram, validated with `settings` instead of those beside the file.
+pub fn save_with_settings(
+    diagram: &Diagram,
+    filename: &str,
+    format: Format,
+    settings: &ValidationSettings,
+) -> Result<(), Error> {
+    let mut diagram = diagram.clone();
+    restore_image_data(&mut diagram, filename)?;
+    validation::validate_diagram(&diagram, settings)?;
+    write_file(filename, &diagram.into(), format)
+}
+
+/// Fills in the data a diagram from the frontend leaves out of its images, from the image with
+/// the same data digest in the file about to be overwritten.
+fn restore_image_data(diagram: &mut Diagram, filename: &str) -> Result<(), Error> {
+    let Some(images) = diagram
+        .diagram_walkers
+        .as_mut()
+        .and_then(|walkers| walkers.images.as_mut())
+        .filter(|images| images.iter().any(|image| image.data.is_empty()))
+    else {
+        return Ok(());
+    };
+
+    let saved = if std::path::Path::new(filename).exists() {
+        let (saved, _) = read(&std::fs::read_to_string(filename)?)?;
+        Diagram::from(saved)
+            .diagram_walkers
+            .and_then(|walkers| walkers.images)
+            .unwrap_or_default()
+    } else {
+        Vec::new()
+    };
+    for (index, image) in images.iter_mut().enumerate() {
+        if !image.data.is_empty() {
+            continue;
+        }
+        let source = image.data_digest.as_ref().and_then(|digest| {
+            saved
+                .iter()
+                .find(|saved| saved.data_digest.as_ref() == Some(digest))
+        });
+        let Some(source) = source else {
+            let image = image
+                .id
+                .clone()
+                .unwrap_or_else(|| format!("image[{index}]"));
+            return Err(Error::MissingImageData(format!(
+                "{image} is not in {filename}"
+            )));
+        };
+        image.data = source.data.clone();
+    }
+
+    Ok(())
 }
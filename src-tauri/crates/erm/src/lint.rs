@@ -0,0 +1,425 @@
+mod keywords;
+
+use crate::dtos::diagram::Diagram;
+use crate::dtos::diagram::diagram_settings::{ConstraintPrefix, DiagramSettings};
+use crate::dtos::diagram::diagram_walkers::tables::columns::ColumnItem;
+use crate::errors::Rule;
+use crate::validation::{ValidationError, rules};
+
+/// The rules checked by [`lint`], all turned off with `validate_physical_name`.
+pub const RULES: &[Rule] = &[
+    rules::INVALID_PHYSICAL_NAME,
+    rules::PHYSICAL_NAME_CASE,
+    rules::PHYSICAL_NAME_TOO_LONG,
+    rules::RESERVED_PHYSICAL_NAME,
+    rules::MISSING_CONSTRAINT_NAME_PREFIX,
+];
+
+/// The database a diagram targets, as far as its identifiers are concerned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dialect {
+    MySql,
+    PostgreSql,
+    Oracle,
+    SqlServer,
+    Db2,
+    Sqlite,
+    /// Any other database, checked against the keywords reserved by standard SQL.
+    Standard,
+}
+
+impl Dialect {
+    /// Returns the dialect of the `database` of the diagram settings, e.g. `PostgreSQL`.
+    pub fn from_database(database: &str) -> Self {
+        let database = database.to_ascii_lowercase();
+        if database.starts_with("mysql") {
+            Self::MySql
+        } else if database.starts_with("postgres") {
+            Self::PostgreSql
+        } else if database.starts_with("oracle") {
+            Self::Oracle
+        } else if database.starts_with("sqlserver") || database.starts_with("sql server") {
+            Self::SqlServer
+        } else if database.starts_with("db2") {
+            Self::Db2
+        } else if database.starts_with("sqlite") {
+            Self::Sqlite
+        } else {
+            Self::Standard
+        }
+    }
+
+    /// Returns the maximum number of characters of an identifier, if the database has one.
+    ///
+    /// Oracle is held to the 30 characters of releases before 12.2, which many schemas still
+    /// have to support.
+    pub fn max_identifier_length(self) -> Option<usize> {
+        match self {
+            Self::MySql => Some(64),
+            Self::PostgreSql => Some(63),
+            Self::Oracle => Some(30),
+            Self::SqlServer | Self::Db2 => Some(128),
+            Self::Sqlite | Self::Standard => None,
+        }
+    }
+
+    /// Returns the keywords that cannot be used as an unquoted identifier, in upper case.
+    pub fn reserved_words(self) -> &'static [&'static str] {
+        match self {
+            Self::MySql => keywords::MYSQL,
+            Self::PostgreSql => keywords::POSTGRESQL,
+            Self::Oracle => keywords::ORACLE,
+            Self::SqlServer => keywords::SQL_SERVER,
+            Self::Sqlite => keywords::SQLITE,
+            Self::Db2 | Self::Standard => keywords::STANDARD,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Case {
+    /// No lower case letter, e.g. `MEMBER_ID`.
+    Upper,
+    /// No upper case letter, e.g. `member_id`.
+    Snake,
+}
+
+impl Case {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Upper => "UPPER_CASE",
+            Self::Snake => "snake_case",
+        }
+    }
+
+    fn matches(self, name: &str) -> bool {
+        match self {
+            Self::Upper => !name.chars().any(char::is_lowercase),
+            Self::Snake => !name.chars().any(char::is_uppercase),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CharacterSet {
+    /// ASCII letters, digits and underscores.
+    Ascii,
+    /// Letters of any script, digits and underscores.
+    Unicode,
+}
+
+impl CharacterSet {
+    fn contains(self, character: char) -> bool {
+        character == '_'
+            || match self {
+                Self::Ascii => character.is_ascii_alphanumeric(),
+                Self::Unicode => character.is_alphanumeric(),
+            }
+    }
+}
+
+/// The conventions physical names are checked against.
+///
+/// [`NamingConventions::new`] derives them from the diagram settings, and each of them can be
+/// changed or turned off afterwards.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NamingConventions {
+    pub case: Option<Case>,
+    pub character_set: CharacterSet,
+    pub max_length: Option<usize>,
+    pub reserved_words: &'static [&'static str],
+    pub foreign_key_prefix: Option<String>,
+    pub unique_prefix: Option<String>,
+    pub index_prefix: Option<String>,
+}
+
+impl NamingConventions {
+    /// Follows `capital` for the case, `database` for the length and the reserved words, and
+    /// `design_settings` for the constraint name prefixes.
+    pub fn new(settings: &DiagramSettings) -> Self {
+        let dialect = Dialect::from_database(&settings.database);
+        let design_settings = settings.design_settings.as_ref();
+        let prefix = |prefix: Option<&ConstraintPrefix>| {
+            prefix
+                .and_then(|prefix| prefix.default_prefix.clone())
+                .filter(|prefix| !prefix.is_empty())
+        };
+
+        Self {
+            case: Some(if settings.capital {
+                Case::Upper
+            } else {
+                Case::Snake
+            }),
+            character_set: CharacterSet::Unicode,
+            max_length: dialect.max_identifier_length(),
+            reserved_words: dialect.reserved_words(),
+            foreign_key_prefix: prefix(design_settings.and_then(|s| s.foreign_key.as_ref())),
+            unique_prefix: prefix(design_settings.and_then(|s| s.unique.as_ref())),
+            index_prefix: prefix(design_settings.and_then(|s| s.index.as_ref())),
+        }
+    }
+}
+
+/// What a checked name belongs to, for messages and constraint prefixes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NameKind {
+    Table,
+    View,
+    Column,
+    ForeignKey,
+    UniqueKey,
+    Index,
+}
+
+impl NameKind {
+    fn label(self) -> &'static str {
+        match self {
+            Self::Table => "table physical_name",
+            Self::View => "view physical_name",
+            Self::Column => "column physical_name",
+            Self::ForeignKey => "relationship name",
+            Self::UniqueKey => "compound unique key name",
+            Self::Index => "index name",
+        }
+    }
+}
+
+struct Name<'a> {
+    kind: NameKind,
+    path: String,
+    value: &'a str,
+    targets: Vec<(&'static str, &'a str)>,
+}
+
+/// Checks the physical names of the tables, views, columns and constraints of the diagram.
+///
+/// Constraints without a name are left out, since the database names them.
+pub fn lint(diagram: &Diagram, conventions: &NamingConventions) -> Vec<ValidationError> {
+    names(diagram)
+        .into_iter()
+        .filter(|name| {
+            matches!(
+                name.kind,
+                NameKind::Table | NameKind::View | NameKind::Column
+            ) || !name.value.is_empty()
+        })
+        .flat_map(|name| lint_name(&name, conventions))
+        .collect()
+}
+
+fn lint_name(name: &Name, conventions: &NamingConventions) -> Vec<ValidationError> {
+    let mut errors = Vec::new();
+    let label = name.kind.label();
+
+    let empty_or_leading_digit = name
+        .value
+        .chars()
+        .next()
+        .is_none_or(|first| first.is_ascii_digit());
+    if empty_or_leading_digit
+        || !name
+            .value
+            .chars()
+            .all(|character| conventions.character_set.contains(character))
+    {
+        errors.push(error(
+            name,
+            &rules::INVALID_PHYSICAL_NAME,
+            format!("invalid {label}: {}", name.value),
+        ));
+    }
+
+    if let Some(case) = conventions.case
+        && !case.matches(name.value)
+    {
+        errors.push(error(
+            name,
+            &rules::PHYSICAL_NAME_CASE,
+            format!("{label} is not {}: {}", case.name(), name.value),
+        ));
+    }
+
+    if let Some(max_length) = conventions.max_length {
+        let length = name.value.chars().count();
+        if length > max_length {
+            errors.push(error(
+                name,
+                &rules::PHYSICAL_NAME_TOO_LONG,
+                format!(
+                    "{label} is longer than {max_length} characters: {}",
+                    name.value
+                ),
+            ));
+        }
+    }
+
+    if conventions
+        .reserved_words
+        .iter()
+        .any(|word| word.eq_ignore_ascii_case(name.value))
+    {
+        errors.push(error(
+            name,
+            &rules::RESERVED_PHYSICAL_NAME,
+            format!("{label} is a reserved word: {}", name.value),
+        ));
+    }
+
+    let prefix = match name.kind {
+        NameKind::ForeignKey => conventions.foreign_key_prefix.as_deref(),
+        NameKind::UniqueKey => conventions.unique_prefix.as_deref(),
+        NameKind::Index => conventions.index_prefix.as_deref(),
+        NameKind::Table | NameKind::View | NameKind::Column => None,
+    };
+    if let Some(prefix) = prefix
+        && !name.value.starts_with(prefix)
+    {
+        errors.push(error(
+            name,
+            &rules::MISSING_CONSTRAINT_NAME_PREFIX,
+            format!("{label} does not start with {prefix}: {}", name.value),
+        ));
+    }
+
+    errors
+}
+
+fn error(name: &Name, rule: &'static Rule, message: String) -> ValidationError {
+    name.targets.iter().fold(
+        ValidationError::new(rule, name.path.clone(), message),
+        |error, (label, value)| error.with_target(*label, *value),
+    )
+}
+
+/// Returns every name to check, in document order.
+fn names(diagram: &Diagram) -> Vec<Name<'_>> {
+    let mut names = Vec::new();
+
+    let tables = diagram
+        .diagram_walkers
+        .iter()
+        .flat_map(|walkers| walkers.tables.iter().flatten());
+    for (table_index, table) in tables.enumerate() {
+        let table_path = format!("diagram_walkers.table[{table_index}]");
+        let table_name = table.physical_name.as_str();
+
+        names.push(Name {
+            kind: NameKind::Table,
+            path: format!("{table_path}.physical_name"),
+            value: table_name,
+            targets: vec![("table name", table_name)],
+        });
+
+        let relationships = table.connections.relationships.iter().flatten();
+        for (relationship_index, relationship) in relationships.enumerate() {
+            names.push(Name {
+                kind: NameKind::ForeignKey,
+                path: format!("{table_path}.connections.relationship[{relationship_index}].name"),
+                value: &relationship.name,
+                targets: vec![
+                    ("table name", table_name),
+                    ("relationship name", &relationship.name),
+                ],
+            });
+        }
+
+        let columns = table.columns.items.iter().flatten().enumerate();
+        for (item_index, column) in columns.filter_map(|(index, item)| match item {
+            ColumnItem::Normal(column) => Some((index, column)),
+            ColumnItem::Group(_) => None,
+        }) {
+            // Foreign key columns may leave their physical name empty to inherit the referred one.
+            if column.referred_column.is_some() && column.physical_name.is_empty() {
+                continue;
+            }
+            names.push(Name {
+                kind: NameKind::Column,
+                path: format!("{table_path}.columns.normal_column[{item_index}].physical_name"),
+                value: &column.physical_name,
+                targets: vec![
+                    ("table name", table_name),
+                    ("column name", &column.physical_name),
+                ],
+            });
+        }
+
+        for (index_index, index) in table.indexes.iter().flatten().enumerate() {
+            names.push(Name {
+                kind: NameKind::Index,
+                path: format!("{table_path}.indexes[{index_index}].name"),
+                value: &index.name,
+                targets: vec![("table name", table_name), ("index name", &index.name)],
+            });
+        }
+
+        let compound_unique_keys = table
+            .compound_unique_key_list
+            .compound_unique_keys
+            .iter()
+            .flatten();
+        for (key_index, key) in compound_unique_keys.enumerate() {
+            names.push(Name {
+                kind: NameKind::UniqueKey,
+                path: format!(
+                    "{table_path}.compound_unique_key_list.compound_unique_key[{key_index}].name"
+                ),
+                value: &key.name,
+                targets: vec![
+                    ("table name", table_name),
+                    ("compound unique key name", &key.name),
+                ],
+            });
+        }
+    }
+
+    let views = diagram
+        .diagram_walkers
+        .iter()
+        .flat_map(|walkers| walkers.views.iter().flatten());
+    for (view_index, view) in views.enumerate() {
+        let view_path = format!("diagram_walkers.view[{view_index}]");
+        let view_name = view.physical_name.as_str();
+
+        names.push(Name {
+            kind: NameKind::View,
+            path: format!("{view_path}.physical_name"),
+            value: view_name,
+            targets: vec![("view name", view_name)],
+        });
+
+        let columns = view.columns.items.iter().flatten().enumerate();
+        for (item_index, column) in columns.filter_map(|(index, item)| match item {
+            ColumnItem::Normal(column) => Some((index, column)),
+            ColumnItem::Group(_) => None,
+        }) {
+            names.push(Name {
+                kind: NameKind::Column,
+                path: format!("{view_path}.columns.normal_column[{item_index}].physical_name"),
+                value: &column.physical_name,
+                targets: vec![
+                    ("view name", view_name),
+                    ("column name", &column.physical_name),
+                ],
+            });
+        }
+    }
+
+    for (group_index, group) in diagram.column_groups.iter().flatten().enumerate() {
+        for (column_index, column) in group.columns.normal_columns.iter().flatten().enumerate() {
+            names.push(Name {
+                kind: NameKind::Column,
+                path: format!(
+                    "column_groups[{group_index}].columns.normal_column[{column_index}].physical_name"
+                ),
+                value: &column.physical_name,
+                targets: vec![
+                    ("column group name", &group.column_group_name),
+                    ("column name", &column.physical_name),
+                ],
+            });
+        }
+    }
+
+    names
+}
@@ -0,0 +1,1125 @@
+use std::collections::BTreeSet;
+use std::fmt;
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::dtos::diagram::Diagram;
+use crate::dtos::diagram::column_groups::{self, ColumnGroup};
+use crate::dtos::diagram::diagram_settings::DiagramSettings;
+use crate::dtos::diagram::diagram_walkers::DiagramWalkers;
+use crate::dtos::diagram::diagram_walkers::groups::WalkerGroup;
+use crate::dtos::diagram::diagram_walkers::images::Image;
+use crate::dtos::diagram::diagram_walkers::notes::WalkerNote;
+use crate::dtos::diagram::diagram_walkers::tables::Table;
+use crate::dtos::diagram::diagram_walkers::tables::columns::{ColumnItem, Columns, NormalColumn};
+use crate::dtos::diagram::diagram_walkers::tables::compound_unique_key_list::{
+    CompoundUniqueKey, CompoundUniqueKeyList,
+};
+use crate::dtos::diagram::diagram_walkers::tables::connections::{Connections, Relationship};
+use crate::dtos::diagram::diagram_walkers::tables::indexes::Index;
+use crate::dtos::diagram::diagram_walkers::views::View;
+use crate::dtos::diagram::virtual_diagrams::{VirtualDiagram, VirtualTable};
+use crate::errors::Error;
+use crate::validation::{self, settings::ValidationSettings};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum MergeConflictKind {
+    /// The same property was changed to different values on both sides.
+    BothModified,
+    /// Ours changed an element that theirs deleted.
+    ModifiedAndDeleted,
+    /// Ours deleted an element that theirs changed.
+    DeletedAndModified,
+    /// Both sides added an element with the same key but different contents.
+    BothAdded,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MergeConflict {
+    pub path: String,
+    pub kind: MergeConflictKind,
+    pub base: Option<String>,
+    pub ours: Option<String>,
+    pub theirs: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MergeConflicts {
+    pub conflicts: Vec<MergeConflict>,
+}
+
+impl fmt::Display for MergeConflict {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            MergeConflictKind::BothModified => write!(
+                formatter,
+                "{}: changed on both sides (base: {}, ours: {}, theirs: {})",
+                self.path,
+                self.base.as_deref().unwrap_or_default(),
+                self.ours.as_deref().unwrap_or_default(),
+                self.theirs.as_deref().unwrap_or_default()
+            ),
+            MergeConflictKind::ModifiedAndDeleted => {
+                write!(
+                    formatter,
+                    "{}: changed in ours, deleted in theirs",
+                    self.path
+                )
+            }
+            MergeConflictKind::DeletedAndModified => {
+                write!(
+                    formatter,
+                    "{}: deleted in ours, changed in theirs",
+                    self.path
+                )
+            }
+            MergeConflictKind::BothAdded => {
+                write!(
+                    formatter,
+                    "{}: added on both sides with different contents",
+                    self.path
+                )
+            }
+        }
+    }
+}
+
+impl fmt::Display for MergeConflicts {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(formatter, "Merge conflict")?;
+
+        for conflict in &self.conflicts {
+            write!(formatter, "\n- {conflict}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for MergeConflicts {}
+
+/// Merges the changes made in `ours` and `theirs` since their common ancestor `base`.
+///
+/// Tables and views are matched by physical name, notes and images by their `id`, walker groups
+/// by name, columns by physical name within their table, relationships, indexes and compound
+/// unique keys by name, column groups by `column_group_name`, and virtual diagrams by name with
+/// their tables by `table_id`, so that changes to different elements never conflict. When both
+/// sides change the same property differently, every such conflict is returned instead of a
+/// diagram.
+///
+/// The merged diagram is validated with the default settings; see [`merge_with_settings`].
+pub fn merge(base: &Diagram, ours: &Diagram, theirs: &Diagram) -> Result<Diagram, Error> {
+    merge_with_settings(base, ours, theirs, &ValidationSettings::default())
+}
+
+/// Merges like [`merge`] and validates the merged diagram with `settings`.
+pub fn merge_with_settings(
+    base: &Diagram,
+    ours: &Diagram,
+    theirs: &Diagram,
+    settings: &ValidationSettings,
+) -> Result<Diagram, Error> {
+    let mut merger = Merger::default();
+    let merged = merger.diagram(base, ours, theirs);
+
+    if !merger.conflicts.is_empty() {
+        return Err(MergeConflicts {
+            conflicts: merger.conflicts,
+        }
+        .into());
+    }
+
+    validation::validate_diagram(&merged, settings)?;
+    Ok(merged)
+}
+
+/// Merges the listed fields of three values into local variables of the same name.
+macro_rules! merge_fields {
+    ($merger:expr, $path:expr, $base:expr, $ours:expr, $theirs:expr, { $($field:ident),+ $(,)? }) => {
+        $(
+            let $field = $merger.value(
+                &field_path($path, stringify!($field)),
+                &$base.$field,
+                &$ours.$field,
+                &$theirs.$field,
+            );
+        )+
+    };
+}
+
+#[derive(Default)]
+struct Merger {
+    conflicts: Vec<MergeConflict>,
+}
+
+impl Merger {
+    fn diagram(&mut self, base: &Diagram, ours: &Diagram, theirs: &Diagram) -> Diagram {
+        let path = "";
+        merge_fields!(self, path, base, ours, theirs, {
+            presenter,
+            page_settings,
+            category_index,
+            current_ermodel,
+            zoom,
+            x,
+            y,
+            default_color,
+            color,
+            font_name,
+            font_size,
+            unknown_elements,
+        });
+
+        let merged_tables = self.list(
+            "diagram_walkers",
+            tables(base),
+            tables(ours),
+            tables(theirs),
+            |table| format!("table[{}]", table.physical_name),
+            Self::table,
+        );
+        let merged_views = self.list(
+            "diagram_walkers",
+            views(base),
+            views(ours),
+            views(theirs),
+            |view| format!("view[{}]", view.physical_name),
+            Self::view,
+        );
+        let merged_notes = self.list(
+            "diagram_walkers",
+            notes(base),
+            notes(ours),
+            notes(theirs),
+            |note| format!("walker_note[{}]", note_key(note)),
+            Self::note,
+        );
+        let merged_groups = self.list(
+            "diagram_walkers",
+            groups(base),
+            groups(ours),
+            groups(theirs),
+            |group| format!("walker_group[{}]", group_key(group)),
+            Self::group,
+        );
+        let merged_images = self.list(
+            "diagram_walkers",
+            images(base),
+            images(ours),
+            images(theirs),
+            |image| format!("image[{}]", image_key(image)),
+            Self::image,
+        );
+        let column_groups = self.list(
+            "column_groups",
+            base.column_groups.as_deref().unwrap_or_default(),
+            ours.column_groups.as_deref().unwrap_or_default(),
+            theirs.column_groups.as_deref().unwrap_or_default(),
+            |group| format!("column_group[{}]", group.column_group_name),
+            Self::column_group,
+        );
+        let virtual_diagrams = self.list(
+            "vdiagrams",
+            base.virtual_diagrams.as_deref().unwrap_or_default(),
+            ours.virtual_diagrams.as_deref().unwrap_or_default(),
+            theirs.virtual_diagrams.as_deref().unwrap_or_default(),
+            |virtual_diagram| format!("vdiagram[{}]", virtual_diagram_key(virtual_diagram)),
+            Self::virtual_diagram,
+        );
+
+        Diagram {
+            presenter,
+            page_settings,
+            category_index,
+            current_ermodel,
+            zoom,
+            x,
+            y,
+            default_color,
+            color,
+            font_name,
+            font_size,
+            diagram_settings: self.diagram_settings(
+                &base.diagram_settings,
+                &ours.diagram_settings,
+                &theirs.diagram_settings,
+            ),
+            diagram_walkers: diagram_walkers(
+                ours,
+                merged_tables,
+                merged_views,
+                merged_notes,
+                merged_groups,
+                merged_images,
+            ),
+            virtual_diagrams: optional_list(virtual_diagrams, ours.virtual_diagrams.is_some()),
+            column_groups: optional_list(column_groups, ours.column_groups.is_some()),
+            unknown_elements,
+            // The merged file replaces ours, so it keeps the layout ours was read with.
+            xml_layout: ours.xml_layout.clone(),
+        }
+    }
+
+    fn diagram_settings(
+        &mut self,
+        base: &DiagramSettings,
+        ours: &DiagramSettings,
+        theirs: &DiagramSettings,
+    ) -> DiagramSettings {
+        let path = "diagram_settings";
+        merge_fields!(self, path, base, ours, theirs, {
+            database,
+            capital,
+            table_style,
+            notation,
+            notation_level,
+            notation_expand_group,
+            view_mode,
+            outline_view_mode,
+            view_order_by,
+            auto_ime_change,
+            validate_physical_name,
+            use_bezier_curve,
+            suspend_validator,
+            title_font_em,
+            master_data_base_path,
+            use_view_object,
+            export_settings,
+            category_settings,
+            model_properties,
+            table_properties,
+            environment_settings,
+            design_settings,
+        });
+
+        DiagramSettings {
+            database,
+            capital,
+            table_style,
+            notation,
+            notation_level,
+            notation_expand_group,
+            view_mode,
+            outline_view_mode,
+            view_order_by,
+            auto_ime_change,
+            validate_physical_name,
+            use_bezier_curve,
+            suspend_validator,
+            title_font_em,
+            master_data_base_path,
+            use_view_object,
+            export_settings,
+            category_settings,
+            model_properties,
+            table_properties,
+            environment_settings,
+            design_settings,
+        }
+    }
+
+    fn table(&mut self, path: &str, base: &Table, ours: &Table, theirs: &Table) -> Table {
+        merge_fields!(self, path, base, ours, theirs, {
+            logical_name,
+            description,
+            height,
+            width,
+            font_name,
+            font_size,
+            x,
+            y,
+            color,
+            table_constraint,
+            primary_key_name,
+            option,
+        });
+
+        let comment_connections = self.value(
+            &format!("{path}.connections.comment_connections"),
+            &base.connections.comment_connections,
+            &ours.connections.comment_connections,
+            &theirs.connections.comment_connections,
+        );
+        let relationships = self.list(
+            &format!("{path}.connections"),
+            base.connections
+                .relationships
+                .as_deref()
+                .unwrap_or_default(),
+            ours.connections
+                .relationships
+                .as_deref()
+                .unwrap_or_default(),
+            theirs
+                .connections
+                .relationships
+                .as_deref()
+                .unwrap_or_default(),
+            |relationship| format!("relationship[{}]", relationship.name),
+            Self::relationship,
+        );
+        let columns = self.list(
+            &format!("{path}.columns"),
+            base.columns.items.as_deref().unwrap_or_default(),
+            ours.columns.items.as_deref().unwrap_or_default(),
+            theirs.columns.items.as_deref().unwrap_or_default(),
+            |item| match item {
+                ColumnItem::Normal(column) => format!("normal_column[{}]", column.physical_name),
+                ColumnItem::Group(group) => format!("column_group[{group}]"),
+            },
+            Self::column_item,
+        );
+        let indexes = self.list(
+            &format!("{path}.indexes"),
+            base.indexes.as_deref().unwrap_or_default(),
+            ours.indexes.as_deref().unwrap_or_default(),
+            theirs.indexes.as_deref().unwrap_or_default(),
+            |index| format!("index[{}]", index.name),
+            Self::index,
+        );
+        let compound_unique_keys = self.list(
+            &format!("{path}.compound_unique_key_list"),
+            base.compound_unique_key_list
+                .compound_unique_keys
+                .as_deref()
+                .unwrap_or_default(),
+            ours.compound_unique_key_list
+                .compound_unique_keys
+                .as_deref()
+                .unwrap_or_default(),
+            theirs
+                .compound_unique_key_list
+                .compound_unique_keys
+                .as_deref()
+                .unwrap_or_default(),
+            |key| format!("compound_unique_key[{}]", key.name),
+            Self::compound_unique_key,
+        );
+
+        Table {
+            physical_name: ours.physical_name.clone(),
+            logical_name,
+            description,
+            height,
+            width,
+            font_name,
+            font_size,
+            x,
+            y,
+            color,
+            connections: Connections {
+                relationships: optional_list(
+                    relationships,
+                    ours.connections.relationships.is_some(),
+                ),
+                comment_connections,
+            },
+            table_constraint,
+            primary_key_name,
+            option,
+            columns: Columns {
+                items: optional_list(columns, ours.columns.items.is_some()),
+            },
+            indexes: optional_list(indexes, ours.indexes.is_some()),
+            compound_unique_key_list: CompoundUniqueKeyList {
+                compound_unique_keys: optional_list(
+                    compound_unique_keys,
+                    ours.compound_unique_key_list.compound_unique_keys.is_some(),
+                ),
+            },
+        }
+    }
+
+    fn relationship(
+        &mut self,
+        path: &str,
+        base: &Relationship,
+        ours: &Relationship,
+        theirs: &Relationship,
+    ) -> Relationship {
+        merge_fields!(self, path, base, ours, theirs, {
+            source,
+            target,
+            bendpoints,
+            fk_columns,
+            parent_cardinality,
+            child_cardinality,
+            reference_for_pk,
+            on_delete_action,
+            on_update_action,
+            referred_simple_unique_column,
+            referred_compound_unique_key,
+        });
+
+        Relationship {
+            name: ours.name.clone(),
+            source,
+            target,
+            bendpoints,
+            fk_columns,
+            parent_cardinality,
+            child_cardinality,
+            reference_for_pk,
+            on_delete_action,
+            on_update_action,
+            referred_simple_unique_column,
+            referred_compound_unique_key,
+        }
+    }
+
+    fn column_item(
+        &mut self,
+        path: &str,
+        base: &ColumnItem,
+        ours: &ColumnItem,
+        theirs: &ColumnItem,
+    ) -> ColumnItem {
+        match (base, ours, theirs) {
+            (ColumnItem::Normal(base), ColumnItem::Normal(ours), ColumnItem::Normal(theirs)) => {
+                ColumnItem::Normal(self.normal_column(path, base, ours, theirs))
+            }
+            // Column group references are keyed by the group name and carry nothing else.
+            _ => ours.clone(),
+        }
+    }
+
+    fn normal_column(
+        &mut self,
+        path: &str,
+        base: &NormalColumn,
+        ours: &NormalColumn,
+        theirs: &NormalColumn,
+    ) -> NormalColumn {
+        merge_fields!(self, path, base, ours, theirs, {
+            logical_name,
+            description,
+            column_type,
+            length,
+            decimal,
+            args,
+            unsigned,
+            not_null,
+            unique_key,
+            default_value,
+            primary_key,
+            auto_increment,
+            referred_column,
+            relationship,
+        });
+
+        NormalColumn {
+            physical_name: ours.physical_name.clone(),
+            logical_name,
+            description,
+            column_type,
+            length,
+            decimal,
+            args,
+            unsigned,
+            not_null,
+            unique_key,
+            default_value,
+            primary_key,
+            auto_increment,
+            referred_column,
+            relationship,
+        }
+    }
+
+    fn index(&mut self, path: &str, base: &Index, ours: &Index, theirs: &Index) -> Index {
+        merge_fields!(self, path, base, ours, theirs, {
+            index_type,
+            description,
+            full_text,
+            non_unique,
+            columns,
+        });
+
+        Index {
+            name: ours.name.clone(),
+            index_type,
+            description,
+            full_text,
+            non_unique,
+            columns,
+        }
+    }
+
+    fn compound_unique_key(
+        &mut self,
+        path: &str,
+        base: &CompoundUniqueKey,
+        ours: &CompoundUniqueKey,
+        theirs: &CompoundUniqueKey,
+    ) -> CompoundUniqueKey {
+        merge_fields!(self, path, base, ours, theirs, { columns });
+
+        CompoundUniqueKey {
+            name: ours.name.clone(),
+            columns,
+        }
+    }
+
+    /// Views are merged property by property, with their connections and columns compared whole.
+    fn view(&mut self, path: &str, base: &View, ours: &View, theirs: &View) -> View {
+        merge_fields!(self, path, base, ours, theirs, {
+            logical_name,
+            description,
+            height,
+            width,
+            font_name,
+            font_size,
+            x,
+            y,
+            color,
+            connections,
+            sql,
+            columns,
+        });
+
+        View {
+            physical_name: ours.physical_name.clone(),
+            logical_name,
+            description,
+            height,
+            width,
+            font_name,
+            font_size,
+            x,
+            y,
+            color,
+            connections,
+            sql,
+            columns,
+        }
+    }
+
+    fn note(
+        &mut self,
+        path: &str,
+        base: &WalkerNote,
+        ours: &WalkerNote,
+        theirs: &WalkerNote,
+    ) -> WalkerNote {
+        merge_fields!(self, path, base, ours, theirs, {
+            height,
+            width,
+            font_name,
+            font_size,
+            x,
+            y,
+            color,
+            connections,
+            note_text,
+        });
+
+        WalkerNote {
+            id: ours.id.clone(),
+            height,
+            width,
+            font_name,
+            font_size,
+            x,
+            y,
+            color,
+            connections,
+            note_text,
+        }
+    }
+
+    fn group(
+        &mut self,
+        path: &str,
+        base: &WalkerGroup,
+        ours: &WalkerGroup,
+        theirs: &WalkerGroup,
+    ) -> WalkerGroup {
+        merge_fields!(self, path, base, ours, theirs, {
+            id,
+            height,
+            width,
+            font_name,
+            font_size,
+            x,
+            y,
+            color,
+            connections,
+            members,
+        });
+
+        WalkerGroup {
+            id,
+            height,
+            width,
+            font_name,
+            font_size,
+            x,
+            y,
+            color,
+            connections,
+            walker_group_name: ours.walker_group_name.clone(),
+            members,
+        }
+    }
+
+    fn image(&mut self, path: &str, base: &Image, ours: &Image, theirs: &Image) -> Image {
+        merge_fields!(self, path, base, ours, theirs, {
+            height,
+            width,
+            font_name,
+            font_size,
+            x,
+            y,
+            color,
+            connections,
+            data,
+            data_digest,
+            format,
+            hue,
+            saturation,
+            brightness,
+            alpha,
+            fix_aspect_ratio,
+        });
+
+        Image {
+            id: ours.id.clone(),
+            height,
+            width,
+            font_name,
+            font_size,
+            x,
+            y,
+            color,
+            connections,
+            data,
+            data_digest,
+            format,
+            hue,
+            saturation,
+            brightness,
+            alpha,
+            fix_aspect_ratio,
+        }
+    }
+
+    fn column_group(
+        &mut self,
+        path: &str,
+        base: &ColumnGroup,
+        ours: &ColumnGroup,
+        theirs: &ColumnGroup,
+    ) -> ColumnGroup {
+        let normal_columns = self.list(
+            &format!("{path}.columns"),
+            base.columns.normal_columns.as_deref().unwrap_or_default(),
+            ours.columns.normal_columns.as_deref().unwrap_or_default(),
+            theirs.columns.normal_columns.as_deref().unwrap_or_default(),
+            |column| format!("normal_column[{}]", column.physical_name),
+            Self::column_group_column,
+        );
+
+        ColumnGroup {
+            column_group_name: ours.column_group_name.clone(),
+            columns: column_groups::Columns {
+                normal_columns: optional_list(
+                    normal_columns,
+                    ours.columns.normal_columns.is_some(),
+                ),
+            },
+        }
+    }
+
+    fn virtual_diagram(
+        &mut self,
+        path: &str,
+        base: &VirtualDiagram,
+        ours: &VirtualDiagram,
+        theirs: &VirtualDiagram,
+    ) -> VirtualDiagram {
+        merge_fields!(self, path, base, ours, theirs, {
+            vdiagram_id,
+            color,
+            notes,
+            groups,
+        });
+        let tables = self.list(
+            &format!("{path}.vtables"),
+            base.tables.as_deref().unwrap_or_default(),
+            ours.tables.as_deref().unwrap_or_default(),
+            theirs.tables.as_deref().unwrap_or_default(),
+            |table| format!("vtable[{}]", table.table_id),
+            Self::virtual_table,
+        );
+
+        VirtualDiagram {
+            vdiagram_id,
+            vdiagram_name: ours.vdiagram_name.clone(),
+            color,
+            tables: optional_list(tables, ours.tables.is_some()),
+            notes,
+            groups,
+        }
+    }
+
+    fn virtual_table(
+        &mut self,
+        path: &str,
+        base: &VirtualTable,
+        ours: &VirtualTable,
+        theirs: &VirtualTable,
+    ) -> VirtualTable {
+        merge_fields!(self, path, base, ours, theirs, {
+            x,
+            y,
+            width,
+            height,
+            font_name,
+            font_size,
+        });
+
+        VirtualTable {
+            table_id: ours.table_id.clone(),
+            x,
+            y,
+            width,
+            height,
+            font_name,
+            font_size,
+        }
+    }
+
+    fn column_group_column(
+        &mut self,
+        path: &str,
+        base: &column_groups::NormalColumn,
+        ours: &column_groups::NormalColumn,
+        theirs: &column_groups::NormalColumn,
+    ) -> column_groups::NormalColumn {
+        merge_fields!(self, path, base, ours, theirs, {
+            logical_name,
+            description,
+            column_type,
+            length,
+            decimal,
+            args,
+            not_null,
+            unique_key,
+            unsigned,
+            default_value,
+        });
+
+        column_groups::NormalColumn {
+            physical_name: ours.physical_name.clone(),
+            logical_name,
+            description,
+            column_type,
+            length,
+            decimal,
+            args,
+            not_null,
+            unique_key,
+            unsigned,
+            default_value,
+        }
+    }
+
+    fn value<T: Clone + PartialEq + Serialize>(
+        &mut self,
+        path: &str,
+        base: &T,
+        ours: &T,
+        theirs: &T,
+    ) -> T {
+        if ours == theirs || theirs == base {
+            return ours.clone();
+        }
+        if ours == base {
+            return theirs.clone();
+        }
+
+        self.value_conflict(path, &to_value(base), &to_value(ours), &to_value(theirs));
+        ours.clone()
+    }
+
+    /// Reports a value changed differently on both sides. Compound values are reported by the
+    /// fields both sides changed, so that every conflict shows plain values.
+    fn value_conflict(&mut self, path: &str, base: &Value, ours: &Value, theirs: &Value) {
+        let (Value::Object(base_fields), Value::Object(our_fields), Value::Object(their_fields)) =
+            (base, ours, theirs)
+        else {
+            self.conflicts.push(MergeConflict {
+                path: path.to_string(),
+                kind: MergeConflictKind::BothModified,
+                base: Some(plain(base)),
+                ours: Some(plain(ours)),
+                theirs: Some(plain(theirs)),
+            });
+            return;
+        };
+
+        let fields = base_fields
+            .keys()
+            .chain(our_fields.keys())
+            .chain(their_fields.keys())
+            .collect::<BTreeSet<_>>();
+        for field in fields {
+            let base = base_fields.get(field).unwrap_or(&Value::Null);
+            let ours = our_fields.get(field).unwrap_or(&Value::Null);
+            let theirs = their_fields.get(field).unwrap_or(&Value::Null);
+            if ours != theirs && ours != base && theirs != base {
+                self.value_conflict(&field_path(path, &snake_case(field)), base, ours, theirs);
+            }
+        }
+    }
+
+    /// Merges lists of elements keyed by their path segment (e.g. `table[MEMBERS]`), keeping the
+    /// order of `ours` and placing elements added by `theirs` after their predecessor there.
+    fn list<'a, T: Clone + PartialEq>(
+        &mut self,
+        path: &str,
+        base: &'a [T],
+        ours: &'a [T],
+        theirs: &'a [T],
+        key: impl Fn(&T) -> String,
+        merge_item: impl Fn(&mut Self, &str, &T, &T, &T) -> T,
+    ) -> Vec<T> {
+        let find = |items: &'a [T], wanted: &str| items.iter().find(|item| key(item) == wanted);
+        let item_path = |item_key: &str| field_path(path, item_key);
+        let mut merged: Vec<(String, T)> = Vec::with_capacity(ours.len());
+
+        for our_item in ours {
+            let item_key = key(our_item);
+
+            match (find(base, &item_key), find(theirs, &item_key)) {
+                (Some(base_item), Some(their_item)) => {
+                    let item =
+                        merge_item(self, &item_path(&item_key), base_item, our_item, their_item);
+                    merged.push((item_key, item));
+                }
+                (Some(base_item), None) => {
+                    if our_item != base_item {
+                        self.item_conflict(
+                            &item_path(&item_key),
+                            MergeConflictKind::ModifiedAndDeleted,
+                        );
+                        merged.push((item_key, our_item.clone()));
+                    }
+                }
+                (None, Some(their_item)) => {
+                    if our_item != their_item {
+                        self.item_conflict(&item_path(&item_key), MergeConflictKind::BothAdded);
+                    }
+                    merged.push((item_key, our_item.clone()));
+                }
+                (None, None) => merged.push((item_key, our_item.clone())),
+            }
+        }
+
+        for (index, their_item) in theirs.iter().enumerate() {
+            let item_key = key(their_item);
+            if find(ours, &item_key).is_some() {
+                continue;
+            }
+
+            match find(base, &item_key) {
+                Some(base_item) => {
+                    if their_item != base_item {
+                        self.item_conflict(
+                            &item_path(&item_key),
+                            MergeConflictKind::DeletedAndModified,
+                        );
+                    }
+                }
+                None => {
+                    let position = theirs[..index]
+                        .iter()
+                        .rev()
+                        .find_map(|previous| {
+                            let previous_key = key(previous);
+                            merged
+                                .iter()
+                                .position(|(merged_key, _)| *merged_key == previous_key)
+                        })
+                        .map_or(0, |position| position + 1);
+                    merged.insert(position, (item_key, their_item.clone()));
+                }
+            }
+        }
+
+        merged.into_iter().map(|(_, item)| item).collect()
+    }
+
+    fn item_conflict(&mut self, path: &str, kind: MergeConflictKind) {
+        self.conflicts.push(MergeConflict {
+            path: path.to_string(),
+            kind,
+            base: None,
+            ours: None,
+            theirs: None,
+        });
+    }
+}
+
+fn field_path(path: &str, field: &str) -> String {
+    if path.is_empty() {
+        field.to_string()
+    } else {
+        format!("{path}.{field}")
+    }
+}
+
+/// Keeps the walkers absent when `ours` has none and nothing was merged into them.
+fn diagram_walkers(
+    ours: &Diagram,
+    tables: Vec<Table>,
+    views: Vec<View>,
+    notes: Vec<WalkerNote>,
+    groups: Vec<WalkerGroup>,
+    images: Vec<Image>,
+) -> Option<DiagramWalkers> {
+    let walkers = ours.diagram_walkers.as_ref();
+    let tables = optional_list(
+        tables,
+        walkers.is_some_and(|walkers| walkers.tables.is_some()),
+    );
+    let views = optional_list(
+        views,
+        walkers.is_some_and(|walkers| walkers.views.is_some()),
+    );
+    let notes = optional_list(
+        notes,
+        walkers.is_some_and(|walkers| walkers.notes.is_some()),
+    );
+    let groups = optional_list(
+        groups,
+        walkers.is_some_and(|walkers| walkers.groups.is_some()),
+    );
+    let images = optional_list(
+        images,
+        walkers.is_some_and(|walkers| walkers.images.is_some()),
+    );
+
+    (walkers.is_some()
+        || tables.is_some()
+        || views.is_some()
+        || notes.is_some()
+        || groups.is_some()
+        || images.is_some())
+    .then_some(DiagramWalkers {
+        tables,
+        views,
+        notes,
+        groups,
+        images,
+    })
+}
+
+fn tables(diagram: &Diagram) -> &[Table] {
+    diagram
+        .diagram_walkers
+        .as_ref()
+        .and_then(|walkers| walkers.tables.as_deref())
+        .unwrap_or_default()
+}
+
+fn views(diagram: &Diagram) -> &[View] {
+    diagram
+        .diagram_walkers
+        .as_ref()
+        .and_then(|walkers| walkers.views.as_deref())
+        .unwrap_or_default()
+}
+
+fn notes(diagram: &Diagram) -> &[WalkerNote] {
+    diagram
+        .diagram_walkers
+        .as_ref()
+        .and_then(|walkers| walkers.notes.as_deref())
+        .unwrap_or_default()
+}
+
+fn groups(diagram: &Diagram) -> &[WalkerGroup] {
+    diagram
+        .diagram_walkers
+        .as_ref()
+        .and_then(|walkers| walkers.groups.as_deref())
+        .unwrap_or_default()
+}
+
+fn images(diagram: &Diagram) -> &[Image] {
+    diagram
+        .diagram_walkers
+        .as_ref()
+        .and_then(|walkers| walkers.images.as_deref())
+        .unwrap_or_default()
+}
+
+/// Notes written without an `id` cannot be referred to, so their text stands in for it.
+fn note_key(note: &WalkerNote) -> &str {
+    note.id
+        .as_deref()
+        .or(note.note_text.as_deref())
+        .unwrap_or_default()
+}
+
+/// Unnamed groups fall back to their `id`.
+fn group_key(group: &WalkerGroup) -> &str {
+    group
+        .walker_group_name
+        .as_deref()
+        .or(group.id.as_deref())
+        .unwrap_or_default()
+}
+
+/// Images written without an `id` are told apart by their data.
+fn image_key(image: &Image) -> &str {
+    image.id.as_deref().unwrap_or(&image.data)
+}
+
+/// Unnamed virtual diagrams fall back to their `vdiagram_id`.
+fn virtual_diagram_key(virtual_diagram: &VirtualDiagram) -> &str {
+    virtual_diagram
+        .vdiagram_name
+        .as_deref()
+        .or(virtual_diagram.vdiagram_id.as_deref())
+        .unwrap_or_default()
+}
+
+/// Keeps an empty list absent when it was absent in `ours`.
+fn optional_list<T>(items: Vec<T>, present: bool) -> Option<Vec<T>> {
+    (present || !items.is_empty()).then_some(items)
+}
+
+fn to_value<T: Serialize>(value: &T) -> Value {
+    serde_json::to_value(value).unwrap_or_default()
+}
+
+/// Shows a value as plain text: strings unquoted, lists comma-separated and absent values as
+/// `(none)`.
+fn plain(value: &Value) -> String {
+    match value {
+        Value::Null => "(none)".to_string(),
+        Value::String(text) => text.clone(),
+        Value::Array(items) if !items.iter().any(|item| item.is_object() || item.is_array()) => {
+            items.iter().map(plain).collect::<Vec<_>>().join(", ")
+        }
+        value => value.to_string(),
+    }
+}
+
+/// Turns a camelCase DTO field name back into the snake_case name conflict paths use.
+fn snake_case(field: &str) -> String {
+    let mut name = String::with_capacity(field.len());
+    for character in field.chars() {
+        if character.is_ascii_uppercase() {
+            name.push('_');
+        }
+        name.push(character.to_ascii_lowercase());
+    }
+    name
+}
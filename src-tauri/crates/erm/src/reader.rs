@@ -1,12 +1,183 @@
+use std::collections::HashMap;
+
+use super::dtos;
 use super::entities::diagram::Diagram;
+use super::entities::diagram::unknown_elements::{UnknownElement, UnknownElements};
+use super::entities::diagram::xml_layout::{ChildLayout, XmlLayout};
 use super::errors::Error;
-use quick_xml::de::from_reader;
-use std::fs::File;
-use std::io::BufReader;
-
-pub fn read_file(filename: &str) -> Result<Diagram, Error> {
-    let file = File::open(filename)?;
-    let reader = BufReader::new(file);
-    let value: Diagram = from_reader(reader)?;
-    Ok(value)
+use super::schema::{Content, Declaration, SCHEMA};
+use super::source::SourceMap;
+use super::writer::to_xml;
+use super::xml_tree::{self, Element, Node};
+use quick_xml::de::{DeError, Deserializer};
+use serde::Deserialize;
+
+/// Reads a diagram, along with where each of its elements is in the content.
+pub fn read(content: &str) -> Result<(Diagram, SourceMap<'_>), Error> {
+    let source_map = SourceMap::new(content)?;
+
+    let mut deserializer = Deserializer::from_str(content);
+    let mut value = Diagram::deserialize(&mut deserializer).map_err(|error| {
+        let reader = deserializer.get_ref().get_ref();
+        // Other errors are found once the offending value has been read.
+        let position = match error {
+            DeError::InvalidXml(_) => reader.error_position(),
+            _ => reader.buffer_position().saturating_sub(1),
+        };
+        Error::Parse {
+            error,
+            span: Some(source_map.element_at(position as usize)),
+        }
+    })?;
+
+    (value.unknown_elements, value.xml_layout) = capture_source_layout(content, &value)?;
+    Ok((value, source_map))
+}
+
+fn capture_source_layout(
+    content: &str,
+    diagram: &Diagram,
+) -> Result<(UnknownElements, XmlLayout), Error> {
+    // Compare against what survives a round trip through the DTOs, so that anything the DTOs
+    // do not carry is kept as well.
+    let modelled = Diagram::from(dtos::diagram::Diagram::from(diagram.clone()));
+    let original = xml_tree::parse(content)?;
+    let modelled = xml_tree::parse(&to_xml(&modelled)?)?;
+
+    let mut capture = Capture::default();
+    capture.element(&original, &modelled, &SCHEMA.root().content, &original.name);
+    Ok((capture.unknown_elements, capture.xml_layout))
+}
+
+#[derive(Default)]
+struct Capture {
+    unknown_elements: UnknownElements,
+    xml_layout: XmlLayout,
+}
+
+impl Capture {
+    /// Pairs the children of an element in the file with the ones the model writes, keeping the
+    /// children it does not write as unknown elements and recording where the file lays the
+    /// others out differently.
+    fn element(&mut self, original: &Element, modelled: &Element, content: &Content, path: &str) {
+        let declarations = match content {
+            Content::Complex(type_name) => SCHEMA.children(type_name),
+            _ => &[],
+        };
+
+        // Children sharing a name keep their relative order through the round trip, so the n-th
+        // one in the file is the n-th one the model writes.
+        let mut modelled_children: HashMap<&str, Vec<(&str, &Element)>> = HashMap::new();
+        let modelled_keys = modelled.child_keys();
+        for (index, key) in &modelled_keys {
+            if let Node::Element(element) = &modelled.children[*index] {
+                modelled_children
+                    .entry(element.name.as_str())
+                    .or_default()
+                    .push((key.as_str(), element));
+            }
+        }
+
+        let mut seen: HashMap<String, usize> = HashMap::new();
+        let mut layout = ChildLayout::default();
+        let mut order = Vec::new();
+        let mut anchor = None;
+
+        for (index, key) in original.child_keys() {
+            let Node::Element(element) = &original.children[index] else {
+                continue;
+            };
+
+            let partner =
+                modelled_name(element, declarations, &modelled_children).and_then(|name| {
+                    let position = seen.entry(name.clone()).or_default();
+                    *position += 1;
+                    modelled_children.get(name.as_str())?.get(*position - 1)
+                });
+
+            let Some(&(modelled_key, modelled_element)) = partner else {
+                self.unknown_elements.push(
+                    path,
+                    UnknownElement {
+                        anchor: anchor.clone(),
+                        xml: element.to_xml(),
+                    },
+                );
+                anchor = Some(key);
+                continue;
+            };
+
+            let declared = declarations
+                .iter()
+                .find(|declaration| declaration.name == modelled_element.name)
+                .map_or(Content::Simple, |declaration| declaration.content.clone());
+
+            if element.name != modelled_element.name {
+                layout
+                    .names
+                    .insert(modelled_key.to_string(), element.name.clone());
+            }
+            if modelled_element.children.is_empty()
+                && (element.children.is_empty() || element.is_expanded())
+                && element.is_expanded() != declared.is_container()
+            {
+                layout
+                    .expanded
+                    .insert(modelled_key.to_string(), element.is_expanded());
+            }
+
+            self.element(
+                element,
+                modelled_element,
+                &declared,
+                &format!("{path}/{modelled_key}"),
+            );
+
+            order.push(modelled_key.to_string());
+            anchor = Some(modelled_key.to_string());
+        }
+
+        let modelled_order = modelled_keys
+            .iter()
+            .map(|(_, key)| key)
+            .filter(|key| order.contains(key));
+        if !order.iter().eq(modelled_order) {
+            layout.order = order;
+        }
+
+        if !layout.is_empty() {
+            self.xml_layout.insert(path, layout);
+        }
+    }
+}
+
+/// Returns the name the model writes an element of the file under: its own name, or the one it
+/// is an alias of, i.e. the member of the same choice with the same content (e.g. `walker_note`
+/// for `note`). Returns `None` for elements the model does not write.
+fn modelled_name(
+    element: &Element,
+    declarations: &[Declaration],
+    modelled_children: &HashMap<&str, Vec<(&str, &Element)>>,
+) -> Option<String> {
+    if modelled_children.contains_key(element.name.as_str()) {
+        return Some(element.name.clone());
+    }
+
+    let alias = declarations
+        .iter()
+        .find(|declaration| declaration.name == element.name)?;
+    let mut names = declarations
+        .iter()
+        .filter(|declaration| {
+            declaration.name != alias.name
+                && declaration.rank == alias.rank
+                && declaration.content == alias.content
+                && modelled_children.contains_key(declaration.name.as_str())
+        })
+        .map(|declaration| declaration.name.clone());
+
+    match (names.next(), names.next()) {
+        (Some(name), None) => Some(name),
+        _ => None,
+    }
 }
@@ -0,0 +1,203 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::LazyLock;
+
+use super::xml_tree::{self, Element};
+
+const ERM_XSD: &str = include_str!("../erm.xsd");
+
+pub(crate) static SCHEMA: LazyLock<Schema> =
+    LazyLock::new(|| Schema::parse(ERM_XSD).expect("erm.xsd must be well-formed"));
+
+/// The element structure declared by the crate's `erm.xsd`, a copy of `docs/erm.xsd`.
+#[derive(Debug)]
+pub(crate) struct Schema {
+    root: Declaration,
+    types: HashMap<String, Vec<Declaration>>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Declaration {
+    pub name: String,
+    /// Position of the element in its parent's sequence. Members of one choice share a rank.
+    pub rank: usize,
+    /// Position of the element among all declarations of its parent.
+    pub position: usize,
+    pub content: Content,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Content {
+    Complex(String),
+    Boolean,
+    Simple,
+}
+
+impl Content {
+    /// Returns whether ERFlute writes an element of this content across two lines when it is
+    /// empty, which it does for elements that hold other elements.
+    pub fn is_container(&self) -> bool {
+        matches!(self, Content::Complex(_))
+    }
+}
+
+impl Schema {
+    fn parse(xsd: &str) -> quick_xml::Result<Self> {
+        let schema = xml_tree::parse(xsd)?;
+
+        let groups = schema
+            .elements()
+            .filter(|element| element.name == "xs:group")
+            .filter_map(|group| Some((attribute(group, "name")?, group)))
+            .collect::<HashMap<_, _>>();
+
+        let mut types = HashMap::new();
+        for complex_type in schema
+            .elements()
+            .filter(|element| element.name == "xs:complexType")
+        {
+            if let Some(name) = attribute(complex_type, "name") {
+                collect_type(name, complex_type, &groups, &mut types);
+            }
+        }
+
+        // Named simple types (e.g. `OnActionType`) hold text just like the built-in ones.
+        let complex_types = types.keys().cloned().collect::<HashSet<_>>();
+        for declaration in types.values_mut().flatten() {
+            if matches!(&declaration.content, Content::Complex(name) if !complex_types.contains(name))
+            {
+                declaration.content = Content::Simple;
+            }
+        }
+
+        let root = schema
+            .elements()
+            .find(|element| element.name == "xs:element")
+            .map(|element| declaration(element, "", 0, 0))
+            .ok_or(quick_xml::errors::SyntaxError::UnclosedTag)?;
+
+        Ok(Self { root, types })
+    }
+
+    pub fn root(&self) -> &Declaration {
+        &self.root
+    }
+
+    /// Returns the declarations of the child elements allowed in the given complex type.
+    pub fn children(&self, type_name: &str) -> &[Declaration] {
+        self.types
+            .get(type_name)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+}
+
+fn attribute<'a>(element: &'a Element, name: &str) -> Option<&'a str> {
+    element
+        .attributes
+        .iter()
+        .find(|(key, _)| key == name)
+        .map(|(_, value)| value.as_str())
+}
+
+fn declaration(element: &Element, parent: &str, rank: usize, position: usize) -> Declaration {
+    let name = attribute(element, "name").unwrap_or_default().to_string();
+
+    let content = match attribute(element, "type") {
+        Some("xs:boolean") => Content::Boolean,
+        Some(type_name) if type_name.starts_with("xs:") => Content::Simple,
+        Some(type_name) => Content::Complex(type_name.to_string()),
+        // Anonymous types are registered under the path of the element declaring them.
+        None if element
+            .elements()
+            .any(|child| child.name == "xs:complexType") =>
+        {
+            Content::Complex(format!("{parent}/{name}"))
+        }
+        None => Content::Simple,
+    };
+
+    Declaration {
+        name,
+        rank,
+        position,
+        content,
+    }
+}
+
+fn collect_type(
+    name: &str,
+    complex_type: &Element,
+    groups: &HashMap<&str, &Element>,
+    types: &mut HashMap<String, Vec<Declaration>>,
+) {
+    let mut declarations = Vec::new();
+    let mut rank = 0;
+    collect_particles(
+        name,
+        complex_type,
+        groups,
+        types,
+        &mut declarations,
+        &mut rank,
+    );
+    types.insert(name.to_string(), declarations);
+}
+
+fn collect_particles(
+    type_name: &str,
+    parent: &Element,
+    groups: &HashMap<&str, &Element>,
+    types: &mut HashMap<String, Vec<Declaration>>,
+    declarations: &mut Vec<Declaration>,
+    rank: &mut usize,
+) {
+    for particle in parent.elements() {
+        collect_particle(type_name, particle, groups, types, declarations, rank);
+    }
+}
+
+fn collect_particle(
+    type_name: &str,
+    particle: &Element,
+    groups: &HashMap<&str, &Element>,
+    types: &mut HashMap<String, Vec<Declaration>>,
+    declarations: &mut Vec<Declaration>,
+    rank: &mut usize,
+) {
+    match particle.name.as_str() {
+        "xs:element" => {
+            let declaration = declaration(particle, type_name, *rank, declarations.len());
+            if let Some(inline) = particle
+                .elements()
+                .find(|element| element.name == "xs:complexType")
+                && let Content::Complex(inline_name) = &declaration.content
+            {
+                collect_type(inline_name, inline, groups, types);
+            }
+            declarations.push(declaration);
+            *rank += 1;
+        }
+        "xs:choice" => {
+            let start = *rank;
+            for member in particle.elements() {
+                let mut member_rank = start;
+                collect_particle(
+                    type_name,
+                    member,
+                    groups,
+                    types,
+                    declarations,
+                    &mut member_rank,
+                );
+                *rank = (*rank).max(member_rank);
+            }
+        }
+        "xs:group" => {
+            if let Some(group) = attribute(particle, "ref").and_then(|name| groups.get(name)) {
+                collect_particles(type_name, group, groups, types, declarations, rank);
+            }
+        }
+        "xs:sequence" => collect_particles(type_name, particle, groups, types, declarations, rank),
+        _ => {}
+    }
+}
@@ -0,0 +1,219 @@
+use std::fmt;
+
+use quick_xml::Reader;
+use quick_xml::de::DeError;
+use quick_xml::events::Event;
+use serde::Serialize;
+
+use crate::errors::Error;
+use crate::validation::ValidationError;
+
+/// Elements whose children are the items of a list, indexed by position in validation paths
+/// whatever their name (e.g. `columns.column_group[2]`, or `indexes[0]` for the first `index`).
+const LIST_ELEMENTS: &[&str] = &["column_groups", "columns", "indexes"];
+
+/// A range of the source file, with the line and column where it starts.
+///
+/// Offsets are in bytes, while lines and columns start at 1 and count characters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SourceSpan {
+    pub start: usize,
+    pub end: usize,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl fmt::Display for SourceSpan {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "line {}, column {}", self.line, self.column)
+    }
+}
+
+struct SourceElement {
+    name: String,
+    start: usize,
+    end: usize,
+    children: Vec<SourceElement>,
+}
+
+impl SourceElement {
+    fn child(&self, name: &str) -> Option<&SourceElement> {
+        self.children.iter().find(|child| child.name == name)
+    }
+
+    /// Returns the innermost element containing the offset, or the one that ends just before it
+    /// when only whitespace lies in between.
+    fn innermost(&self, content: &str, offset: usize) -> Option<&SourceElement> {
+        if !(self.start..self.end).contains(&offset) {
+            return None;
+        }
+
+        self.children
+            .iter()
+            .find_map(|child| child.innermost(content, offset))
+            .or_else(|| {
+                self.children.iter().rev().find(|child| {
+                    content
+                        .get(child.end..offset)
+                        .is_some_and(|gap| gap.trim().is_empty())
+                })
+            })
+            .or(Some(self))
+    }
+}
+
+/// Where each element of a file is, so that errors can point at the XML they come from.
+pub(crate) struct SourceMap<'c> {
+    content: &'c str,
+    line_starts: Vec<usize>,
+    root: SourceElement,
+}
+
+impl<'c> SourceMap<'c> {
+    /// Records the position of every element, failing with the location of the first syntax error.
+    pub fn new(content: &'c str) -> Result<Self, Error> {
+        let line_starts: Vec<_> = std::iter::once(0)
+            .chain(content.match_indices('\n').map(|(index, _)| index + 1))
+            .collect();
+        let mut reader = Reader::from_str(content);
+        let mut stack: Vec<SourceElement> = Vec::new();
+        let mut root = None;
+
+        loop {
+            let start = reader.buffer_position() as usize;
+            let event = match reader.read_event() {
+                Ok(event) => event,
+                Err(error) => {
+                    let position = reader.error_position() as usize;
+                    return Err(Error::Parse {
+                        error: DeError::InvalidXml(error),
+                        span: Some(span(content, &line_starts, position, position)),
+                    });
+                }
+            };
+            let end = reader.buffer_position() as usize;
+
+            match event {
+                Event::Start(element) => stack.push(SourceElement {
+                    name: String::from_utf8_lossy(element.name().as_ref()).into_owned(),
+                    start,
+                    end,
+                    children: Vec::new(),
+                }),
+                Event::Empty(element) => append(
+                    &mut stack,
+                    &mut root,
+                    SourceElement {
+                        name: String::from_utf8_lossy(element.name().as_ref()).into_owned(),
+                        start,
+                        end,
+                        children: Vec::new(),
+                    },
+                ),
+                Event::End(_) => {
+                    if let Some(mut element) = stack.pop() {
+                        element.end = end;
+                        append(&mut stack, &mut root, element);
+                    }
+                }
+                Event::Eof => break,
+                _ => {}
+            }
+        }
+
+        let root = root.ok_or_else(|| Error::Parse {
+            error: DeError::InvalidXml(quick_xml::errors::SyntaxError::UnclosedTag.into()),
+            span: Some(span(content, &line_starts, content.len(), content.len())),
+        })?;
+
+        Ok(Self {
+            content,
+            line_starts,
+            root,
+        })
+    }
+
+    /// Returns the span of the innermost element containing the offset.
+    pub fn element_at(&self, offset: usize) -> SourceSpan {
+        match self.root.innermost(self.content, offset) {
+            Some(element) => self.span(element.start, element.end),
+            None => self.span(offset, offset),
+        }
+    }
+
+    /// Returns the span of the element a validation path points to.
+    ///
+    /// Paths are relative to the root element. When part of the path is missing from the file,
+    /// such as an optional element left out, the span of the deepest element found is returned.
+    pub fn resolve(&self, path: &str) -> SourceSpan {
+        let mut element = &self.root;
+
+        for segment in path.split('.') {
+            let (name, index) = match segment.split_once('[') {
+                Some((name, index)) => (name, index.trim_end_matches(']').parse::<usize>().ok()),
+                None => (segment, None),
+            };
+
+            let next = match index {
+                None => element.child(name),
+                Some(index) if LIST_ELEMENTS.contains(&name) => element
+                    .child(name)
+                    .and_then(|list| list.children.get(index)),
+                Some(index) if LIST_ELEMENTS.contains(&element.name.as_str()) => {
+                    element.children.get(index)
+                }
+                Some(index) => element
+                    .children
+                    .iter()
+                    .filter(|child| child.name == name)
+                    .nth(index),
+            };
+
+            match next {
+                Some(next) => element = next,
+                None => break,
+            }
+        }
+
+        self.span(element.start, element.end)
+    }
+
+    /// Sets the span of every validation error from its path.
+    pub fn locate(&self, errors: Vec<ValidationError>) -> Vec<ValidationError> {
+        errors
+            .into_iter()
+            .map(|error| {
+                let span = self.resolve(&error.path);
+                error.with_span(span)
+            })
+            .collect()
+    }
+
+    fn span(&self, start: usize, end: usize) -> SourceSpan {
+        span(self.content, &self.line_starts, start, end)
+    }
+}
+
+fn span(content: &str, line_starts: &[usize], start: usize, end: usize) -> SourceSpan {
+    let start = start.min(content.len());
+    let line = line_starts.partition_point(|line_start| *line_start <= start);
+    let line_start = line_starts[line - 1];
+
+    SourceSpan {
+        start,
+        end: end.clamp(start, content.len()),
+        line,
+        column: content
+            .get(line_start..start)
+            .map_or(start - line_start, |text| text.chars().count())
+            + 1,
+    }
+}
+
+fn append(stack: &mut [SourceElement], root: &mut Option<SourceElement>, element: SourceElement) {
+    match stack.last_mut() {
+        Some(parent) => parent.children.push(element),
+        None => *root = Some(element),
+    }
+}
@@ -1,13 +1,47 @@
 pub mod diagram;
+pub mod rules;
+pub mod settings;
 
 pub use erm_macros::Validate;
+use serde::Serialize;
+use serde::ser::{SerializeStruct, Serializer};
 use std::fmt;
 
+use crate::dtos::diagram::Diagram;
+use crate::lint::{self, NamingConventions};
+use crate::source::SourceSpan;
+use rules::Rule;
+use settings::ValidationSettings;
+
+/// How serious a validation problem is.
+///
+/// Errors keep a diagram from being opened or saved, while warnings are only reported.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Severity {
+    #[default]
+    Error,
+    Warning,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Error => write!(formatter, "error"),
+            Self::Warning => write!(formatter, "warning"),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq)]
 pub struct ValidationError {
+    /// The rule that found the problem.
+    pub rule: &'static Rule,
     pub path: String,
     pub message: String,
     pub targets: Vec<ValidationErrorTarget>,
+    /// Where the element at `path` is in the file, for diagrams read from one.
+    pub span: Option<SourceSpan>,
 }
 
 #[derive(Debug, PartialEq)]
@@ -17,14 +51,29 @@ pub struct ValidationErrorTarget {
 }
 
 impl ValidationError {
-    pub fn new(path: String, message: String) -> Self {
+    pub fn new(rule: &'static Rule, path: String, message: String) -> Self {
         Self {
+            rule,
             path,
             message,
             targets: Vec::new(),
+            span: None,
         }
     }
 
+    /// Returns the code of the rule that found the problem, e.g. `ERM0012`.
+    pub fn code(&self) -> &'static str {
+        self.rule.code
+    }
+
+    pub fn severity(&self) -> Severity {
+        self.rule.severity
+    }
+
+    pub fn is_warning(&self) -> bool {
+        self.severity() == Severity::Warning
+    }
+
     pub fn with_target(mut self, label: impl Into<String>, value: impl fmt::Debug) -> Self {
         self.targets.push(ValidationErrorTarget {
             label: label.into(),
@@ -33,6 +82,19 @@ impl ValidationError {
         self
     }
 
+    /// Returns the value of the target with the given label, e.g. `table name`.
+    pub fn target(&self, label: &str) -> Option<&str> {
+        self.targets
+            .iter()
+            .find(|target| target.label == label)
+            .map(|target| format_target_value(&target.value))
+    }
+
+    pub fn with_span(mut self, span: SourceSpan) -> Self {
+        self.span = Some(span);
+        self
+    }
+
     pub fn prepend_path(mut self, segment: impl AsRef<str>) -> Self {
         if self.path.starts_with('[') {
             self.path = format!("{}{}", segment.as_ref(), self.path);
@@ -45,7 +107,12 @@ impl ValidationError {
 
 impl fmt::Display for ValidationError {
     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(formatter, "Validation error\n\n{}", self.message)?;
+        write!(
+            formatter,
+            "Validation {}\n\n{}",
+            self.severity(),
+            self.message
+        )?;
 
         if !self.targets.is_empty() {
             write!(formatter, "\n\nTarget:")?;
@@ -60,7 +127,15 @@ impl fmt::Display for ValidationError {
             }
         }
 
-        write!(formatter, "\n\nTechnical details:\n- path: {}", self.path)?;
+        write!(
+            formatter,
+            "\n\nTechnical details:\n- rule: {}\n- path: {}",
+            self.code(),
+            self.path
+        )?;
+        if let Some(span) = self.span {
+            write!(formatter, "\n- location: {span}")?;
+        }
 
         Ok(())
     }
@@ -68,37 +143,86 @@ impl fmt::Display for ValidationError {
 
 impl std::error::Error for ValidationError {}
 
-fn format_target_value(value: &str) -> String {
+/// Serializes the error for the frontend, in the same shape as [`crate::errors::Error`].
+impl Serialize for ValidationError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("ValidationError", 7)?;
+        state.serialize_field("code", self.code())?;
+        state.serialize_field("kind", "validation")?;
+        state.serialize_field("severity", &self.severity())?;
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field("message", &self.message)?;
+        state.serialize_field("targets", &self.targets)?;
+        state.serialize_field("span", &self.span)?;
+        state.end()
+    }
+}
+
+/// Serializes the target with its value as displayed, so that names can be matched directly.
+impl Serialize for ValidationErrorTarget {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("ValidationErrorTarget", 2)?;
+        state.serialize_field("label", &self.label)?;
+        state.serialize_field("value", &format_target_value(&self.value))?;
+        state.end()
+    }
+}
+
+fn format_target_value(value: &str) -> &str {
     value
         .strip_prefix('"')
         .and_then(|value| value.strip_suffix('"'))
         .unwrap_or(value)
-        .to_string()
 }
 
-pub trait Validate {
-    fn validate(&self) -> Result<(), ValidationError>;
+/// A list of validation errors, reported together so that every problem can be fixed at once.
+#[derive(Debug, PartialEq)]
+pub struct ValidationErrors {
+    pub errors: Vec<ValidationError>,
 }
 
-impl<T: Validate> Validate for Option<T> {
-    fn validate(&self) -> Result<(), ValidationError> {
-        if let Some(value) = self {
-            value.validate()?;
+impl fmt::Display for ValidationErrors {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let [error] = self.errors.as_slice() {
+            return write!(formatter, "{error}");
+        }
+
+        write!(formatter, "{} validation errors", self.errors.len())?;
+        for (index, error) in self.errors.iter().enumerate() {
+            write!(formatter, "\n\n{}. {error}", index + 1)?;
         }
 
         Ok(())
     }
 }
 
-impl<T: Validate> Validate for Vec<T> {
-    fn validate(&self) -> Result<(), ValidationError> {
-        for (index, value) in self.iter().enumerate() {
-            value
-                .validate()
-                .map_err(|error| error.prepend_path(format!("[{index}]")))?;
+impl std::error::Error for ValidationErrors {}
+
+pub trait Validate {
+    /// Returns every validation error of the value, in the order the rules are run.
+    fn validate_all(&self) -> Vec<ValidationError>;
+}
+
+impl<T: Validate> Validate for Option<T> {
+    fn validate_all(&self) -> Vec<ValidationError> {
+        match self {
+            Some(value) => value.validate_all(),
+            None => Vec::new(),
         }
+    }
+}
 
-        Ok(())
+impl<T: Validate> Validate for Vec<T> {
+    fn validate_all(&self) -> Vec<ValidationError> {
+        self.iter()
+            .enumerate()
+            .flat_map(|(index, value)| {
+                value
+                    .validate_all()
+                    .into_iter()
+                    .map(move |error| error.prepend_path(format!("[{index}]")))
+            })
+            .collect()
     }
 }
 
@@ -106,8 +230,8 @@ macro_rules! impl_noop_validate {
     ($($ty:ty),+ $(,)?) => {
         $(
             impl Validate for $ty {
-                fn validate(&self) -> Result<(), ValidationError> {
-                    Ok(())
+                fn validate_all(&self) -> Vec<ValidationError> {
+                    Vec::new()
                 }
             }
         )+
@@ -130,11 +254,38 @@ impl_noop_validate!(
     f32,
     f64,
     crate::column_type::ColumnType,
+    crate::entities::diagram::unknown_elements::UnknownElements,
+    crate::entities::diagram::xml_layout::XmlLayout,
     crate::entities::diagram::diagram_walkers::tables::connections::ChildCardinality,
     crate::entities::diagram::diagram_walkers::tables::connections::ParentCardinality,
     crate::entities::diagram::diagram_walkers::tables::connections::OnAction
 );
 
-pub fn validate<T: Validate>(value: &T) -> Result<(), ValidationError> {
-    value.validate()
+/// Fails with every error of the value, or returns its warnings when there is no error.
+pub fn validate<T: Validate>(value: &T) -> Result<Vec<ValidationError>, ValidationErrors> {
+    partition(value.validate_all())
+}
+
+/// Validates a diagram and lints its names, with the rules its settings and the validation
+/// settings leave on.
+pub fn validate_diagram(
+    diagram: &Diagram,
+    settings: &ValidationSettings,
+) -> Result<Vec<ValidationError>, ValidationErrors> {
+    let mut errors = diagram.validate_all();
+    errors.extend(lint::lint(
+        diagram,
+        &NamingConventions::new(&diagram.diagram_settings),
+    ));
+    partition(settings.filter(errors, &diagram.diagram_settings))
+}
+
+fn partition(errors: Vec<ValidationError>) -> Result<Vec<ValidationError>, ValidationErrors> {
+    let (warnings, errors): (Vec<_>, Vec<_>) =
+        errors.into_iter().partition(ValidationError::is_warning);
+    if errors.is_empty() {
+        Ok(warnings)
+    } else {
+        Err(ValidationErrors { errors })
+    }
 }
@@ -5,7 +5,7 @@ use std::collections::{HashMap, HashSet};
 use crate::column_type::ColumnType;
 use crate::dtos::diagram::Diagram;
 use crate::dtos::diagram::diagram_walkers::tables::columns::ColumnItem;
-use crate::validation::ValidationError;
+use crate::validation::{ValidationError, rules};
 
 pub(crate) fn validate_column_length_and_decimal_values(
     column_type: Option<ColumnType>,
@@ -15,22 +15,24 @@ pub(crate) fn validate_column_length_and_decimal_values(
     decimal_path: String,
     targets: &[(&str, &str)],
 ) -> Result<(), ValidationError> {
-    if let (Some(length), Some(decimal)) = (length, decimal) {
-        if decimal > length {
-            return Err(with_targets(
-                ValidationError::new(
-                    decimal_path,
-                    format!("decimal must be less than or equal to length: {decimal} > {length}"),
-                ),
-                targets,
-            ));
-        }
+    if let (Some(length), Some(decimal)) = (length, decimal)
+        && decimal > length
+    {
+        return Err(with_targets(
+            ValidationError::new(
+                &rules::DECIMAL_EXCEEDS_LENGTH,
+                decimal_path,
+                format!("decimal must be less than or equal to length: {decimal} > {length}"),
+            ),
+            targets,
+        ));
     }
 
     let Some(column_type) = column_type else {
         if length.is_some() {
             return Err(with_targets(
                 ValidationError::new(
+                    &rules::LENGTH_WITHOUT_COLUMN_TYPE,
                     length_path,
                     "length requires a column type that supports length".to_string(),
                 ),
@@ -40,6 +42,7 @@ pub(crate) fn validate_column_length_and_decimal_values(
         if decimal.is_some() {
             return Err(with_targets(
                 ValidationError::new(
+                    &rules::DECIMAL_WITHOUT_COLUMN_TYPE,
                     decimal_path,
                     "decimal requires a column type that supports decimal".to_string(),
                 ),
@@ -52,6 +55,7 @@ pub(crate) fn validate_column_length_and_decimal_values(
     if length.is_some() && !column_type.supports_length() {
         return Err(with_targets(
             ValidationError::new(
+                &rules::LENGTH_NOT_SUPPORTED,
                 length_path,
                 format!("column type does not support length: {column_type}"),
             ),
@@ -62,6 +66,7 @@ pub(crate) fn validate_column_length_and_decimal_values(
     if decimal.is_some() && !column_type.supports_decimal() {
         return Err(with_targets(
             ValidationError::new(
+                &rules::DECIMAL_NOT_SUPPORTED,
                 decimal_path,
                 format!("column type does not support decimal: {column_type}"),
             ),
@@ -80,13 +85,9 @@ fn with_targets(mut error: ValidationError, targets: &[(&str, &str)]) -> Validat
     error
 }
 
-pub fn validate_column_group_references(diagram: &Diagram) -> Result<(), ValidationError> {
+pub fn validate_column_group_references(diagram: &Diagram) -> Vec<ValidationError> {
     let Some(diagram_walkers) = &diagram.diagram_walkers else {
-        return Ok(());
-    };
-
-    let Some(tables) = &diagram_walkers.tables else {
-        return Ok(());
+        return Vec::new();
     };
 
     let column_group_names = diagram
@@ -100,8 +101,35 @@ pub fn validate_column_group_references(diagram: &Diagram) -> Result<(), Validat
         })
         .unwrap_or_default();
 
-    for (table_index, table) in tables.iter().enumerate() {
-        let Some(items) = &table.columns.items else {
+    let tables = diagram_walkers
+        .tables
+        .iter()
+        .flatten()
+        .enumerate()
+        .map(|(table_index, table)| {
+            (
+                format!("table[{table_index}]"),
+                ("table name", table.physical_name.as_str()),
+                &table.columns,
+            )
+        });
+    let views = diagram_walkers
+        .views
+        .iter()
+        .flatten()
+        .enumerate()
+        .map(|(view_index, view)| {
+            (
+                format!("view[{view_index}]"),
+                ("view name", view.physical_name.as_str()),
+                &view.columns,
+            )
+        });
+
+    let mut errors = Vec::new();
+
+    for (segment, (label, name), columns) in tables.chain(views) {
+        let Some(items) = &columns.items else {
             continue;
         };
 
@@ -111,50 +139,57 @@ pub fn validate_column_group_references(diagram: &Diagram) -> Result<(), Validat
             };
 
             if !column_group_names.contains(column_group_name.as_str()) {
-                return Err(ValidationError::new(
-                    format!(
-                        "diagram_walkers.table[{table_index}].columns.column_group[{item_index}]"
-                    ),
-                    format!("unknown column group: {column_group_name}"),
-                )
-                .with_target("table name", table.physical_name.as_str()));
+                errors.push(
+                    ValidationError::new(
+                        &rules::UNKNOWN_COLUMN_GROUP,
+                        format!("diagram_walkers.{segment}.columns.column_group[{item_index}]"),
+                        format!("unknown column group: {column_group_name}"),
+                    )
+                    .with_target(label, name),
+                );
             }
         }
     }
 
-    Ok(())
+    errors
 }
 
-pub fn validate_duplicate_column_group_names(diagram: &Diagram) -> Result<(), ValidationError> {
+pub fn validate_duplicate_column_group_names(diagram: &Diagram) -> Vec<ValidationError> {
     let Some(column_groups) = &diagram.column_groups else {
-        return Ok(());
+        return Vec::new();
     };
 
     let mut group_names = HashMap::new();
+    let mut errors = Vec::new();
 
     for (group_index, group) in column_groups.iter().enumerate() {
         if group_names
             .insert(group.column_group_name.as_str(), group)
             .is_some()
         {
-            return Err(ValidationError::new(
-                format!("column_groups[{group_index}].column_group_name"),
-                format!("duplicate column group name: {}", group.column_group_name),
-            )
-            .with_target("column group name", group.column_group_name.as_str()));
+            errors.push(
+                ValidationError::new(
+                    &rules::DUPLICATE_COLUMN_GROUP_NAME,
+                    format!("column_groups[{group_index}].column_group_name"),
+                    format!("duplicate column group name: {}", group.column_group_name),
+                )
+                .with_target("column group name", group.column_group_name.as_str()),
+            );
         }
     }
 
-    Ok(())
+    errors
 }
 
 pub fn validate_duplicate_column_group_column_physical_names(
     diagram: &Diagram,
-) -> Result<(), ValidationError> {
+) -> Vec<ValidationError> {
     let Some(column_groups) = &diagram.column_groups else {
-        return Ok(());
+        return Vec::new();
     };
 
+    let mut errors = Vec::new();
+
     for (group_index, group) in column_groups.iter().enumerate() {
         let Some(normal_columns) = &group.columns.normal_columns else {
             continue;
@@ -167,38 +202,40 @@ pub fn validate_duplicate_column_group_column_physical_names(
                 .insert(column.physical_name.as_str(), column)
                 .is_some()
             {
-                return Err(ValidationError::new(
-                    format!(
-                        "column_groups[{group_index}].columns.normal_column[{column_index}].physical_name"
-                    ),
-                    format!(
-                        "duplicate column group column physical_name: {}",
-                        column.physical_name
-                    ),
-                )
-                .with_target("column group name", group.column_group_name.as_str())
-                .with_target("column name", column.physical_name.as_str()));
+                errors.push(
+                    ValidationError::new(&rules::DUPLICATE_COLUMN_GROUP_COLUMN_PHYSICAL_NAME,
+                        format!(
+                            "column_groups[{group_index}].columns.normal_column[{column_index}].physical_name"
+                        ),
+                        format!(
+                            "duplicate column group column physical_name: {}",
+                            column.physical_name
+                        ),
+                    )
+                    .with_target("column group name", group.column_group_name.as_str())
+                    .with_target("column name", column.physical_name.as_str()),
+                );
             }
         }
     }
 
-    Ok(())
+    errors
 }
 
-pub fn validate_column_group_column_length_and_decimal(
-    diagram: &Diagram,
-) -> Result<(), ValidationError> {
+pub fn validate_column_group_column_length_and_decimal(diagram: &Diagram) -> Vec<ValidationError> {
     let Some(column_groups) = &diagram.column_groups else {
-        return Ok(());
+        return Vec::new();
     };
 
+    let mut errors = Vec::new();
+
     for (group_index, group) in column_groups.iter().enumerate() {
         let Some(normal_columns) = &group.columns.normal_columns else {
             continue;
         };
 
         for (column_index, column) in normal_columns.iter().enumerate() {
-            validate_column_length_and_decimal_values(
+            if let Err(error) = validate_column_length_and_decimal_values(
                 Some(column.column_type),
                 column.length,
                 column.decimal,
@@ -212,9 +249,44 @@ pub fn validate_column_group_column_length_and_decimal(
                     ("column group name", group.column_group_name.as_str()),
                     ("column name", column.physical_name.as_str()),
                 ],
-            )?;
+            ) {
+                errors.push(error);
+            }
         }
     }
 
-    Ok(())
+    errors
+}
+
+pub fn validate_virtual_table_references(diagram: &Diagram) -> Vec<ValidationError> {
+    let table_ids = diagram
+        .diagram_walkers
+        .iter()
+        .flat_map(|walkers| walkers.tables.iter().flatten())
+        .map(|table| format!("table.{}", table.physical_name))
+        .collect::<HashSet<_>>();
+
+    let mut errors = Vec::new();
+
+    for (diagram_index, virtual_diagram) in diagram.virtual_diagrams.iter().flatten().enumerate() {
+        for (table_index, virtual_table) in virtual_diagram.tables.iter().flatten().enumerate() {
+            if table_ids.contains(&virtual_table.table_id) {
+                continue;
+            }
+
+            let error = ValidationError::new(
+                &rules::UNKNOWN_VIRTUAL_TABLE,
+                format!(
+                    "vdiagrams.vdiagram[{diagram_index}].vtables.vtable[{table_index}].table_id"
+                ),
+                format!("unknown virtual table: {}", virtual_table.table_id),
+            );
+            errors.push(match &virtual_diagram.vdiagram_name {
+                Some(name) => error.with_target("virtual diagram name", name.as_str()),
+                None => error,
+            });
+        }
+    }
+
+    errors
 }
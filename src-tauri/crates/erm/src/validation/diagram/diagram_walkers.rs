@@ -1,47 +1,89 @@
 pub mod tables;
+pub mod views;
 
 use std::collections::{HashMap, HashSet};
 
 use crate::dtos::diagram::diagram_walkers::DiagramWalkers;
+use crate::dtos::diagram::diagram_walkers::images::ImageFormat;
 use crate::dtos::diagram::diagram_walkers::tables::Table;
 use crate::dtos::diagram::diagram_walkers::tables::columns::{ColumnItem, NormalColumn};
-use crate::validation::ValidationError;
+use crate::dtos::diagram::diagram_walkers::tables::connections::Relationship;
+use crate::dtos::diagram::diagram_walkers::views::View;
+use crate::validation::rules::Rule;
+use crate::validation::{ValidationError, rules};
 
 use tables::normal_column_names;
 
 pub fn validate_duplicate_table_physical_names(
     diagram_walkers: &DiagramWalkers,
-) -> Result<(), ValidationError> {
+) -> Vec<ValidationError> {
     let Some(tables) = &diagram_walkers.tables else {
-        return Ok(());
+        return Vec::new();
     };
 
     let mut table_names = HashMap::new();
+    let mut errors = Vec::new();
 
     for (table_index, table) in tables.iter().enumerate() {
         if table_names
             .insert(table.physical_name.as_str(), table)
             .is_some()
         {
-            return Err(ValidationError::new(
-                format!("table[{table_index}].physical_name"),
-                format!("duplicate table physical_name: {}", table.physical_name),
-            )
-            .with_target("table name", table.physical_name.as_str()));
+            errors.push(
+                ValidationError::new(
+                    &rules::DUPLICATE_TABLE_PHYSICAL_NAME,
+                    format!("table[{table_index}].physical_name"),
+                    format!("duplicate table physical_name: {}", table.physical_name),
+                )
+                .with_target("table name", table.physical_name.as_str()),
+            );
         }
     }
 
-    Ok(())
+    errors
+}
+
+/// Views share their namespace with tables, so a view may not take the name of a table either.
+pub fn validate_duplicate_view_physical_names(
+    diagram_walkers: &DiagramWalkers,
+) -> Vec<ValidationError> {
+    let Some(views) = &diagram_walkers.views else {
+        return Vec::new();
+    };
+
+    let mut names = diagram_walkers
+        .tables
+        .iter()
+        .flatten()
+        .map(|table| table.physical_name.as_str())
+        .collect::<HashSet<_>>();
+    let mut errors = Vec::new();
+
+    for (view_index, view) in views.iter().enumerate() {
+        if !names.insert(view.physical_name.as_str()) {
+            errors.push(
+                ValidationError::new(
+                    &rules::DUPLICATE_VIEW_PHYSICAL_NAME,
+                    format!("view[{view_index}].physical_name"),
+                    format!("duplicate view physical_name: {}", view.physical_name),
+                )
+                .with_target("view name", view.physical_name.as_str()),
+            );
+        }
+    }
+
+    errors
 }
 
 pub fn validate_duplicate_relationship_names(
     diagram_walkers: &DiagramWalkers,
-) -> Result<(), ValidationError> {
+) -> Vec<ValidationError> {
     let Some(tables) = &diagram_walkers.tables else {
-        return Ok(());
+        return Vec::new();
     };
 
     let mut relationship_names = HashMap::new();
+    let mut errors = Vec::new();
 
     for (table_index, table) in tables.iter().enumerate() {
         let Some(relationships) = &table.connections.relationships else {
@@ -53,26 +95,26 @@ pub fn validate_duplicate_relationship_names(
                 .insert(relationship.name.as_str(), relationship)
                 .is_some()
             {
-                return Err(ValidationError::new(
-                    format!(
-                        "table[{table_index}].connections.relationship[{relationship_index}].name"
-                    ),
-                    format!("duplicate relationship name: {}", relationship.name),
-                )
-                .with_target("table name", table.physical_name.as_str())
-                .with_target("relationship name", relationship.name.as_str()));
+                errors.push(
+                    ValidationError::new(&rules::DUPLICATE_RELATIONSHIP_NAME,
+                        format!(
+                            "table[{table_index}].connections.relationship[{relationship_index}].name"
+                        ),
+                        format!("duplicate relationship name: {}", relationship.name),
+                    )
+                    .with_target("table name", table.physical_name.as_str())
+                    .with_target("relationship name", relationship.name.as_str()),
+                );
             }
         }
     }
 
-    Ok(())
+    errors
 }
 
-pub fn validate_cross_table_references(
-    diagram_walkers: &DiagramWalkers,
-) -> Result<(), ValidationError> {
+pub fn validate_cross_table_references(diagram_walkers: &DiagramWalkers) -> Vec<ValidationError> {
     let Some(tables) = &diagram_walkers.tables else {
-        return Ok(());
+        return Vec::new();
     };
 
     let table_names = tables
@@ -85,6 +127,8 @@ pub fn validate_cross_table_references(
         .map(|relationship| relationship.name.as_str())
         .collect::<HashSet<_>>();
 
+    let mut errors = Vec::new();
+
     for (table_index, table) in tables.iter().enumerate() {
         let Some(items) = &table.columns.items else {
             continue;
@@ -95,209 +139,498 @@ pub fn validate_cross_table_references(
                 continue;
             };
 
-            let relationship = if let Some(relationship_name) = &column.relationship {
-                if !relationship_names.contains(relationship_name.as_str()) {
-                    return Err(ValidationError::new(
-                        format!(
-                            "table[{table_index}].columns.normal_column[{item_index}].relationship"
-                        ),
-                        format!("unknown relationship: {relationship_name}"),
-                    )
-                    .with_target("table name", table.physical_name.as_str())
-                    .with_target("column name", column.physical_name.as_str()));
-                }
+            if let Err(error) = validate_column_references(
+                tables,
+                &table_names,
+                &relationship_names,
+                table_index,
+                table,
+                item_index,
+                column,
+            ) {
+                errors.push(error);
+            }
+        }
+    }
 
-                find_relationship(tables, relationship_name)
-            } else {
-                None
-            };
+    for (table_index, table) in tables.iter().enumerate() {
+        let Some(relationships) = &table.connections.relationships else {
+            continue;
+        };
 
-            if let Some(referred_column) = &column.referred_column {
-                let Some((referred_table_name, referred_column_name)) =
-                    column_reference_names(referred_column)
-                else {
-                    return Err(ValidationError::new(
-                        format!(
-                            "table[{table_index}].columns.normal_column[{item_index}].referred_column"
-                        ),
-                        format!("invalid referred_column: {referred_column}"),
-                    )
-                    .with_target("table name", table.physical_name.as_str())
-                    .with_target("column name", column.physical_name.as_str()));
-                };
+        for (relationship_index, relationship) in relationships.iter().enumerate() {
+            if let Err(error) = validate_relationship_references(
+                tables,
+                table_index,
+                table,
+                relationship_index,
+                relationship,
+            ) {
+                errors.push(error);
+            }
+        }
+    }
 
-                let Some(referred_table) = find_table(tables, referred_table_name) else {
-                    return Err(ValidationError::new(
-                        format!(
-                            "table[{table_index}].columns.normal_column[{item_index}].referred_column"
-                        ),
-                        format!("unknown referred column table: {referred_column}"),
-                    )
-                    .with_target("table name", table.physical_name.as_str())
-                    .with_target("column name", column.physical_name.as_str()));
-                };
+    errors
+}
 
-                let referred_column_names = normal_column_names(referred_table);
+pub fn validate_view_column_references(diagram_walkers: &DiagramWalkers) -> Vec<ValidationError> {
+    let Some(views) = &diagram_walkers.views else {
+        return Vec::new();
+    };
+    let tables = diagram_walkers.tables.as_deref().unwrap_or_default();
 
-                if !referred_column_names.contains(referred_column_name) {
-                    return Err(ValidationError::new(
-                        format!(
-                            "table[{table_index}].columns.normal_column[{item_index}].referred_column"
-                        ),
-                        format!("unknown referred column: {referred_column}"),
-                    )
-                    .with_target("table name", table.physical_name.as_str())
-                    .with_target("column name", column.physical_name.as_str()));
-                }
+    let relationship_names = tables
+        .iter()
+        .map(|table| &table.connections)
+        .chain(views.iter().filter_map(|view| view.connections.as_ref()))
+        .flat_map(|connections| connections.relationships.iter().flatten())
+        .map(|relationship| relationship.name.as_str())
+        .collect::<HashSet<_>>();
+
+    let mut errors = Vec::new();
+
+    for (view_index, view) in views.iter().enumerate() {
+        let Some(items) = &view.columns.items else {
+            continue;
+        };
+
+        for (item_index, item) in items.iter().enumerate() {
+            let ColumnItem::Normal(column) = item else {
+                continue;
+            };
+
+            if let Err(error) = validate_view_column_reference(
+                tables,
+                &relationship_names,
+                view_index,
+                view,
+                item_index,
+                column,
+            ) {
+                errors.push(error);
             }
+        }
+    }
+
+    errors
+}
 
-            if let Some(relationship) = relationship {
-                // Invalid relationship sources are reported on the relationship itself below.
-                let source_table_exists = table_reference_name(&relationship.source)
-                    .is_some_and(|source_table_name| table_names.contains(source_table_name));
-
-                if source_table_exists {
-                    validate_relationship_column_source(
-                        table.physical_name.as_str(),
-                        table_index,
-                        item_index,
-                        column,
-                        relationship.source.as_str(),
-                    )?;
+/// Checks the references of a view column, stopping at its first problem.
+///
+/// View columns are not foreign keys, so unlike table columns they are not held to the source of
+/// their relationship.
+fn validate_view_column_reference(
+    tables: &[Table],
+    relationship_names: &HashSet<&str>,
+    view_index: usize,
+    view: &View,
+    item_index: usize,
+    column: &NormalColumn,
+) -> Result<(), ValidationError> {
+    let error = |rule: &'static Rule, field: &str, message: String| {
+        ValidationError::new(
+            rule,
+            format!("view[{view_index}].columns.normal_column[{item_index}].{field}"),
+            message,
+        )
+        .with_target("view name", view.physical_name.as_str())
+        .with_target("column name", column.physical_name.as_str())
+    };
+
+    if let Some(relationship_name) = &column.relationship
+        && !relationship_names.contains(relationship_name.as_str())
+    {
+        return Err(error(
+            &rules::UNKNOWN_RELATIONSHIP,
+            "relationship",
+            format!("unknown relationship: {relationship_name}"),
+        ));
+    }
+
+    let Some(referred_column) = &column.referred_column else {
+        return Ok(());
+    };
+
+    let Some((referred_table_name, referred_column_name)) = column_reference_names(referred_column)
+    else {
+        return Err(error(
+            &rules::INVALID_REFERRED_COLUMN,
+            "referred_column",
+            format!("invalid referred_column: {referred_column}"),
+        ));
+    };
+
+    let Some(referred_table) = find_table(tables, referred_table_name) else {
+        return Err(error(
+            &rules::UNKNOWN_REFERRED_COLUMN_TABLE,
+            "referred_column",
+            format!("unknown referred column table: {referred_column}"),
+        ));
+    };
+
+    if !normal_column_names(referred_table).contains(referred_column_name) {
+        return Err(error(
+            &rules::UNKNOWN_REFERRED_COLUMN,
+            "referred_column",
+            format!("unknown referred column: {referred_column}"),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Notes are usually linked to tables, but a comment connection may join any two walkers that
+/// can be referred to: `table.<name>`, `view.<name>` or the `id` of a note.
+pub fn validate_comment_connections(diagram_walkers: &DiagramWalkers) -> Vec<ValidationError> {
+    let tables = diagram_walkers.tables.iter().flatten();
+    let views = diagram_walkers.views.iter().flatten();
+    let notes = diagram_walkers.notes.iter().flatten();
+
+    let walker_references = walker_references(diagram_walkers);
+
+    let owners = tables
+        .enumerate()
+        .map(|(index, table)| {
+            (
+                format!("table[{index}]"),
+                Some(("table name", table.physical_name.as_str())),
+                Some(&table.connections),
+            )
+        })
+        .chain(views.enumerate().map(|(index, view)| {
+            (
+                format!("view[{index}]"),
+                Some(("view name", view.physical_name.as_str())),
+                view.connections.as_ref(),
+            )
+        }))
+        .chain(notes.enumerate().map(|(index, note)| {
+            (
+                format!("walker_note[{index}]"),
+                note.id.as_deref().map(|id| ("note id", id)),
+                note.connections.as_ref(),
+            )
+        }));
+
+    let mut errors = Vec::new();
+
+    for (segment, owner, connections) in owners {
+        let comment_connections = connections
+            .and_then(|connections| connections.comment_connections.as_ref())
+            .into_iter()
+            .flatten();
+
+        for (connection_index, connection) in comment_connections.enumerate() {
+            for (field, reference) in [
+                ("source", &connection.source),
+                ("target", &connection.target),
+            ] {
+                if walker_references.contains(reference) {
+                    continue;
                 }
+
+                let error = ValidationError::new(
+                    &rules::UNKNOWN_COMMENT_CONNECTION_WALKER,
+                    format!("{segment}.connections.comment_connection[{connection_index}].{field}"),
+                    format!("unknown comment connection {field}: {reference}"),
+                );
+                errors.push(match owner {
+                    Some((label, name)) => error.with_target(label, name),
+                    None => error,
+                });
             }
         }
     }
 
-    for (table_index, table) in tables.iter().enumerate() {
-        let Some(relationships) = &table.connections.relationships else {
-            continue;
-        };
+    errors
+}
 
-        for (relationship_index, relationship) in relationships.iter().enumerate() {
-            let Some(source_table_name) = table_reference_name(&relationship.source) else {
-                return Err(ValidationError::new(
-                    format!(
-                        "table[{table_index}].connections.relationship[{relationship_index}].source"
-                    ),
-                    format!("invalid relationship source: {}", relationship.source),
-                )
-                .with_target("table name", table.physical_name.as_str())
-                .with_target("relationship name", relationship.name.as_str()));
-            };
+pub fn validate_group_members(diagram_walkers: &DiagramWalkers) -> Vec<ValidationError> {
+    let walker_references = walker_references(diagram_walkers);
+    let mut groups_by_member = HashMap::new();
+    let mut errors = Vec::new();
 
-            let Some(target_table_name) = table_reference_name(&relationship.target) else {
-                return Err(ValidationError::new(
+    for (group_index, group) in diagram_walkers.groups.iter().flatten().enumerate() {
+        let group_name = group.walker_group_name.as_deref();
+
+        for (member_index, member) in group.members.iter().flatten().enumerate() {
+            let path = format!("walker_group[{group_index}].diagram_walker[{member_index}]");
+
+            let error = if !walker_references.contains(member) {
+                ValidationError::new(
+                    &rules::UNKNOWN_GROUP_MEMBER,
+                    path,
+                    format!("unknown group member: {member}"),
+                )
+            } else if let Some(first_group) = groups_by_member.insert(member, group_name) {
+                ValidationError::new(
+                    &rules::WALKER_IN_SEVERAL_GROUPS,
+                    path,
                     format!(
-                        "table[{table_index}].connections.relationship[{relationship_index}].target"
+                        "{member} already belongs to group {}",
+                        first_group.unwrap_or("(unnamed)")
                     ),
-                    format!("invalid relationship target: {}", relationship.target),
                 )
-                .with_target("table name", table.physical_name.as_str())
-                .with_target("relationship name", relationship.name.as_str()));
+            } else {
+                continue;
             };
 
-            let Some(source_table) = find_table(tables, source_table_name) else {
-                return Err(ValidationError::new(
-                    format!(
-                        "table[{table_index}].connections.relationship[{relationship_index}].source"
-                    ),
-                    format!("unknown relationship source table: {}", relationship.source),
-                )
-                .with_target("table name", table.physical_name.as_str())
-                .with_target("relationship name", relationship.name.as_str()));
+            errors.push(match group_name {
+                Some(name) => error.with_target("group name", name),
+                None => error,
+            });
+        }
+    }
+
+    errors
+}
+
+pub fn validate_image_data(diagram_walkers: &DiagramWalkers) -> Vec<ValidationError> {
+    let images = diagram_walkers.images.iter().flatten().enumerate();
+
+    images
+        // Data left out of a frontend payload is restored from the file when it is saved.
+        .filter(|(_, image)| !image.data.is_empty())
+        .filter_map(|(index, image)| {
+            let message = match image.bytes() {
+                Ok(bytes) if ImageFormat::sniff(&bytes).is_some() => return None,
+                Ok(_) => "image data is not a PNG, JPEG or GIF image".to_string(),
+                Err(error) => format!("image data is not valid base64: {error}"),
             };
+            let error = ValidationError::new(
+                &rules::CORRUPT_IMAGE_DATA,
+                format!("image[{index}].data"),
+                message,
+            );
+
+            Some(match &image.id {
+                Some(id) => error.with_target("image id", id),
+                None => error,
+            })
+        })
+        .collect()
+}
 
-            if relationship.reference_for_pk {
-                let primary_key_names = primary_key_names(source_table);
+/// Every reference a comment connection or group may use: `table.<name>`, `view.<name>` or a
+/// note's id.
+fn walker_references(diagram_walkers: &DiagramWalkers) -> HashSet<String> {
+    let tables = diagram_walkers.tables.iter().flatten();
+    let views = diagram_walkers.views.iter().flatten();
+    let notes = diagram_walkers.notes.iter().flatten();
 
-                if primary_key_names.is_empty() {
-                    return Err(ValidationError::new(
-                        format!(
-                            "table[{table_index}].connections.relationship[{relationship_index}].reference_for_pk"
-                        ),
-                        format!(
-                            "relationship source table requires a primary key: {}",
-                            relationship.source
-                        ),
-                    )
-                    .with_target("table name", table.physical_name.as_str())
-                    .with_target("relationship name", relationship.name.as_str())
-                    .with_target("source table name", source_table.physical_name.as_str()));
-                }
-            } else if relationship.referred_simple_unique_column.is_none()
-                && relationship.referred_compound_unique_key.is_none()
-            {
-                return Err(ValidationError::new(
-                    format!(
-                        "table[{table_index}].connections.relationship[{relationship_index}].reference_for_pk"
-                    ),
-                    "relationship must reference a simple unique column or compound unique key"
-                        .to_string(),
-                )
-                .with_target("table name", table.physical_name.as_str())
-                .with_target("relationship name", relationship.name.as_str())
-                .with_target("source table name", source_table.physical_name.as_str()));
-            }
+    tables
+        .map(|table| format!("table.{}", table.physical_name))
+        .chain(views.map(|view| format!("view.{}", view.physical_name)))
+        .chain(notes.filter_map(|note| note.id.clone()))
+        .collect()
+}
 
-            if find_table(tables, target_table_name).is_none() {
-                return Err(ValidationError::new(
-                    format!(
-                        "table[{table_index}].connections.relationship[{relationship_index}].target"
-                    ),
-                    format!("unknown relationship target table: {}", relationship.target),
-                )
-                .with_target("table name", table.physical_name.as_str())
-                .with_target("relationship name", relationship.name.as_str()));
-            }
+/// Checks the references of a column, stopping at its first problem.
+fn validate_column_references(
+    tables: &[Table],
+    table_names: &HashSet<&str>,
+    relationship_names: &HashSet<&str>,
+    table_index: usize,
+    table: &Table,
+    item_index: usize,
+    column: &NormalColumn,
+) -> Result<(), ValidationError> {
+    let relationship = if let Some(relationship_name) = &column.relationship {
+        if !relationship_names.contains(relationship_name.as_str()) {
+            return Err(ValidationError::new(
+                &rules::UNKNOWN_RELATIONSHIP,
+                format!("table[{table_index}].columns.normal_column[{item_index}].relationship"),
+                format!("unknown relationship: {relationship_name}"),
+            )
+            .with_target("table name", table.physical_name.as_str())
+            .with_target("column name", column.physical_name.as_str()));
+        }
 
-            if let Some(column_reference) = &relationship.referred_simple_unique_column {
-                let Some((_, referred_column_name)) = column_reference_names(column_reference)
-                else {
-                    return Err(ValidationError::new(
-                        format!(
-                            "table[{table_index}].connections.relationship[{relationship_index}].referred_simple_unique_column"
-                        ),
-                        format!("invalid referred simple unique column: {column_reference}"),
-                    )
-                    .with_target("table name", table.physical_name.as_str())
-                    .with_target("relationship name", relationship.name.as_str()));
-                };
+        find_relationship(tables, relationship_name)
+    } else {
+        None
+    };
 
-                // Traditional ERM files may store the target table name in this reference.
-                // Keep that compatible by validating only the column name for now.
-                // See https://github.com/erflute/erflute-traditional/issues/70.
+    if let Some(referred_column) = &column.referred_column {
+        let Some((referred_table_name, referred_column_name)) =
+            column_reference_names(referred_column)
+        else {
+            return Err(ValidationError::new(
+                &rules::INVALID_REFERRED_COLUMN,
+                format!("table[{table_index}].columns.normal_column[{item_index}].referred_column"),
+                format!("invalid referred_column: {referred_column}"),
+            )
+            .with_target("table name", table.physical_name.as_str())
+            .with_target("column name", column.physical_name.as_str()));
+        };
 
-                let unique_column_names = unique_column_names(source_table);
+        let Some(referred_table) = find_table(tables, referred_table_name) else {
+            return Err(ValidationError::new(
+                &rules::UNKNOWN_REFERRED_COLUMN_TABLE,
+                format!("table[{table_index}].columns.normal_column[{item_index}].referred_column"),
+                format!("unknown referred column table: {referred_column}"),
+            )
+            .with_target("table name", table.physical_name.as_str())
+            .with_target("column name", column.physical_name.as_str()));
+        };
 
-                if !unique_column_names.contains(referred_column_name) {
-                    return Err(ValidationError::new(
-                        format!(
-                            "table[{table_index}].connections.relationship[{relationship_index}].referred_simple_unique_column"
-                        ),
-                        format!("unknown referred simple unique column: {column_reference}"),
-                    )
-                    .with_target("table name", table.physical_name.as_str())
-                    .with_target("relationship name", relationship.name.as_str())
-                    .with_target("source table name", source_table.physical_name.as_str()));
-                }
-            }
+        let referred_column_names = normal_column_names(referred_table);
 
-            if let Some(key_name) = &relationship.referred_compound_unique_key {
-                let compound_unique_key_names = compound_unique_key_names(source_table);
+        if !referred_column_names.contains(referred_column_name) {
+            return Err(ValidationError::new(
+                &rules::UNKNOWN_REFERRED_COLUMN,
+                format!("table[{table_index}].columns.normal_column[{item_index}].referred_column"),
+                format!("unknown referred column: {referred_column}"),
+            )
+            .with_target("table name", table.physical_name.as_str())
+            .with_target("column name", column.physical_name.as_str()));
+        }
+    }
 
-                if !compound_unique_key_names.contains(key_name.as_str()) {
-                    return Err(ValidationError::new(
-                        format!(
-                            "table[{table_index}].connections.relationship[{relationship_index}].referred_compound_unique_key"
-                        ),
-                        format!("unknown referred compound unique key: {key_name}"),
-                    )
-                    .with_target("table name", table.physical_name.as_str())
-                    .with_target("relationship name", relationship.name.as_str())
-                    .with_target("source table name", source_table.physical_name.as_str()));
-                }
-            }
+    if let Some(relationship) = relationship {
+        // Invalid relationship sources are reported on the relationship itself below.
+        let source_table_exists = table_reference_name(&relationship.source)
+            .is_some_and(|source_table_name| table_names.contains(source_table_name));
+
+        if source_table_exists {
+            validate_relationship_column_source(
+                table.physical_name.as_str(),
+                table_index,
+                item_index,
+                column,
+                relationship.source.as_str(),
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks the tables, keys and columns a relationship refers to, stopping at its first problem.
+fn validate_relationship_references(
+    tables: &[Table],
+    table_index: usize,
+    table: &Table,
+    relationship_index: usize,
+    relationship: &Relationship,
+) -> Result<(), ValidationError> {
+    let Some(source_table_name) = table_reference_name(&relationship.source) else {
+        return Err(ValidationError::new(
+            &rules::INVALID_RELATIONSHIP_SOURCE,
+            format!("table[{table_index}].connections.relationship[{relationship_index}].source"),
+            format!("invalid relationship source: {}", relationship.source),
+        )
+        .with_target("table name", table.physical_name.as_str())
+        .with_target("relationship name", relationship.name.as_str()));
+    };
+
+    let Some(target_table_name) = table_reference_name(&relationship.target) else {
+        return Err(ValidationError::new(
+            &rules::INVALID_RELATIONSHIP_TARGET,
+            format!("table[{table_index}].connections.relationship[{relationship_index}].target"),
+            format!("invalid relationship target: {}", relationship.target),
+        )
+        .with_target("table name", table.physical_name.as_str())
+        .with_target("relationship name", relationship.name.as_str()));
+    };
+
+    let Some(source_table) = find_table(tables, source_table_name) else {
+        return Err(ValidationError::new(
+            &rules::UNKNOWN_RELATIONSHIP_SOURCE,
+            format!("table[{table_index}].connections.relationship[{relationship_index}].source"),
+            format!("unknown relationship source table: {}", relationship.source),
+        )
+        .with_target("table name", table.physical_name.as_str())
+        .with_target("relationship name", relationship.name.as_str()));
+    };
+
+    if relationship.reference_for_pk {
+        let primary_key_names = primary_key_names(source_table);
+
+        if primary_key_names.is_empty() {
+            return Err(ValidationError::new(&rules::SOURCE_WITHOUT_PRIMARY_KEY,
+                format!(
+                    "table[{table_index}].connections.relationship[{relationship_index}].reference_for_pk"
+                ),
+                format!(
+                    "relationship source table requires a primary key: {}",
+                    relationship.source
+                ),
+            )
+            .with_target("table name", table.physical_name.as_str())
+            .with_target("relationship name", relationship.name.as_str())
+            .with_target("source table name", source_table.physical_name.as_str()));
+        }
+    } else if relationship.referred_simple_unique_column.is_none()
+        && relationship.referred_compound_unique_key.is_none()
+    {
+        return Err(ValidationError::new(&rules::MISSING_REFERRED_UNIQUE_KEY,
+            format!(
+                "table[{table_index}].connections.relationship[{relationship_index}].reference_for_pk"
+            ),
+            "relationship must reference a simple unique column or compound unique key"
+                .to_string(),
+        )
+        .with_target("table name", table.physical_name.as_str())
+        .with_target("relationship name", relationship.name.as_str())
+        .with_target("source table name", source_table.physical_name.as_str()));
+    }
+
+    if find_table(tables, target_table_name).is_none() {
+        return Err(ValidationError::new(
+            &rules::UNKNOWN_RELATIONSHIP_TARGET,
+            format!("table[{table_index}].connections.relationship[{relationship_index}].target"),
+            format!("unknown relationship target table: {}", relationship.target),
+        )
+        .with_target("table name", table.physical_name.as_str())
+        .with_target("relationship name", relationship.name.as_str()));
+    }
+
+    if let Some(column_reference) = &relationship.referred_simple_unique_column {
+        let Some((_, referred_column_name)) = column_reference_names(column_reference) else {
+            return Err(ValidationError::new(&rules::INVALID_REFERRED_SIMPLE_UNIQUE_COLUMN,
+                format!(
+                    "table[{table_index}].connections.relationship[{relationship_index}].referred_simple_unique_column"
+                ),
+                format!("invalid referred simple unique column: {column_reference}"),
+            )
+            .with_target("table name", table.physical_name.as_str())
+            .with_target("relationship name", relationship.name.as_str()));
+        };
+
+        // Traditional ERM files may store the target table name in this reference.
+        // Keep that compatible by validating only the column name for now.
+        // See https://github.com/erflute/erflute-traditional/issues/70.
+
+        let unique_column_names = unique_column_names(source_table);
+
+        if !unique_column_names.contains(referred_column_name) {
+            return Err(ValidationError::new(&rules::UNKNOWN_REFERRED_SIMPLE_UNIQUE_COLUMN,
+                format!(
+                    "table[{table_index}].connections.relationship[{relationship_index}].referred_simple_unique_column"
+                ),
+                format!("unknown referred simple unique column: {column_reference}"),
+            )
+            .with_target("table name", table.physical_name.as_str())
+            .with_target("relationship name", relationship.name.as_str())
+            .with_target("source table name", source_table.physical_name.as_str()));
+        }
+    }
+
+    if let Some(key_name) = &relationship.referred_compound_unique_key {
+        let compound_unique_key_names = compound_unique_key_names(source_table);
+
+        if !compound_unique_key_names.contains(key_name.as_str()) {
+            return Err(ValidationError::new(&rules::UNKNOWN_REFERRED_COMPOUND_UNIQUE_KEY,
+                format!(
+                    "table[{table_index}].connections.relationship[{relationship_index}].referred_compound_unique_key"
+                ),
+                format!("unknown referred compound unique key: {key_name}"),
+            )
+            .with_target("table name", table.physical_name.as_str())
+            .with_target("relationship name", relationship.name.as_str())
+            .with_target("source table name", source_table.physical_name.as_str()));
         }
     }
 
@@ -356,10 +689,7 @@ fn compound_unique_key_names(table: &Table) -> HashSet<&str> {
         .collect()
 }
 
-fn find_relationship<'a>(
-    tables: &'a [Table],
-    relationship_name: &str,
-) -> Option<&'a crate::dtos::diagram::diagram_walkers::tables::connections::Relationship> {
+fn find_relationship<'a>(tables: &'a [Table], relationship_name: &str) -> Option<&'a Relationship> {
     tables
         .iter()
         .flat_map(|table| table.connections.relationships.iter().flatten())
@@ -381,6 +711,7 @@ fn validate_relationship_column_source(
 ) -> Result<(), ValidationError> {
     let Some(referred_column) = &column.referred_column else {
         return Err(ValidationError::new(
+            &rules::MISSING_REFERRED_COLUMN,
             format!("table[{table_index}].columns.normal_column[{item_index}].referred_column"),
             format!(
                 "relationship column requires referred_column: {}",
@@ -401,6 +732,7 @@ fn validate_relationship_column_source(
 
     if referred_table_name != source_table_name {
         return Err(ValidationError::new(
+            &rules::REFERRED_COLUMN_TABLE_MISMATCH,
             format!("table[{table_index}].columns.normal_column[{item_index}].referred_column"),
             format!("referred_column table must match relationship source: {referred_column}"),
         )
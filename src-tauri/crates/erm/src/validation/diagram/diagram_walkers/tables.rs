@@ -2,15 +2,17 @@ use std::collections::{HashMap, HashSet};
 
 use crate::dtos::diagram::diagram_walkers::tables::Table;
 use crate::dtos::diagram::diagram_walkers::tables::columns::{ColumnItem, NormalColumn};
-use crate::validation::ValidationError;
+use crate::dtos::diagram::diagram_walkers::tables::connections::Relationship;
 use crate::validation::diagram::validate_column_length_and_decimal_values;
+use crate::validation::{ValidationError, rules};
 
-pub fn validate_duplicate_column_physical_names(table: &Table) -> Result<(), ValidationError> {
+pub fn validate_duplicate_column_physical_names(table: &Table) -> Vec<ValidationError> {
     let Some(items) = &table.columns.items else {
-        return Ok(());
+        return Vec::new();
     };
 
     let mut column_names = HashMap::new();
+    let mut errors = Vec::new();
 
     for (item_index, item) in items.iter().enumerate() {
         let ColumnItem::Normal(column) = item else {
@@ -21,247 +23,400 @@ pub fn validate_duplicate_column_physical_names(table: &Table) -> Result<(), Val
             .insert(column.physical_name.as_str(), column)
             .is_some()
         {
-            return Err(ValidationError::new(
-                format!("columns.normal_column[{item_index}].physical_name"),
-                format!("duplicate column physical_name: {}", column.physical_name),
-            )
-            .with_target("table name", table.physical_name.as_str())
-            .with_target("column name", column.physical_name.as_str()));
+            errors.push(
+                ValidationError::new(
+                    &rules::DUPLICATE_COLUMN_PHYSICAL_NAME,
+                    format!("columns.normal_column[{item_index}].physical_name"),
+                    format!("duplicate column physical_name: {}", column.physical_name),
+                )
+                .with_target("table name", table.physical_name.as_str())
+                .with_target("column name", column.physical_name.as_str()),
+            );
         }
     }
 
-    Ok(())
+    errors
 }
 
-pub fn validate_duplicate_index_names(table: &Table) -> Result<(), ValidationError> {
+pub fn validate_duplicate_index_names(table: &Table) -> Vec<ValidationError> {
     let Some(indexes) = &table.indexes else {
-        return Ok(());
+        return Vec::new();
     };
 
     let mut index_names = HashMap::new();
+    let mut errors = Vec::new();
 
     for (index_index, index) in indexes.iter().enumerate() {
         if index_names.insert(index.name.as_str(), index).is_some() {
-            return Err(ValidationError::new(
-                format!("indexes[{index_index}].name"),
-                format!("duplicate index name: {}", index.name),
-            )
-            .with_target("table name", table.physical_name.as_str())
-            .with_target("index name", index.name.as_str()));
+            errors.push(
+                ValidationError::new(
+                    &rules::DUPLICATE_INDEX_NAME,
+                    format!("indexes[{index_index}].name"),
+                    format!("duplicate index name: {}", index.name),
+                )
+                .with_target("table name", table.physical_name.as_str())
+                .with_target("index name", index.name.as_str()),
+            );
         }
     }
 
-    Ok(())
+    errors
 }
 
-pub fn validate_duplicate_compound_unique_key_names(table: &Table) -> Result<(), ValidationError> {
+pub fn validate_duplicate_compound_unique_key_names(table: &Table) -> Vec<ValidationError> {
     let Some(compound_unique_keys) = &table.compound_unique_key_list.compound_unique_keys else {
-        return Ok(());
+        return Vec::new();
     };
 
     let mut key_names = HashMap::new();
+    let mut errors = Vec::new();
 
     for (key_index, key) in compound_unique_keys.iter().enumerate() {
         if key_names.insert(key.name.as_str(), key).is_some() {
-            return Err(ValidationError::new(
-                format!("compound_unique_key_list.compound_unique_key[{key_index}].name"),
-                format!("duplicate compound unique key name: {}", key.name),
-            )
-            .with_target("table name", table.physical_name.as_str())
-            .with_target("compound unique key name", key.name.as_str()));
+            errors.push(
+                ValidationError::new(
+                    &rules::DUPLICATE_COMPOUND_UNIQUE_KEY_NAME,
+                    format!("compound_unique_key_list.compound_unique_key[{key_index}].name"),
+                    format!("duplicate compound unique key name: {}", key.name),
+                )
+                .with_target("table name", table.physical_name.as_str())
+                .with_target("compound unique key name", key.name.as_str()),
+            );
         }
     }
 
-    Ok(())
+    errors
 }
 
-pub fn validate_auto_increment_columns_are_key_columns(
-    table: &Table,
-) -> Result<(), ValidationError> {
+pub fn validate_auto_increment_columns_are_key_columns(table: &Table) -> Vec<ValidationError> {
     let key_column_names = key_column_names(table);
 
-    for (item_index, column) in normal_columns(table) {
-        if column.auto_increment != Some(true) {
-            continue;
-        }
-
-        if column.primary_key == Some(true)
-            || key_column_names.contains(column.physical_name.as_str())
-        {
-            continue;
-        }
-
-        return Err(ValidationError::new(
-            format!("columns.normal_column[{item_index}].auto_increment"),
-            format!(
-                "auto_increment column must be a key column: {}",
-                column.physical_name
-            ),
-        )
-        .with_target("table name", table.physical_name.as_str())
-        .with_target("column name", column.physical_name.as_str()));
-    }
-
-    Ok(())
+    normal_columns(table)
+        .filter(|(_, column)| {
+            column.auto_increment == Some(true)
+                && column.primary_key != Some(true)
+                && !key_column_names.contains(column.physical_name.as_str())
+        })
+        .map(|(item_index, column)| {
+            ValidationError::new(
+                &rules::AUTO_INCREMENT_NOT_KEY,
+                format!("columns.normal_column[{item_index}].auto_increment"),
+                format!(
+                    "auto_increment column must be a key column: {}",
+                    column.physical_name
+                ),
+            )
+            .with_target("table name", table.physical_name.as_str())
+            .with_target("column name", column.physical_name.as_str())
+        })
+        .collect()
 }
 
-pub fn validate_column_length_and_decimal(table: &Table) -> Result<(), ValidationError> {
-    for (item_index, column) in normal_columns(table) {
-        validate_column_length_and_decimal_values(
-            column.column_type,
-            column.length,
-            column.decimal,
-            format!("columns.normal_column[{item_index}].length"),
-            format!("columns.normal_column[{item_index}].decimal"),
-            &[
-                ("table name", table.physical_name.as_str()),
-                ("column name", column.physical_name.as_str()),
-            ],
-        )?;
-    }
-
-    Ok(())
+pub fn validate_column_length_and_decimal(table: &Table) -> Vec<ValidationError> {
+    normal_columns(table)
+        .filter_map(|(item_index, column)| {
+            validate_column_length_and_decimal_values(
+                column.column_type,
+                column.length,
+                column.decimal,
+                format!("columns.normal_column[{item_index}].length"),
+                format!("columns.normal_column[{item_index}].decimal"),
+                &[
+                    ("table name", table.physical_name.as_str()),
+                    ("column name", column.physical_name.as_str()),
+                ],
+            )
+            .err()
+        })
+        .collect()
 }
 
-pub fn validate_index_column_references(table: &Table) -> Result<(), ValidationError> {
+pub fn validate_index_column_references(table: &Table) -> Vec<ValidationError> {
     let Some(indexes) = &table.indexes else {
-        return Ok(());
+        return Vec::new();
     };
 
     let column_names = normal_column_names(table);
+    let mut errors = Vec::new();
 
     for (index_index, index) in indexes.iter().enumerate() {
         for (column_index, column) in index.columns.iter().enumerate() {
             if !column_reference_exists(table, &column_names, &column.column_id) {
-                return Err(ValidationError::new(
-                    format!("indexes[{index_index}].columns[{column_index}].column_id"),
-                    format!("unknown index column_id: {}", column.column_id),
-                )
-                .with_target("table name", table.physical_name.as_str())
-                .with_target("index name", index.name.as_str()));
+                errors.push(
+                    ValidationError::new(
+                        &rules::UNKNOWN_INDEX_COLUMN,
+                        format!("indexes[{index_index}].columns[{column_index}].column_id"),
+                        format!("unknown index column_id: {}", column.column_id),
+                    )
+                    .with_target("table name", table.physical_name.as_str())
+                    .with_target("index name", index.name.as_str()),
+                );
             }
         }
     }
 
-    Ok(())
+    errors
 }
 
-pub fn validate_compound_unique_key_column_references(
-    table: &Table,
-) -> Result<(), ValidationError> {
+pub fn validate_compound_unique_key_column_references(table: &Table) -> Vec<ValidationError> {
     let Some(compound_unique_keys) = &table.compound_unique_key_list.compound_unique_keys else {
-        return Ok(());
+        return Vec::new();
     };
 
     let column_names = normal_column_names(table);
+    let mut errors = Vec::new();
 
     for (key_index, key) in compound_unique_keys.iter().enumerate() {
         for (column_index, column) in key.columns.iter().enumerate() {
             if !column_reference_exists(table, &column_names, &column.column_id) {
-                return Err(ValidationError::new(
-                    format!(
-                        "compound_unique_key_list.compound_unique_key[{key_index}].columns[{column_index}].column_id"
-                    ),
+                errors.push(
+                    ValidationError::new(&rules::UNKNOWN_COMPOUND_UNIQUE_KEY_COLUMN,
+                        format!(
+                            "compound_unique_key_list.compound_unique_key[{key_index}].columns[{column_index}].column_id"
+                        ),
+                        format!(
+                            "unknown compound unique key column_id: {}",
+                            column.column_id
+                        ),
+                    )
+                    .with_target("table name", table.physical_name.as_str())
+                    .with_target("compound unique key name", key.name.as_str()),
+                );
+            }
+        }
+    }
+
+    errors
+}
+
+pub fn validate_local_relationship_consistency(table: &Table) -> Vec<ValidationError> {
+    let mut errors = table
+        .connections
+        .relationships
+        .iter()
+        .flatten()
+        .enumerate()
+        .filter_map(|(relationship_index, relationship)| {
+            validate_local_relationship(table, relationship_index, relationship).err()
+        })
+        .collect::<Vec<_>>();
+
+    for (item_index, column) in normal_columns(table) {
+        let Some(relationship_name) = &column.relationship else {
+            continue;
+        };
+
+        let Some(relationship) = find_relationship(table, relationship_name) else {
+            continue;
+        };
+
+        if !relationship
+            .fk_columns
+            .fk_column
+            .iter()
+            .any(|fk_column| fk_column.fk_column_name == column.physical_name)
+        {
+            errors.push(
+                ValidationError::new(
+                    &rules::RELATIONSHIP_MISSING_FK_COLUMN,
+                    format!("columns.normal_column[{item_index}].relationship"),
                     format!(
-                        "unknown compound unique key column_id: {}",
-                        column.column_id
+                        "relationship does not contain fk column: {} -> {}",
+                        relationship_name, column.physical_name
                     ),
                 )
                 .with_target("table name", table.physical_name.as_str())
-                .with_target("compound unique key name", key.name.as_str()));
-            }
+                .with_target("column name", column.physical_name.as_str())
+                .with_target("relationship name", relationship.name.as_str()),
+            );
         }
     }
 
-    Ok(())
+    errors
 }
 
-pub fn validate_local_relationship_consistency(table: &Table) -> Result<(), ValidationError> {
-    let Some(relationships) = &table.connections.relationships else {
-        return Ok(());
-    };
+pub fn validate_table_has_primary_key(table: &Table) -> Vec<ValidationError> {
+    if normal_columns(table).any(|(_, column)| column.primary_key == Some(true)) {
+        return Vec::new();
+    }
 
-    for (relationship_index, relationship) in relationships.iter().enumerate() {
-        if table_reference_name(&relationship.target)
-            .is_some_and(|target_table_name| target_table_name != table.physical_name)
-        {
-            return Err(ValidationError::new(
-                format!("connections.relationship[{relationship_index}].target"),
-                format!(
-                    "relationship target must match containing table: {}",
-                    relationship.target
-                ),
-            )
-            .with_target("table name", table.physical_name.as_str())
-            .with_target("relationship name", relationship.name.as_str()));
-        }
+    vec![
+        ValidationError::new(
+            &rules::TABLE_WITHOUT_PRIMARY_KEY,
+            "columns".to_string(),
+            format!("table has no primary key: {}", table.physical_name),
+        )
+        .with_target("table name", table.physical_name.as_str()),
+    ]
+}
 
-        if relationship.referred_simple_unique_column.is_some()
-            && relationship.referred_compound_unique_key.is_some()
-        {
-            return Err(ValidationError::new(
-                format!(
-                    "connections.relationship[{relationship_index}].referred_simple_unique_column"
-                ),
-                "referred_simple_unique_column and referred_compound_unique_key cannot both be specified"
-                    .to_string(),
+pub fn validate_foreign_key_columns_are_indexed(table: &Table) -> Vec<ValidationError> {
+    let keys = key_column_lists(table);
+
+    table
+        .connections
+        .relationships
+        .iter()
+        .flatten()
+        .enumerate()
+        .filter(|(_, relationship)| {
+            let fk_column_names = relationship
+                .fk_columns
+                .fk_column
+                .iter()
+                .map(|fk_column| fk_column.fk_column_name.as_str())
+                .collect::<HashSet<_>>();
+
+            !fk_column_names.is_empty()
+                && !keys.iter().any(|key| {
+                    key.len() >= fk_column_names.len()
+                        && key[..fk_column_names.len()]
+                            .iter()
+                            .all(|column_name| fk_column_names.contains(column_name))
+                })
+        })
+        .map(|(relationship_index, relationship)| {
+            ValidationError::new(
+                &rules::UNINDEXED_FOREIGN_KEY,
+                format!("connections.relationship[{relationship_index}].fk_columns"),
+                format!("foreign key columns are not indexed: {}", relationship.name),
             )
             .with_target("table name", table.physical_name.as_str())
-            .with_target("relationship name", relationship.name.as_str()));
-        }
+            .with_target("relationship name", relationship.name.as_str())
+        })
+        .collect()
+}
 
-        for (column_index, fk_column) in relationship.fk_columns.fk_column.iter().enumerate() {
-            let Some((_, column)) = normal_column_by_name(table, &fk_column.fk_column_name) else {
-                return Err(ValidationError::new(
-                    format!(
-                        "connections.relationship[{relationship_index}].fk_columns.fk_column[{column_index}].fk_column_name"
-                    ),
-                    format!(
-                        "unknown relationship fk_column_name: {}",
-                        fk_column.fk_column_name
-                    ),
-                )
-                .with_target("table name", table.physical_name.as_str())
-                .with_target("relationship name", relationship.name.as_str()));
+pub fn validate_compound_unique_key_columns_are_not_null(table: &Table) -> Vec<ValidationError> {
+    let Some(compound_unique_keys) = &table.compound_unique_key_list.compound_unique_keys else {
+        return Vec::new();
+    };
+
+    let mut errors = Vec::new();
+
+    for (key_index, key) in compound_unique_keys.iter().enumerate() {
+        for (column_index, key_column) in key.columns.iter().enumerate() {
+            let Some((_, column)) = column_reference_column_name(table, &key_column.column_id)
+                .and_then(|column_name| normal_column_by_name(table, column_name))
+            else {
+                continue;
             };
 
-            if column.relationship.as_deref() != Some(relationship.name.as_str()) {
-                return Err(ValidationError::new(
-                    format!(
-                        "connections.relationship[{relationship_index}].fk_columns.fk_column[{column_index}].fk_column_name"
-                    ),
-                    format!(
-                        "fk column must reference relationship: {} -> {}",
-                        fk_column.fk_column_name, relationship.name
-                    ),
-                )
-                .with_target("table name", table.physical_name.as_str())
-                .with_target("column name", column.physical_name.as_str())
-                .with_target("relationship name", relationship.name.as_str()));
+            if column.not_null != Some(true) && column.primary_key != Some(true) {
+                errors.push(
+                    ValidationError::new(&rules::NULLABLE_COMPOUND_UNIQUE_KEY_COLUMN,
+                        format!(
+                            "compound_unique_key_list.compound_unique_key[{key_index}].columns[{column_index}].column_id"
+                        ),
+                        format!(
+                            "compound unique key column is nullable: {}",
+                            column.physical_name
+                        ),
+                    )
+                    .with_target("table name", table.physical_name.as_str())
+                    .with_target("compound unique key name", key.name.as_str())
+                    .with_target("column name", column.physical_name.as_str()),
+                );
             }
         }
     }
 
+    errors
+}
+
+pub fn validate_logical_names_are_not_empty(table: &Table) -> Vec<ValidationError> {
+    let mut errors = Vec::new();
+
+    if table.logical_name.trim().is_empty() {
+        errors.push(
+            ValidationError::new(
+                &rules::EMPTY_TABLE_LOGICAL_NAME,
+                "logical_name".to_string(),
+                format!("table logical_name is empty: {}", table.physical_name),
+            )
+            .with_target("table name", table.physical_name.as_str()),
+        );
+    }
+
+    // Foreign key columns show the logical name of the column they refer to.
     for (item_index, column) in normal_columns(table) {
-        let Some(relationship_name) = &column.relationship else {
-            continue;
-        };
+        if column.referred_column.is_none()
+            && column
+                .logical_name
+                .as_deref()
+                .is_none_or(|logical_name| logical_name.trim().is_empty())
+        {
+            errors.push(
+                ValidationError::new(
+                    &rules::EMPTY_COLUMN_LOGICAL_NAME,
+                    format!("columns.normal_column[{item_index}].logical_name"),
+                    format!("column logical_name is empty: {}", column.physical_name),
+                )
+                .with_target("table name", table.physical_name.as_str())
+                .with_target("column name", column.physical_name.as_str()),
+            );
+        }
+    }
 
-        let Some(relationship) = find_relationship(table, relationship_name) else {
-            continue;
+    errors
+}
+
+/// Checks a relationship against its containing table, stopping at its first problem.
+fn validate_local_relationship(
+    table: &Table,
+    relationship_index: usize,
+    relationship: &Relationship,
+) -> Result<(), ValidationError> {
+    if table_reference_name(&relationship.target)
+        .is_some_and(|target_table_name| target_table_name != table.physical_name)
+    {
+        return Err(ValidationError::new(
+            &rules::RELATIONSHIP_TARGET_MISMATCH,
+            format!("connections.relationship[{relationship_index}].target"),
+            format!(
+                "relationship target must match containing table: {}",
+                relationship.target
+            ),
+        )
+        .with_target("table name", table.physical_name.as_str())
+        .with_target("relationship name", relationship.name.as_str()));
+    }
+
+    if relationship.referred_simple_unique_column.is_some()
+        && relationship.referred_compound_unique_key.is_some()
+    {
+        return Err(ValidationError::new(&rules::AMBIGUOUS_REFERRED_UNIQUE_KEY,
+            format!("connections.relationship[{relationship_index}].referred_simple_unique_column"),
+            "referred_simple_unique_column and referred_compound_unique_key cannot both be specified"
+                .to_string(),
+        )
+        .with_target("table name", table.physical_name.as_str())
+        .with_target("relationship name", relationship.name.as_str()));
+    }
+
+    for (column_index, fk_column) in relationship.fk_columns.fk_column.iter().enumerate() {
+        let Some((_, column)) = normal_column_by_name(table, &fk_column.fk_column_name) else {
+            return Err(ValidationError::new(&rules::UNKNOWN_FK_COLUMN,
+                format!(
+                    "connections.relationship[{relationship_index}].fk_columns.fk_column[{column_index}].fk_column_name"
+                ),
+                format!(
+                    "unknown relationship fk_column_name: {}",
+                    fk_column.fk_column_name
+                ),
+            )
+            .with_target("table name", table.physical_name.as_str())
+            .with_target("relationship name", relationship.name.as_str()));
         };
 
-        if !relationship
-            .fk_columns
-            .fk_column
-            .iter()
-            .any(|fk_column| fk_column.fk_column_name == column.physical_name)
-        {
-            return Err(ValidationError::new(
-                format!("columns.normal_column[{item_index}].relationship"),
+        if column.relationship.as_deref() != Some(relationship.name.as_str()) {
+            return Err(ValidationError::new(&rules::FK_COLUMN_RELATIONSHIP_MISMATCH,
+                format!(
+                    "connections.relationship[{relationship_index}].fk_columns.fk_column[{column_index}].fk_column_name"
+                ),
                 format!(
-                    "relationship does not contain fk column: {} -> {}",
-                    relationship_name, column.physical_name
+                    "fk column must reference relationship: {} -> {}",
+                    fk_column.fk_column_name, relationship.name
                 ),
             )
             .with_target("table name", table.physical_name.as_str())
@@ -328,10 +483,7 @@ fn normal_column_by_name<'a>(
     normal_columns(table).find(|(_, column)| column.physical_name == column_name)
 }
 
-fn find_relationship<'a>(
-    table: &'a Table,
-    relationship_name: &str,
-) -> Option<&'a crate::dtos::diagram::diagram_walkers::tables::connections::Relationship> {
+fn find_relationship<'a>(table: &'a Table, relationship_name: &str) -> Option<&'a Relationship> {
     table
         .connections
         .relationships
@@ -344,6 +496,45 @@ fn table_reference_name(reference: &str) -> Option<&str> {
     reference.strip_prefix("table.")
 }
 
+/// Returns the columns of the primary key, the unique columns, the indexes and the compound unique
+/// keys of the table, each in key order.
+fn key_column_lists(table: &Table) -> Vec<Vec<&str>> {
+    let primary_key = normal_columns(table)
+        .filter(|(_, column)| column.primary_key == Some(true))
+        .map(|(_, column)| column.physical_name.as_str())
+        .collect::<Vec<_>>();
+
+    let unique_columns = normal_columns(table)
+        .filter(|(_, column)| column.unique_key == Some(true))
+        .map(|(_, column)| vec![column.physical_name.as_str()]);
+
+    let index_columns = table.indexes.iter().flatten().map(|index| {
+        index
+            .columns
+            .iter()
+            .filter_map(|column| column_reference_column_name(table, &column.column_id))
+            .collect()
+    });
+
+    let compound_unique_key_columns = table
+        .compound_unique_key_list
+        .compound_unique_keys
+        .iter()
+        .flatten()
+        .map(|key| {
+            key.columns
+                .iter()
+                .filter_map(|column| column_reference_column_name(table, &column.column_id))
+                .collect()
+        });
+
+    std::iter::once(primary_key)
+        .chain(unique_columns)
+        .chain(index_columns)
+        .chain(compound_unique_key_columns)
+        .collect()
+}
+
 fn key_column_names(table: &Table) -> HashSet<String> {
     let simple_key_names = normal_columns(table).filter_map(|(_, column)| {
         if column.unique_key == Some(true) {
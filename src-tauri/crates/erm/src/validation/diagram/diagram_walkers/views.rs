@@ -0,0 +1,56 @@
+use std::collections::HashSet;
+
+use crate::dtos::diagram::diagram_walkers::tables::columns::{ColumnItem, NormalColumn};
+use crate::dtos::diagram::diagram_walkers::views::View;
+use crate::validation::diagram::validate_column_length_and_decimal_values;
+use crate::validation::{ValidationError, rules};
+
+pub fn validate_duplicate_view_column_physical_names(view: &View) -> Vec<ValidationError> {
+    let mut column_names = HashSet::new();
+
+    normal_columns(view)
+        .filter(|(_, column)| !column_names.insert(column.physical_name.as_str()))
+        .map(|(item_index, column)| {
+            ValidationError::new(
+                &rules::DUPLICATE_VIEW_COLUMN_PHYSICAL_NAME,
+                format!("columns.normal_column[{item_index}].physical_name"),
+                format!(
+                    "duplicate view column physical_name: {}",
+                    column.physical_name
+                ),
+            )
+            .with_target("view name", view.physical_name.as_str())
+            .with_target("column name", column.physical_name.as_str())
+        })
+        .collect()
+}
+
+pub fn validate_view_column_length_and_decimal(view: &View) -> Vec<ValidationError> {
+    normal_columns(view)
+        .filter_map(|(item_index, column)| {
+            validate_column_length_and_decimal_values(
+                column.column_type,
+                column.length,
+                column.decimal,
+                format!("columns.normal_column[{item_index}].length"),
+                format!("columns.normal_column[{item_index}].decimal"),
+                &[
+                    ("view name", view.physical_name.as_str()),
+                    ("column name", column.physical_name.as_str()),
+                ],
+            )
+            .err()
+        })
+        .collect()
+}
+
+fn normal_columns(view: &View) -> impl Iterator<Item = (usize, &NormalColumn)> {
+    view.columns
+        .items
+        .iter()
+        .flat_map(|items| items.iter().enumerate())
+        .filter_map(|(index, item)| match item {
+            ColumnItem::Normal(column) => Some((index, column)),
+            ColumnItem::Group(_) => None,
+        })
+}
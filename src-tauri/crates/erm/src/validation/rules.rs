@@ -0,0 +1,304 @@
+use std::fmt;
+
+use super::Severity;
+
+/// A validation rule, identified by a code that never changes once released.
+///
+/// Codes are never reused: a rule that is removed leaves a gap in the numbering.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rule {
+    pub code: &'static str,
+    pub severity: Severity,
+    pub description: &'static str,
+}
+
+impl Rule {
+    /// Returns the rule with the given code, e.g. `ERM0012`.
+    pub fn find(code: &str) -> Option<&'static Rule> {
+        RULES.iter().find(|rule| rule.code == code)
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "{} ({}): {}",
+            self.code, self.severity, self.description
+        )
+    }
+}
+
+const fn error(code: &'static str, description: &'static str) -> Rule {
+    Rule {
+        code,
+        severity: Severity::Error,
+        description,
+    }
+}
+
+const fn warning(code: &'static str, description: &'static str) -> Rule {
+    Rule {
+        code,
+        severity: Severity::Warning,
+        description,
+    }
+}
+
+pub const DUPLICATE_COLUMN_GROUP_NAME: Rule =
+    error("ERM0001", "Column group names must be unique.");
+pub const DUPLICATE_COLUMN_GROUP_COLUMN_PHYSICAL_NAME: Rule = error(
+    "ERM0002",
+    "Column physical names must be unique within a column group.",
+);
+pub const UNKNOWN_COLUMN_GROUP: Rule = error(
+    "ERM0003",
+    "Tables and views may only use column groups that are defined in the diagram.",
+);
+pub const DECIMAL_EXCEEDS_LENGTH: Rule = error(
+    "ERM0004",
+    "The decimal of a column must not be greater than its length.",
+);
+pub const LENGTH_WITHOUT_COLUMN_TYPE: Rule =
+    error("ERM0005", "A column with a length must have a column type.");
+pub const DECIMAL_WITHOUT_COLUMN_TYPE: Rule = error(
+    "ERM0006",
+    "A column with a decimal must have a column type.",
+);
+pub const LENGTH_NOT_SUPPORTED: Rule = error(
+    "ERM0007",
+    "A column may only have a length when its column type supports one.",
+);
+pub const DECIMAL_NOT_SUPPORTED: Rule = error(
+    "ERM0008",
+    "A column may only have a decimal when its column type supports one.",
+);
+pub const DUPLICATE_TABLE_PHYSICAL_NAME: Rule =
+    error("ERM0009", "Table physical names must be unique.");
+pub const DUPLICATE_RELATIONSHIP_NAME: Rule = error(
+    "ERM0010",
+    "Relationship names must be unique across tables.",
+);
+pub const DUPLICATE_COLUMN_PHYSICAL_NAME: Rule = error(
+    "ERM0011",
+    "Column physical names must be unique within a table.",
+);
+pub const DUPLICATE_INDEX_NAME: Rule =
+    error("ERM0012", "Index names must be unique within a table.");
+pub const DUPLICATE_COMPOUND_UNIQUE_KEY_NAME: Rule = error(
+    "ERM0013",
+    "Compound unique key names must be unique within a table.",
+);
+pub const AUTO_INCREMENT_NOT_KEY: Rule = error(
+    "ERM0014",
+    "An auto increment column must be part of the primary key, a unique key or an index.",
+);
+pub const UNKNOWN_INDEX_COLUMN: Rule =
+    error("ERM0015", "Index columns must be columns of the table.");
+pub const UNKNOWN_COMPOUND_UNIQUE_KEY_COLUMN: Rule = error(
+    "ERM0016",
+    "Compound unique key columns must be columns of the table.",
+);
+pub const RELATIONSHIP_TARGET_MISMATCH: Rule = error(
+    "ERM0017",
+    "A relationship must be declared in the table it targets.",
+);
+pub const AMBIGUOUS_REFERRED_UNIQUE_KEY: Rule = error(
+    "ERM0018",
+    "A relationship must not refer to both a simple unique column and a compound unique key.",
+);
+pub const UNKNOWN_FK_COLUMN: Rule = error(
+    "ERM0019",
+    "The foreign key columns of a relationship must be columns of its table.",
+);
+pub const FK_COLUMN_RELATIONSHIP_MISMATCH: Rule = error(
+    "ERM0020",
+    "The foreign key columns of a relationship must refer back to that relationship.",
+);
+pub const RELATIONSHIP_MISSING_FK_COLUMN: Rule = error(
+    "ERM0021",
+    "A column referring to a relationship must be one of its foreign key columns.",
+);
+pub const UNKNOWN_RELATIONSHIP: Rule = error(
+    "ERM0022",
+    "A column may only refer to a relationship that exists.",
+);
+pub const MISSING_REFERRED_COLUMN: Rule = error(
+    "ERM0023",
+    "A column referring to a relationship must name the column it refers to.",
+);
+pub const INVALID_REFERRED_COLUMN: Rule = error(
+    "ERM0024",
+    "A referred column must be written as `table.<table>.<column>`.",
+);
+pub const UNKNOWN_REFERRED_COLUMN_TABLE: Rule = error(
+    "ERM0025",
+    "A referred column must belong to a table of the diagram.",
+);
+pub const REFERRED_COLUMN_TABLE_MISMATCH: Rule = error(
+    "ERM0026",
+    "A referred column must belong to the source table of the relationship.",
+);
+pub const UNKNOWN_REFERRED_COLUMN: Rule = error(
+    "ERM0027",
+    "A referred column must be a column of its table.",
+);
+pub const INVALID_RELATIONSHIP_SOURCE: Rule = error(
+    "ERM0028",
+    "A relationship source must be written as `table.<table>`.",
+);
+pub const UNKNOWN_RELATIONSHIP_SOURCE: Rule = error(
+    "ERM0029",
+    "A relationship source must be a table of the diagram.",
+);
+pub const INVALID_RELATIONSHIP_TARGET: Rule = error(
+    "ERM0030",
+    "A relationship target must be written as `table.<table>`.",
+);
+pub const UNKNOWN_RELATIONSHIP_TARGET: Rule = error(
+    "ERM0031",
+    "A relationship target must be a table of the diagram.",
+);
+pub const SOURCE_WITHOUT_PRIMARY_KEY: Rule = error(
+    "ERM0032",
+    "A relationship referring to the primary key needs a source table with a primary key.",
+);
+pub const MISSING_REFERRED_UNIQUE_KEY: Rule = error(
+    "ERM0033",
+    "A relationship that does not refer to the primary key must refer to a unique key.",
+);
+pub const INVALID_REFERRED_SIMPLE_UNIQUE_COLUMN: Rule = error(
+    "ERM0034",
+    "A referred simple unique column must be written as `table.<table>.<column>`.",
+);
+pub const UNKNOWN_REFERRED_SIMPLE_UNIQUE_COLUMN: Rule = error(
+    "ERM0035",
+    "A referred simple unique column must be a unique column of the source table.",
+);
+pub const UNKNOWN_REFERRED_COMPOUND_UNIQUE_KEY: Rule = error(
+    "ERM0036",
+    "A referred compound unique key must be a compound unique key of the source table.",
+);
+pub const TABLE_WITHOUT_PRIMARY_KEY: Rule = warning("ERM0037", "Tables should have a primary key.");
+pub const UNINDEXED_FOREIGN_KEY: Rule = warning(
+    "ERM0038",
+    "Foreign key columns should lead the primary key, a unique key or an index.",
+);
+pub const NULLABLE_COMPOUND_UNIQUE_KEY_COLUMN: Rule = warning(
+    "ERM0039",
+    "Compound unique key columns should be not null, since nulls are never equal.",
+);
+pub const EMPTY_TABLE_LOGICAL_NAME: Rule = warning("ERM0040", "Tables should have a logical name.");
+pub const EMPTY_COLUMN_LOGICAL_NAME: Rule = warning(
+    "ERM0041",
+    "Columns should have a logical name, unless they inherit the one of the column they refer to.",
+);
+pub const INVALID_PHYSICAL_NAME: Rule = warning(
+    "ERM0042",
+    "Physical names should only contain letters, digits and underscores, and not start with a digit.",
+);
+pub const PHYSICAL_NAME_CASE: Rule = warning(
+    "ERM0043",
+    "Physical names should be UPPER_CASE when the diagram capitalizes names, and snake_case otherwise.",
+);
+pub const PHYSICAL_NAME_TOO_LONG: Rule = warning(
+    "ERM0044",
+    "Physical names should not be longer than the database allows for identifiers.",
+);
+pub const RESERVED_PHYSICAL_NAME: Rule = warning(
+    "ERM0045",
+    "Physical names should not be reserved keywords of the database.",
+);
+pub const MISSING_CONSTRAINT_NAME_PREFIX: Rule = warning(
+    "ERM0046",
+    "Foreign key, unique key and index names should start with the prefix of the design settings.",
+);
+pub const DUPLICATE_VIEW_PHYSICAL_NAME: Rule = error(
+    "ERM0047",
+    "View physical names must be unique among the tables and views.",
+);
+pub const DUPLICATE_VIEW_COLUMN_PHYSICAL_NAME: Rule = error(
+    "ERM0048",
+    "Column physical names must be unique within a view.",
+);
+pub const UNKNOWN_COMMENT_CONNECTION_WALKER: Rule = error(
+    "ERM0049",
+    "Comment connections must link walkers that exist in the diagram.",
+);
+
+pub const UNKNOWN_GROUP_MEMBER: Rule = error(
+    "ERM0050",
+    "Walker groups must only list walkers that exist in the diagram.",
+);
+
+pub const WALKER_IN_SEVERAL_GROUPS: Rule = error(
+    "ERM0051",
+    "A walker must belong to at most one walker group.",
+);
+
+pub const CORRUPT_IMAGE_DATA: Rule = error(
+    "ERM0052",
+    "Inserted images must hold base64 encoded PNG, JPEG or GIF data.",
+);
+
+pub const UNKNOWN_VIRTUAL_TABLE: Rule = error(
+    "ERM0053",
+    "Virtual diagrams must only place tables that exist in the diagram.",
+);
+
+/// Every validation rule, in code order.
+pub const RULES: &[Rule] = &[
+    DUPLICATE_COLUMN_GROUP_NAME,
+    DUPLICATE_COLUMN_GROUP_COLUMN_PHYSICAL_NAME,
+    UNKNOWN_COLUMN_GROUP,
+    DECIMAL_EXCEEDS_LENGTH,
+    LENGTH_WITHOUT_COLUMN_TYPE,
+    DECIMAL_WITHOUT_COLUMN_TYPE,
+    LENGTH_NOT_SUPPORTED,
+    DECIMAL_NOT_SUPPORTED,
+    DUPLICATE_TABLE_PHYSICAL_NAME,
+    DUPLICATE_RELATIONSHIP_NAME,
+    DUPLICATE_COLUMN_PHYSICAL_NAME,
+    DUPLICATE_INDEX_NAME,
+    DUPLICATE_COMPOUND_UNIQUE_KEY_NAME,
+    AUTO_INCREMENT_NOT_KEY,
+    UNKNOWN_INDEX_COLUMN,
+    UNKNOWN_COMPOUND_UNIQUE_KEY_COLUMN,
+    RELATIONSHIP_TARGET_MISMATCH,
+    AMBIGUOUS_REFERRED_UNIQUE_KEY,
+    UNKNOWN_FK_COLUMN,
+    FK_COLUMN_RELATIONSHIP_MISMATCH,
+    RELATIONSHIP_MISSING_FK_COLUMN,
+    UNKNOWN_RELATIONSHIP,
+    MISSING_REFERRED_COLUMN,
+    INVALID_REFERRED_COLUMN,
+    UNKNOWN_REFERRED_COLUMN_TABLE,
+    REFERRED_COLUMN_TABLE_MISMATCH,
+    UNKNOWN_REFERRED_COLUMN,
+    INVALID_RELATIONSHIP_SOURCE,
+    UNKNOWN_RELATIONSHIP_SOURCE,
+    INVALID_RELATIONSHIP_TARGET,
+    UNKNOWN_RELATIONSHIP_TARGET,
+    SOURCE_WITHOUT_PRIMARY_KEY,
+    MISSING_REFERRED_UNIQUE_KEY,
+    INVALID_REFERRED_SIMPLE_UNIQUE_COLUMN,
+    UNKNOWN_REFERRED_SIMPLE_UNIQUE_COLUMN,
+    UNKNOWN_REFERRED_COMPOUND_UNIQUE_KEY,
+    TABLE_WITHOUT_PRIMARY_KEY,
+    UNINDEXED_FOREIGN_KEY,
+    NULLABLE_COMPOUND_UNIQUE_KEY_COLUMN,
+    EMPTY_TABLE_LOGICAL_NAME,
+    EMPTY_COLUMN_LOGICAL_NAME,
+    INVALID_PHYSICAL_NAME,
+    PHYSICAL_NAME_CASE,
+    PHYSICAL_NAME_TOO_LONG,
+    RESERVED_PHYSICAL_NAME,
+    MISSING_CONSTRAINT_NAME_PREFIX,
+    DUPLICATE_VIEW_PHYSICAL_NAME,
+    DUPLICATE_VIEW_COLUMN_PHYSICAL_NAME,
+    UNKNOWN_COMMENT_CONNECTION_WALKER,
+    UNKNOWN_GROUP_MEMBER,
+    WALKER_IN_SEVERAL_GROUPS,
+    CORRUPT_IMAGE_DATA,
+    UNKNOWN_VIRTUAL_TABLE,
+];
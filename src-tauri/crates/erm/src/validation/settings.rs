@@ -0,0 +1,170 @@
+use std::path::{Path, PathBuf};
+
+use quick_xml::de::DeError;
+use serde::{Deserialize, Serialize};
+
+use super::rules::Rule;
+use super::{Severity, ValidationError};
+use crate::dtos::diagram::diagram_settings::DiagramSettings;
+use crate::errors::Error;
+use crate::lint;
+
+/// Extension appended to a diagram filename to find its validation settings.
+const SIDECAR_EXTENSION: &str = "validation.xml";
+
+/// Which validation rules run for a diagram, kept beside it so that legacy files can be opened
+/// without being modified.
+///
+/// ```xml
+/// <validation>
+///   <disabled_rules><rule>ERM0037</rule></disabled_rules>
+///   <enabled_rules><rule>ERM0042</rule></enabled_rules>
+///   <suppressions>
+///     <suppression><rule>ERM0041</rule><table>MEMBERS</table><column>NOTE</column></suppression>
+///   </suppressions>
+/// </validation>
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename = "validation")]
+pub struct ValidationSettings {
+    /// Rules that never run.
+    #[serde(default)]
+    pub disabled_rules: RuleCodes,
+
+    /// Rules that run even when the diagram settings turn them off.
+    #[serde(default)]
+    pub enabled_rules: RuleCodes,
+
+    #[serde(default)]
+    pub suppressions: Suppressions,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RuleCodes {
+    #[serde(default, rename = "rule")]
+    pub codes: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Suppressions {
+    #[serde(default, rename = "suppression")]
+    pub suppressions: Vec<Suppression>,
+}
+
+/// Silences the problems of one table or view, or of one of its columns.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Suppression {
+    /// The code of the silenced rule. Every rule is silenced when it is left out.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rule: Option<String>,
+
+    /// The physical name of the table or view.
+    pub table: String,
+
+    /// The physical name of the column. The whole table is silenced when it is left out.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub column: Option<String>,
+}
+
+impl ValidationSettings {
+    /// Returns the path of the validation settings of a diagram, e.g. `model.erm.validation.xml`.
+    pub fn sidecar_path(filename: impl AsRef<Path>) -> PathBuf {
+        let mut path = filename.as_ref().as_os_str().to_owned();
+        path.push(".");
+        path.push(SIDECAR_EXTENSION);
+        PathBuf::from(path)
+    }
+
+    /// Reads the validation settings beside a diagram, or the defaults when there are none.
+    pub fn read(filename: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = Self::sidecar_path(filename);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let settings: Self = quick_xml::de::from_str(&std::fs::read_to_string(path)?)?;
+        settings.check_rule_codes()?;
+        Ok(settings)
+    }
+
+    /// Tells whether the rule runs for a diagram with the given settings.
+    ///
+    /// `suspend_validator` turns off every warning and `validate_physical_name` the naming rules
+    /// of [`crate::lint`], while errors always run unless they are disabled here.
+    pub fn is_enabled(&self, rule: &Rule, diagram_settings: &DiagramSettings) -> bool {
+        if self.enabled_rules.contains(rule) {
+            return true;
+        }
+        if self.disabled_rules.contains(rule) {
+            return false;
+        }
+
+        let suspended = diagram_settings.suspend_validator && rule.severity == Severity::Warning;
+        let physical_name_skipped =
+            !diagram_settings.validate_physical_name && lint::RULES.contains(rule);
+        !suspended && !physical_name_skipped
+    }
+
+    /// Tells whether the problem is silenced by a suppression.
+    pub fn is_suppressed(&self, error: &ValidationError) -> bool {
+        self.suppressions
+            .suppressions
+            .iter()
+            .any(|suppression| suppression.matches(error))
+    }
+
+    /// Keeps the problems of the rules that run and are not suppressed.
+    pub fn filter(
+        &self,
+        errors: Vec<ValidationError>,
+        diagram_settings: &DiagramSettings,
+    ) -> Vec<ValidationError> {
+        errors
+            .into_iter()
+            .filter(|error| {
+                self.is_enabled(error.rule, diagram_settings) && !self.is_suppressed(error)
+            })
+            .collect()
+    }
+
+    /// Fails on the first rule code that is not in the catalog, so that typos do not go unnoticed.
+    fn check_rule_codes(&self) -> Result<(), Error> {
+        let codes = self
+            .disabled_rules
+            .codes
+            .iter()
+            .chain(&self.enabled_rules.codes)
+            .chain(
+                self.suppressions
+                    .suppressions
+                    .iter()
+                    .filter_map(|suppression| suppression.rule.as_ref()),
+            );
+
+        for code in codes {
+            if Rule::find(code).is_none() {
+                return Err(DeError::Custom(format!("unknown validation rule: {code}")).into());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl RuleCodes {
+    fn contains(&self, rule: &Rule) -> bool {
+        self.codes.iter().any(|code| code == rule.code)
+    }
+}
+
+impl Suppression {
+    fn matches(&self, error: &ValidationError) -> bool {
+        self.rule.as_ref().is_none_or(|code| code == error.code())
+            && [error.target("table name"), error.target("view name")]
+                .contains(&Some(self.table.as_str()))
+            && self
+                .column
+                .as_ref()
+                .is_none_or(|column| error.target("column name") == Some(column.as_str()))
+    }
+}
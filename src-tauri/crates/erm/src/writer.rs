@@ -0,0 +1,189 @@
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::mem;
+
+use super::canonical::canonicalize;
+use super::entities::diagram::Diagram;
+use super::entities::diagram::unknown_elements::UnknownElements;
+use super::entities::diagram::xml_layout::XmlLayout;
+use super::errors::Error;
+use super::schema::{Content, SCHEMA};
+use super::xml_tree::{self, Element, Node};
+use quick_xml::se::Serializer;
+use serde::Serialize;
+
+const XML_DECLARATION: &str = "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n";
+
+/// Layout of a saved `.erm` file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    /// Writes elements back the way they were read: in their order, under their tag names and in
+    /// their empty-element form, including elements the model does not know.
+    #[default]
+    Preserve,
+
+    /// Orders elements as declared in `erm.xsd` and sorts tables and relationships by name,
+    /// so that edits to different tables produce non-overlapping diffs.
+    Canonical,
+}
+
+pub fn write_file(filename: &str, diagram: &Diagram, format: Format) -> Result<(), Error> {
+    let mut root = xml_tree::parse(&to_xml(diagram)?)?;
+    let path = root.name.clone();
+    let xml_layout = match format {
+        Format::Preserve => Some(&diagram.xml_layout),
+        Format::Canonical => None,
+    };
+    restore_source_layout(
+        &mut root,
+        &SCHEMA.root().content,
+        &path,
+        &diagram.unknown_elements,
+        xml_layout,
+    )?;
+
+    if format == Format::Canonical {
+        canonicalize(&mut root);
+    }
+
+    let content = format!("{XML_DECLARATION}{}\n", root.to_pretty_xml("\t"));
+    fs::write(filename, content)?;
+    Ok(())
+}
+
+pub(crate) fn to_xml(diagram: &Diagram) -> Result<String, Error> {
+    let mut content = String::new();
+    let mut serializer = Serializer::with_root(&mut content, Some("diagram"))?;
+    serializer.expand_empty_elements(true);
+    diagram.serialize(serializer)?;
+    Ok(content)
+}
+
+/// Puts back the elements the model does not know and, when a layout is given, the order, tag
+/// names and empty-element form the file was read with.
+fn restore_source_layout(
+    element: &mut Element,
+    content: &Content,
+    path: &str,
+    unknown_elements: &UnknownElements,
+    xml_layout: Option<&XmlLayout>,
+) -> Result<(), Error> {
+    let declarations = match content {
+        Content::Complex(type_name) => SCHEMA.children(type_name),
+        _ => &[],
+    };
+
+    if element.elements().next().is_none() && unknown_elements.get(path).is_empty() {
+        return Ok(());
+    }
+
+    // Keys are taken before children are restored, since renaming a child's identity element
+    // (e.g. `walker_group_name` to `name`) would change them.
+    let mut keys = element.child_keys().into_iter().collect::<HashMap<_, _>>();
+    let mut children = Vec::with_capacity(keys.len());
+    for (index, node) in mem::take(&mut element.children).into_iter().enumerate() {
+        let (Node::Element(mut child), Some(key)) = (node, keys.remove(&index)) else {
+            continue;
+        };
+        let declared = declarations
+            .iter()
+            .find(|declaration| declaration.name == child.name)
+            .map_or(Content::Simple, |declaration| declaration.content.clone());
+
+        restore_source_layout(
+            &mut child,
+            &declared,
+            &format!("{path}/{key}"),
+            unknown_elements,
+            xml_layout,
+        )?;
+        if xml_layout.is_some() && child.children.is_empty() {
+            child.set_expanded(declared.is_container());
+        }
+        children.push((key, child));
+    }
+
+    let layout = xml_layout.and_then(|xml_layout| xml_layout.get(path));
+    if let Some(layout) = layout
+        && !layout.order.is_empty()
+    {
+        children = reorder(children, &layout.order);
+    }
+
+    // An element that is modelled now wins over a stale copy captured when it was not.
+    let modelled_names = children
+        .iter()
+        .map(|(_, child)| child.name.clone())
+        .collect::<HashSet<_>>();
+
+    for unknown_element in unknown_elements.get(path) {
+        let restored = xml_tree::parse(&unknown_element.xml)?;
+
+        if modelled_names.contains(&restored.name) {
+            continue;
+        }
+
+        let position = unknown_element
+            .anchor
+            .as_ref()
+            .map(|anchor| {
+                children
+                    .iter()
+                    .position(|(key, _)| key == anchor)
+                    .map_or(children.len(), |index| index + 1)
+            })
+            .unwrap_or(0);
+
+        let siblings = children[..position]
+            .iter()
+            .filter(|(_, child)| child.name == restored.name)
+            .count();
+        children.insert(position, (restored.key(siblings), restored));
+    }
+
+    if let Some(layout) = layout {
+        for (key, child) in &mut children {
+            if let Some(name) = layout.names.get(key) {
+                child.name = name.clone();
+            }
+            if let Some(expanded) = layout.expanded.get(key)
+                && (child.children.is_empty() || child.is_expanded())
+            {
+                child.set_expanded(*expanded);
+            }
+        }
+    }
+
+    element.children = children
+        .into_iter()
+        .map(|(_, child)| Node::Element(child))
+        .collect();
+    Ok(())
+}
+
+/// Puts the children in the order the file listed them. Children added since follow the sibling
+/// they follow in the model's order.
+fn reorder(children: Vec<(String, Element)>, order: &[String]) -> Vec<(String, Element)> {
+    let mut added = Vec::new();
+    let mut listed = Vec::new();
+    let mut previous = None;
+    for (key, child) in children {
+        if order.contains(&key) {
+            listed.push((key.clone(), child));
+        } else {
+            added.push((previous.clone(), (key.clone(), child)));
+        }
+        previous = Some(key);
+    }
+
+    listed.sort_by_key(|(key, _)| order.iter().position(|listed| listed == key));
+
+    for (previous, child) in added {
+        let position = previous
+            .and_then(|previous| listed.iter().position(|(key, _)| *key == previous))
+            .map_or(0, |index| index + 1);
+        listed.insert(position, child);
+    }
+
+    listed
+}
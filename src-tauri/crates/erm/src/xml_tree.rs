@@ -0,0 +1,274 @@
+use quick_xml::escape::{escape, partial_escape, resolve_predefined_entity};
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::{Reader, Result};
+
+/// Child elements whose text identifies their parent among same-name siblings.
+const IDENTITY_ELEMENTS: &[&str] = &["physical_name", "column_group_name", "name"];
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Node {
+    Element(Element),
+    Text(String),
+    Comment(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Element {
+    pub name: String,
+    pub attributes: Vec<(String, String)>,
+    pub children: Vec<Node>,
+}
+
+impl Element {
+    fn new(start: &BytesStart<'_>) -> Result<Self> {
+        let name = String::from_utf8_lossy(start.name().as_ref()).into_owned();
+        let mut attributes = Vec::new();
+
+        for attribute in start.attributes() {
+            let attribute = attribute?;
+            let key = String::from_utf8_lossy(attribute.key.as_ref()).into_owned();
+            let value = attribute.unescape_value()?.into_owned();
+            attributes.push((key, value));
+        }
+
+        Ok(Self {
+            name,
+            attributes,
+            children: Vec::new(),
+        })
+    }
+
+    pub fn elements(&self) -> impl Iterator<Item = &Element> {
+        self.children.iter().filter_map(|node| match node {
+            Node::Element(element) => Some(element),
+            _ => None,
+        })
+    }
+
+    /// Returns whether the element has no content and was written across two lines, the way
+    /// ERFlute writes empty containers.
+    pub fn is_expanded(&self) -> bool {
+        matches!(self.children.as_slice(), [Node::Text(text)] if text == "\n")
+    }
+
+    /// Sets whether the element, which must have no content, is written across two lines.
+    pub fn set_expanded(&mut self, expanded: bool) {
+        self.children = if expanded {
+            vec![Node::Text("\n".to_string())]
+        } else {
+            Vec::new()
+        };
+    }
+
+    pub fn text(&self) -> String {
+        self.children
+            .iter()
+            .filter_map(|node| match node {
+                Node::Text(text) => Some(text.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns the text of the child that identifies this element among same-name siblings.
+    pub fn identity(&self) -> Option<String> {
+        IDENTITY_ELEMENTS.iter().find_map(|identity| {
+            self.elements()
+                .find(|child| child.name == *identity)
+                .map(Element::text)
+        })
+    }
+
+    /// Returns the key of every child element, in document order.
+    ///
+    /// Elements that carry an identity child (e.g. a table's `physical_name`) are keyed by it,
+    /// so keys stay stable when siblings are added, removed or reordered. Other elements fall back
+    /// to their position among same-name siblings, leaving the first one keyed by its bare name.
+    pub fn child_keys(&self) -> Vec<(usize, String)> {
+        let mut keys = Vec::new();
+
+        for (index, node) in self.children.iter().enumerate() {
+            let Node::Element(element) = node else {
+                continue;
+            };
+
+            let position = self.children[..index]
+                .iter()
+                .filter(|node| matches!(node, Node::Element(e) if e.name == element.name))
+                .count();
+
+            keys.push((index, element.key(position)));
+        }
+
+        keys
+    }
+
+    /// Returns the key of this element when `position` same-name siblings precede it.
+    pub fn key(&self, position: usize) -> String {
+        match (self.identity(), position) {
+            (Some(identity), _) => format!("{}[{identity}]", self.name),
+            (None, 0) => self.name.clone(),
+            (None, position) => format!("{}[{position}]", self.name),
+        }
+    }
+
+    pub fn to_xml(&self) -> String {
+        let mut output = String::new();
+        write_element(&mut output, self, None, 0);
+        output
+    }
+
+    pub fn to_pretty_xml(&self, indent: &str) -> String {
+        let mut output = String::new();
+        write_element(&mut output, self, Some(indent), 0);
+        output
+    }
+}
+
+pub(crate) fn parse(content: &str) -> Result<Element> {
+    let mut reader = Reader::from_str(content);
+    let mut stack: Vec<Element> = Vec::new();
+    let mut root = None;
+
+    loop {
+        match reader.read_event()? {
+            Event::Start(start) => stack.push(Element::new(&start)?),
+            Event::Empty(start) => {
+                let element = Element::new(&start)?;
+                append(&mut stack, &mut root, Node::Element(element));
+            }
+            Event::End(_) => {
+                if let Some(element) = stack.pop() {
+                    append(&mut stack, &mut root, Node::Element(element));
+                }
+            }
+            Event::Text(text) => {
+                let text = text.xml_content()?.into_owned();
+                if let Some(parent) = stack.last_mut() {
+                    parent.children.push(Node::Text(text));
+                }
+            }
+            Event::GeneralRef(reference) => {
+                let text = match reference.resolve_char_ref()? {
+                    Some(character) => character.to_string(),
+                    None => {
+                        let name = reference.decode()?;
+                        resolve_predefined_entity(&name)
+                            .map(str::to_string)
+                            .unwrap_or_else(|| format!("&{name};"))
+                    }
+                };
+                if let Some(parent) = stack.last_mut() {
+                    parent.children.push(Node::Text(text));
+                }
+            }
+            Event::CData(data) => {
+                let text = String::from_utf8_lossy(&data).into_owned();
+                if let Some(parent) = stack.last_mut() {
+                    parent.children.push(Node::Text(text));
+                }
+            }
+            Event::Comment(comment) => {
+                let comment = String::from_utf8_lossy(&comment).into_owned();
+                if let Some(parent) = stack.last_mut() {
+                    parent.children.push(Node::Comment(comment));
+                }
+            }
+            Event::Eof => break,
+            Event::Decl(_) | Event::PI(_) | Event::DocType(_) => {}
+        }
+    }
+
+    let mut root = root.ok_or(quick_xml::errors::SyntaxError::UnclosedTag)?;
+    normalize(&mut root);
+    Ok(root)
+}
+
+fn append(stack: &mut [Element], root: &mut Option<Element>, node: Node) {
+    match stack.last_mut() {
+        Some(parent) => parent.children.push(node),
+        None => {
+            if let Node::Element(element) = node {
+                *root = Some(element);
+            }
+        }
+    }
+}
+
+/// Merges adjacent text nodes and drops whitespace that only formats the document.
+fn normalize(element: &mut Element) {
+    let mut children: Vec<Node> = Vec::with_capacity(element.children.len());
+
+    for node in element.children.drain(..) {
+        match (children.last_mut(), node) {
+            (Some(Node::Text(previous)), Node::Text(text)) => previous.push_str(&text),
+            (_, node) => children.push(node),
+        }
+    }
+
+    // Whitespace between child elements is layout. Inside an empty element, whitespace spanning
+    // lines only tells that the element was written across two lines.
+    let has_markup = children.iter().any(|node| !matches!(node, Node::Text(_)));
+    children.retain(|node| match node {
+        Node::Text(text) if text.trim().is_empty() => !has_markup,
+        _ => true,
+    });
+    if let [Node::Text(text)] = children.as_mut_slice()
+        && text.trim().is_empty()
+        && text.contains('\n')
+    {
+        *text = "\n".to_string();
+    }
+
+    for node in &mut children {
+        if let Node::Element(child) = node {
+            normalize(child);
+        }
+    }
+
+    element.children = children;
+}
+
+fn write_element(output: &mut String, element: &Element, indent: Option<&str>, depth: usize) {
+    output.push('<');
+    output.push_str(&element.name);
+    for (key, value) in &element.attributes {
+        output.push_str(&format!(" {key}=\"{}\"", escape(value.as_str())));
+    }
+    output.push('>');
+
+    let has_markup = element
+        .children
+        .iter()
+        .any(|node| !matches!(node, Node::Text(_)));
+
+    if has_markup {
+        for node in &element.children {
+            write_line_break(output, indent, depth + 1);
+            match node {
+                Node::Element(child) => write_element(output, child, indent, depth + 1),
+                Node::Text(text) => output.push_str(&partial_escape(text.trim())),
+                Node::Comment(comment) => output.push_str(&format!("<!--{comment}-->")),
+            }
+        }
+        write_line_break(output, indent, depth);
+    } else if element.is_expanded() {
+        output.push('\n');
+        if let Some(indent) = indent {
+            output.push_str(&indent.repeat(depth));
+        }
+    } else {
+        output.push_str(&partial_escape(element.text().as_str()));
+    }
+
+    output.push_str("</");
+    output.push_str(&element.name);
+    output.push('>');
+}
+
+fn write_line_break(output: &mut String, indent: Option<&str>, depth: usize) {
+    if let Some(indent) = indent {
+        output.push('\n');
+        output.push_str(&indent.repeat(depth));
+    }
+}
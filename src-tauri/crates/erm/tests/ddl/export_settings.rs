@@ -0,0 +1,214 @@
+use pretty_assertions::assert_eq;
+use rusqlite::Connection;
+
+use erm::ddl::{mysql, postgresql, sqlite};
+use erm::dtos::diagram::Diagram;
+use erm::dtos::diagram::diagram_settings::ExportSettings;
+use erm::dtos::diagram::diagram_walkers::tables::Table;
+use erm::dtos::diagram::diagram_walkers::tables::indexes::{Column, Index};
+
+use super::support::{FIXTURES, fixture};
+
+fn with_settings(settings: ExportSettings) -> Diagram {
+    let mut diagram = fixture(FIXTURES[0]);
+    diagram.diagram_settings.export_settings = settings;
+    diagram
+}
+
+fn table_mut<'a>(diagram: &'a mut Diagram, name: &str) -> &'a mut Table {
+    diagram
+        .diagram_walkers
+        .as_mut()
+        .and_then(|walkers| walkers.tables.as_mut())
+        .and_then(|tables| tables.iter_mut().find(|table| table.physical_name == name))
+        .expect("missing table")
+}
+
+fn statements_starting_with<'a>(sql: &'a str, prefix: &str) -> Vec<&'a str> {
+    sql.lines()
+        .filter(|line| line.starts_with(prefix))
+        .collect()
+}
+
+#[test]
+fn missing_settings_create_everything_and_drop_nothing() {
+    let sql = mysql::generate(&with_settings(ExportSettings::default())).sql;
+
+    assert_eq!(statements_starting_with(&sql, "CREATE TABLE").len(), 6);
+    assert_eq!(statements_starting_with(&sql, "ALTER TABLE").len(), 6);
+    assert!(statements_starting_with(&sql, "DROP").is_empty());
+    assert!(sql.contains(" COMMENT = '会員';"));
+}
+
+#[test]
+fn drop_table_drops_referencing_tables_first() {
+    let sql = mysql::generate(&with_settings(ExportSettings {
+        drop_table: Some(true),
+        ..ExportSettings::default()
+    }))
+    .sql;
+
+    assert_eq!(
+        statements_starting_with(&sql, "DROP TABLE"),
+        vec![
+            "DROP TABLE IF EXISTS `POST_REPLIES`;",
+            "DROP TABLE IF EXISTS `POST_THREADS`;",
+            "DROP TABLE IF EXISTS `POSTS`;",
+            "DROP TABLE IF EXISTS `MEMBER_PROFILES`;",
+            "DROP TABLE IF EXISTS `MST_GENDER`;",
+            "DROP TABLE IF EXISTS `MEMBERS`;",
+        ]
+    );
+    assert!(sql.find("DROP TABLE") < sql.find("CREATE TABLE"));
+}
+
+#[test]
+fn drop_statements_make_the_script_rerunnable() {
+    let mut diagram = with_settings(ExportSettings {
+        drop_index: Some(true),
+        drop_table: Some(true),
+        ..ExportSettings::default()
+    });
+    table_mut(&mut diagram, "POSTS").indexes = Some(vec![Index {
+        name: "IDX_POSTS_TITLE".to_string(),
+        index_type: "BTREE".to_string(),
+        description: None,
+        full_text: None,
+        non_unique: Some(true),
+        columns: vec![Column {
+            column_id: "TITLE".to_string(),
+            desc: None,
+        }],
+    }]);
+    let sql = sqlite::generate(&diagram).sql;
+    let connection = Connection::open_in_memory().expect("failed to open SQLite");
+
+    for _ in 0..2 {
+        connection
+            .execute_batch(&format!("PRAGMA foreign_keys = ON;\n{sql}"))
+            .unwrap_or_else(|error| panic!("{error}\n\n{sql}"));
+    }
+    assert!(sql.starts_with("DROP INDEX IF EXISTS \"IDX_POSTS_TITLE\";\n"));
+}
+
+#[test]
+fn disabled_statements_are_left_out() {
+    let sql = postgresql::generate(&with_settings(ExportSettings {
+        create_table: Some(true),
+        create_index: Some(false),
+        create_foreign_key: Some(false),
+        create_comment: Some(false),
+        ..ExportSettings::default()
+    }))
+    .sql;
+
+    assert_eq!(statements_starting_with(&sql, "CREATE TABLE").len(), 6);
+    assert!(statements_starting_with(&sql, "ALTER TABLE").is_empty());
+    assert!(statements_starting_with(&sql, "COMMENT ON").is_empty());
+
+    let sql = postgresql::generate(&with_settings(ExportSettings {
+        create_table: Some(false),
+        ..ExportSettings::default()
+    }))
+    .sql;
+
+    assert!(statements_starting_with(&sql, "CREATE TABLE").is_empty());
+    assert_eq!(statements_starting_with(&sql, "ALTER TABLE").len(), 6);
+}
+
+#[test]
+fn inline_comments_precede_tables_and_columns() {
+    let sql = sqlite::generate(&with_settings(ExportSettings {
+        inline_table_comment: Some(true),
+        inline_column_comment: Some(true),
+        ..ExportSettings::default()
+    }))
+    .sql;
+
+    assert!(sql.starts_with(
+        "-- 会員\nCREATE TABLE \"MEMBERS\" (\n  -- 会員ID\n  \"MEMBER_ID\" INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,\n  -- 苗字\n"
+    ));
+    Connection::open_in_memory()
+        .expect("failed to open SQLite")
+        .execute_batch(&sql)
+        .expect("inline comments must keep the script runnable");
+}
+
+#[test]
+fn comment_value_selects_the_commented_text() {
+    let mut diagram = with_settings(ExportSettings {
+        comment_value_description: Some(true),
+        ..ExportSettings::default()
+    });
+    table_mut(&mut diagram, "MEMBERS").description = "Registered\nmembers".to_string();
+
+    let sql = mysql::generate(&diagram).sql;
+    assert!(sql.contains(") COMMENT = 'Registered\nmembers';"));
+    assert!(!sql.contains("COMMENT '苗字'"));
+
+    diagram.diagram_settings.export_settings = ExportSettings {
+        comment_value_logical_name: Some(true),
+        ..ExportSettings::default()
+    };
+    let sql = mysql::generate(&diagram).sql;
+    assert!(sql.contains(") COMMENT = '会員';"));
+    assert!(sql.contains("COMMENT '苗字'"));
+
+    diagram.diagram_settings.export_settings = ExportSettings {
+        comment_value_logical_name_description: Some(true),
+        comment_replace_line_feed: Some(true),
+        comment_replace_string: Some("<br>".to_string()),
+        ..ExportSettings::default()
+    };
+    let sql = mysql::generate(&diagram).sql;
+    assert!(sql.contains(") COMMENT = '会員: Registered<br>members';"));
+}
+
+#[test]
+fn views_follow_their_create_and_drop_settings() {
+    let mut diagram = fixture(FIXTURES[3]);
+    diagram.diagram_settings.export_settings = ExportSettings {
+        drop_view: Some(true),
+        drop_table: Some(true),
+        ..ExportSettings::default()
+    };
+
+    let sql = sqlite::generate(&diagram).sql;
+    assert!(sql.starts_with("DROP VIEW IF EXISTS \"MEMBER_NAMES\";\n"));
+    assert!(sql.ends_with("CREATE VIEW \"MEMBER_NAMES\" AS\nSELECT MEMBER_ID, MEMBER_NAME\nFROM MEMBERS\nORDER BY MEMBER_NAME;\n"));
+    let connection = Connection::open_in_memory().expect("failed to open SQLite");
+    connection
+        .execute_batch(&sql)
+        .expect("views must be created after their tables");
+    connection
+        .execute_batch(&sql)
+        .expect("dropping views first must keep the script rerunnable");
+
+    diagram.diagram_settings.export_settings = ExportSettings {
+        create_view: Some(false),
+        ..ExportSettings::default()
+    };
+    assert!(!postgresql::generate(&diagram).sql.contains("VIEW"));
+}
+
+#[test]
+fn views_without_a_query_are_skipped_with_a_warning() {
+    let mut diagram = fixture(FIXTURES[3]);
+    let views = diagram
+        .diagram_walkers
+        .as_mut()
+        .and_then(|walkers| walkers.views.as_mut())
+        .expect("missing views");
+    views[0].sql = " ;\n".to_string();
+
+    let ddl = mysql::generate(&diagram);
+
+    assert!(!ddl.sql.contains("CREATE VIEW"));
+    assert_eq!(
+        ddl.warnings
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>(),
+        vec!["MEMBER_NAMES: view without a query is skipped"]
+    );
+}
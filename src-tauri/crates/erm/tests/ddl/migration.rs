@@ -0,0 +1,347 @@
+use pretty_assertions::assert_eq;
+use rusqlite::Connection;
+
+use erm::column_type::ColumnType;
+use erm::ddl::{Warning, WarningKind, mysql, postgresql, sqlite};
+use erm::dtos::diagram::Diagram;
+use erm::dtos::diagram::diagram_walkers::tables::Table;
+use erm::dtos::diagram::diagram_walkers::tables::columns::{ColumnItem, NormalColumn};
+use erm::dtos::diagram::diagram_walkers::tables::compound_unique_key_list::{
+    Column as UniqueKeyColumn, CompoundUniqueKey,
+};
+use erm::dtos::diagram::diagram_walkers::tables::connections::{OnAction, Relationship};
+use erm::dtos::diagram::diagram_walkers::tables::indexes::{Column, Index};
+
+use super::support::{FIXTURES, fixture};
+
+fn tables_mut(diagram: &mut Diagram) -> &mut Vec<Table> {
+    diagram
+        .diagram_walkers
+        .as_mut()
+        .and_then(|walkers| walkers.tables.as_mut())
+        .expect("missing tables")
+}
+
+fn table_mut<'a>(diagram: &'a mut Diagram, name: &str) -> &'a mut Table {
+    tables_mut(diagram)
+        .iter_mut()
+        .find(|table| table.physical_name == name)
+        .expect("missing table")
+}
+
+fn column_mut<'a>(diagram: &'a mut Diagram, table: &str, name: &str) -> &'a mut NormalColumn {
+    table_mut(diagram, table)
+        .columns
+        .items
+        .iter_mut()
+        .flatten()
+        .find_map(|item| match item {
+            ColumnItem::Normal(column) if column.physical_name == name => Some(column),
+            _ => None,
+        })
+        .expect("missing column")
+}
+
+fn relationship_mut<'a>(diagram: &'a mut Diagram, table: &str, name: &str) -> &'a mut Relationship {
+    table_mut(diagram, table)
+        .connections
+        .relationships
+        .iter_mut()
+        .flatten()
+        .find(|relationship| relationship.name == name)
+        .expect("missing relationship")
+}
+
+fn index(name: &str, column_ids: &[&str]) -> Index {
+    Index {
+        name: name.to_string(),
+        index_type: String::new(),
+        description: None,
+        full_text: None,
+        non_unique: Some(true),
+        columns: column_ids
+            .iter()
+            .map(|column_id| Column {
+                column_id: column_id.to_string(),
+                desc: None,
+            })
+            .collect(),
+    }
+}
+
+/// Renames a table along with every reference other tables make to it.
+fn rename_table(diagram: &mut Diagram, old_name: &str, new_name: &str) {
+    let old_reference = format!("table.{old_name}");
+    let new_reference = format!("table.{new_name}");
+
+    for table in tables_mut(diagram) {
+        if table.physical_name == old_name {
+            table.physical_name = new_name.to_string();
+        }
+        for relationship in table.connections.relationships.iter_mut().flatten() {
+            for reference in [&mut relationship.source, &mut relationship.target] {
+                if *reference == old_reference {
+                    *reference = new_reference.clone();
+                }
+            }
+        }
+        for item in table.columns.items.iter_mut().flatten() {
+            if let ColumnItem::Normal(column) = item
+                && let Some(referred_column) = &mut column.referred_column
+                && let Some(column_name) =
+                    referred_column.strip_prefix(&format!("{old_reference}."))
+            {
+                *referred_column = format!("{new_reference}.{column_name}");
+            }
+        }
+    }
+}
+
+#[test]
+fn unchanged_diagrams_generate_nothing() {
+    for path in FIXTURES {
+        let diagram = fixture(path);
+
+        assert_eq!(mysql::migrate(&diagram, &diagram).sql, "");
+        assert_eq!(postgresql::migrate(&diagram, &diagram).sql, "");
+        assert_eq!(sqlite::migrate(&diagram, &diagram).sql, "");
+    }
+}
+
+#[test]
+fn changed_referential_actions_recreate_the_foreign_key() {
+    let old = fixture(FIXTURES[0]);
+    let mut new = old.clone();
+    relationship_mut(&mut new, "POSTS", "FK_MEMBER_POSTS_MEMBERS").on_delete_action =
+        Some(OnAction::Cascade);
+
+    assert_eq!(
+        mysql::migrate(&old, &new).sql,
+        "ALTER TABLE `POSTS` DROP FOREIGN KEY `FK_MEMBER_POSTS_MEMBERS`;\n\
+         \n\
+         ALTER TABLE `POSTS` ADD CONSTRAINT `FK_MEMBER_POSTS_MEMBERS` FOREIGN KEY (`MEMBER_ID`) REFERENCES `MEMBERS` (`MEMBER_ID`) ON DELETE CASCADE ON UPDATE RESTRICT;\n"
+    );
+}
+
+#[test]
+fn column_changes_become_alter_table_statements() {
+    let old = fixture(FIXTURES[0]);
+    let mut new = old.clone();
+    column_mut(&mut new, "POSTS", "TITLE").length = Some(256);
+    column_mut(&mut new, "POSTS", "TEXT").not_null = Some(true);
+    column_mut(&mut new, "POSTS", "VIEW_COUNT").default_value = None;
+    let items = table_mut(&mut new, "POSTS").columns.items.as_mut().unwrap();
+    items.retain(
+        |item| !matches!(item, ColumnItem::Normal(column) if column.physical_name == "IMG_URL"),
+    );
+    items.push(ColumnItem::Normal(NormalColumn {
+        physical_name: "SUMMARY".to_string(),
+        logical_name: Some("概要".to_string()),
+        column_type: Some(ColumnType::VarCharN),
+        length: Some(255),
+        ..Default::default()
+    }));
+
+    assert_eq!(
+        mysql::migrate(&old, &new).sql,
+        "ALTER TABLE `POSTS` DROP COLUMN `IMG_URL`;\n\
+         \n\
+         ALTER TABLE `POSTS` ADD COLUMN `SUMMARY` varchar(255) COMMENT '概要';\n\
+         \n\
+         ALTER TABLE `POSTS` MODIFY COLUMN `TITLE` varchar(256) NOT NULL COMMENT 'タイトル';\n\
+         \n\
+         ALTER TABLE `POSTS` MODIFY COLUMN `TEXT` text NOT NULL COMMENT '本文';\n\
+         \n\
+         ALTER TABLE `POSTS` MODIFY COLUMN `VIEW_COUNT` bigint NOT NULL COMMENT '閲覧数';\n"
+    );
+    assert_eq!(
+        postgresql::migrate(&old, &new).sql,
+        "ALTER TABLE \"POSTS\" DROP COLUMN \"IMG_URL\";\n\
+         \n\
+         ALTER TABLE \"POSTS\" ADD COLUMN \"SUMMARY\" varchar(255);\n\
+         \n\
+         COMMENT ON COLUMN \"POSTS\".\"SUMMARY\" IS '概要';\n\
+         \n\
+         ALTER TABLE \"POSTS\" ALTER COLUMN \"TITLE\" TYPE varchar(256);\n\
+         \n\
+         ALTER TABLE \"POSTS\" ALTER COLUMN \"TEXT\" SET NOT NULL;\n\
+         \n\
+         ALTER TABLE \"POSTS\" ALTER COLUMN \"VIEW_COUNT\" DROP DEFAULT;\n"
+    );
+}
+
+#[test]
+fn renamed_columns_are_renamed_in_place() {
+    let old = fixture(FIXTURES[0]);
+    let mut new = old.clone();
+    column_mut(&mut new, "POSTS", "TITLE").physical_name = "SUBJECT".to_string();
+
+    assert_eq!(
+        mysql::migrate(&old, &new).sql,
+        "ALTER TABLE `POSTS` RENAME COLUMN `TITLE` TO `SUBJECT`;\n"
+    );
+    assert_eq!(
+        postgresql::migrate(&old, &new).sql,
+        "ALTER TABLE \"POSTS\" RENAME COLUMN \"TITLE\" TO \"SUBJECT\";\n"
+    );
+    assert_eq!(
+        sqlite::migrate(&old, &new).sql,
+        "ALTER TABLE \"POSTS\" RENAME COLUMN \"TITLE\" TO \"SUBJECT\";\n"
+    );
+}
+
+#[test]
+fn foreign_keys_depending_on_a_modified_column_are_dropped_first_and_added_last() {
+    let old = fixture(FIXTURES[0]);
+    let mut new = old.clone();
+    column_mut(&mut new, "MST_GENDER", "GENDER_ID").column_type = Some(ColumnType::BigInt);
+
+    assert_eq!(
+        mysql::migrate(&old, &new).sql,
+        "ALTER TABLE `MEMBER_PROFILES` DROP FOREIGN KEY `FK_MEMBER_PROFILES_MST_GENDER`;\n\
+         \n\
+         ALTER TABLE `MEMBER_PROFILES` MODIFY COLUMN `GENDER_ID` bigint UNSIGNED;\n\
+         \n\
+         ALTER TABLE `MST_GENDER` MODIFY COLUMN `GENDER_ID` bigint UNSIGNED NOT NULL AUTO_INCREMENT COMMENT '性別ID';\n\
+         \n\
+         ALTER TABLE `MEMBER_PROFILES` ADD CONSTRAINT `FK_MEMBER_PROFILES_MST_GENDER` FOREIGN KEY (`GENDER_ID`) REFERENCES `MST_GENDER` (`GENDER_ID`) ON DELETE RESTRICT ON UPDATE RESTRICT;\n"
+    );
+}
+
+#[test]
+fn renamed_tables_keep_their_foreign_keys() {
+    let old = fixture(FIXTURES[0]);
+    let mut new = old.clone();
+    rename_table(&mut new, "MST_GENDER", "MST_GENDERS");
+
+    assert_eq!(
+        mysql::migrate(&old, &new).sql,
+        "RENAME TABLE `MST_GENDER` TO `MST_GENDERS`;\n"
+    );
+    assert_eq!(
+        postgresql::migrate(&old, &new).sql,
+        "ALTER TABLE \"MST_GENDER\" RENAME TO \"MST_GENDERS\";\n"
+    );
+}
+
+#[test]
+fn removed_and_added_tables_are_dropped_and_created() {
+    let old = fixture(FIXTURES[0]);
+    let mut new = old.clone();
+    tables_mut(&mut new).retain(|table| table.physical_name != "POST_REPLIES");
+    let mut source = old.clone();
+    let tags = table_mut(&mut source, "MST_GENDER");
+    tags.physical_name = "MST_TAGS".to_string();
+    tags.logical_name = "タグ".to_string();
+    for item in tags.columns.items.iter_mut().flatten() {
+        if let ColumnItem::Normal(column) = item
+            && column.physical_name == "GENDER"
+        {
+            column.physical_name = "TAG".to_string();
+            column.length = Some(32);
+        }
+    }
+    tables_mut(&mut new).push(tags.clone());
+
+    let sql = mysql::migrate(&old, &new).sql;
+
+    assert!(sql.starts_with("DROP TABLE `POST_REPLIES`;\n\nCREATE TABLE `MST_TAGS` (\n"));
+    assert!(!sql.contains("DROP FOREIGN KEY"));
+}
+
+#[test]
+fn index_and_unique_key_changes_are_dropped_and_created() {
+    let mut old = fixture(FIXTURES[0]);
+    table_mut(&mut old, "POSTS").indexes = Some(vec![
+        index("IDX_POSTS_TITLE", &["TITLE"]),
+        index("IDX_POSTS_PUBLIC", &["PUBLIC_START_AT"]),
+    ]);
+    let mut new = old.clone();
+    let posts = table_mut(&mut new, "POSTS");
+    posts.indexes = Some(vec![
+        index("IDX_POSTS_TITLE", &["TITLE", "TEXT"]),
+        index("IDX_POSTS_CREATED", &["CREATED_AT"]),
+    ]);
+    posts.compound_unique_key_list.compound_unique_keys = Some(vec![CompoundUniqueKey {
+        name: "UK_POSTS_MEMBER_TITLE".to_string(),
+        columns: ["MEMBER_ID", "TITLE"]
+            .into_iter()
+            .map(|column_id| UniqueKeyColumn {
+                column_id: column_id.to_string(),
+            })
+            .collect(),
+    }]);
+    column_mut(&mut new, "POST_THREADS", "POST_ID").unique_key = None;
+
+    assert_eq!(
+        mysql::migrate(&old, &new).sql,
+        "ALTER TABLE `POST_THREADS` DROP FOREIGN KEY `FK_POST_THREADS_MEMBER_POSTS`;\n\
+         \n\
+         DROP INDEX `IDX_POSTS_TITLE` ON `POSTS`;\n\
+         \n\
+         DROP INDEX `IDX_POSTS_PUBLIC` ON `POSTS`;\n\
+         \n\
+         ALTER TABLE `POST_THREADS` DROP INDEX `POST_ID`;\n\
+         \n\
+         ALTER TABLE `POSTS` ADD CONSTRAINT `UK_POSTS_MEMBER_TITLE` UNIQUE (`MEMBER_ID`, `TITLE`);\n\
+         \n\
+         CREATE INDEX `IDX_POSTS_TITLE` ON `POSTS` (`TITLE`, `TEXT`);\n\
+         \n\
+         CREATE INDEX `IDX_POSTS_CREATED` ON `POSTS` (`CREATED_AT`);\n\
+         \n\
+         ALTER TABLE `POST_THREADS` ADD CONSTRAINT `FK_POST_THREADS_MEMBER_POSTS` FOREIGN KEY (`POST_ID`) REFERENCES `POSTS` (`POST_ID`) ON DELETE RESTRICT ON UPDATE RESTRICT;\n"
+    );
+}
+
+#[test]
+fn sqlite_migration_runs_on_the_old_schema() {
+    let old = fixture(FIXTURES[0]);
+    let mut new = old.clone();
+    rename_table(&mut new, "MST_GENDER", "MST_GENDERS");
+    let items = table_mut(&mut new, "POSTS").columns.items.as_mut().unwrap();
+    items.retain(
+        |item| !matches!(item, ColumnItem::Normal(column) if column.physical_name == "IMG_URL"),
+    );
+    items.push(ColumnItem::Normal(NormalColumn {
+        physical_name: "SUMMARY".to_string(),
+        column_type: Some(ColumnType::VarCharN),
+        length: Some(255),
+        ..Default::default()
+    }));
+    table_mut(&mut new, "POSTS").indexes = Some(vec![index("IDX_POSTS_SUMMARY", &["SUMMARY"])]);
+    column_mut(&mut new, "POSTS", "TITLE").length = Some(256);
+    column_mut(&mut new, "POSTS", "TEXT").physical_name = "BODY".to_string();
+    relationship_mut(&mut new, "POSTS", "FK_MEMBER_POSTS_MEMBERS").on_delete_action =
+        Some(OnAction::Cascade);
+
+    let migration = sqlite::migrate(&old, &new);
+    let connection = Connection::open_in_memory().expect("failed to open SQLite");
+    connection
+        .execute_batch(&sqlite::generate(&old).sql)
+        .expect("failed to create the old schema");
+    connection
+        .execute_batch(&migration.sql)
+        .unwrap_or_else(|error| panic!("{error}\n\n{}", migration.sql));
+
+    assert_eq!(
+        migration.warnings,
+        vec![
+            Warning {
+                table: "POSTS".to_string(),
+                column: None,
+                kind: WarningKind::ForeignKeyChangeUnsupported {
+                    foreign_key: "FK_MEMBER_POSTS_MEMBERS".to_string(),
+                },
+            },
+            Warning {
+                table: "POSTS".to_string(),
+                column: Some("TITLE".to_string()),
+                kind: WarningKind::ColumnChangeUnsupported,
+            },
+        ]
+    );
+    assert_eq!(
+        migration.warnings[1].to_string(),
+        "POSTS.TITLE: changing the column requires rebuilding the table"
+    );
+}
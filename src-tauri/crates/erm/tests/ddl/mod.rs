@@ -0,0 +1,6 @@
+pub mod export_settings;
+pub mod migration;
+pub mod mysql;
+pub mod postgresql;
+pub mod sqlite;
+mod support;
@@ -0,0 +1,36 @@
+use erm::ddl::{Ddl, mysql};
+
+use super::support::{FIXTURES, assert_snapshot, fixture};
+
+#[test]
+fn generated_ddl_matches_snapshots() {
+    for path in FIXTURES {
+        assert_snapshot("mysql", path, &mysql::generate(&fixture(path)).sql);
+    }
+}
+
+#[test]
+fn diagram_without_tables_generates_nothing() {
+    let diagram = fixture("./tests/open/fixtures/diagram/diagram_settings.erm");
+
+    assert_eq!(mysql::generate(&diagram), Ddl::default());
+}
+
+#[test]
+fn columns_without_a_type_are_reported_as_warnings() {
+    let ddl = mysql::generate(&fixture(
+        "./tests/open/fixtures/diagram/diagram_walkers_details.erm",
+    ));
+
+    assert_eq!(
+        ddl.warnings
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>(),
+        vec![
+            "MEMBERS.MEMBER_NAME: column type is missing",
+            "PARENT_MEMBERS.PARENT_MEMBER_ID: column type is missing",
+            "PARENT_MEMBERS.PARENT_MEMBER_CODE: column type is missing",
+        ]
+    );
+}
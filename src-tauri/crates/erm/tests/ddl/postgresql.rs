@@ -0,0 +1,159 @@
+use pretty_assertions::assert_eq;
+
+use erm::column_type::ColumnType;
+use erm::ddl::{Warning, WarningKind, postgresql};
+use erm::dtos::diagram::diagram_walkers::tables::columns::ColumnItem;
+
+use super::support::{FIXTURES, assert_snapshot, fixture};
+
+const DETAILS: &str = "./tests/open/fixtures/diagram/diagram_walkers_details.erm";
+
+fn warning(table: &str, column: Option<&str>, kind: WarningKind) -> Warning {
+    Warning {
+        table: table.to_string(),
+        column: column.map(str::to_string),
+        kind,
+    }
+}
+
+#[test]
+fn generated_ddl_matches_snapshots() {
+    for path in FIXTURES {
+        assert_snapshot(
+            "postgresql",
+            path,
+            &postgresql::generate(&fixture(path)).sql,
+        );
+    }
+}
+
+#[test]
+fn unsupported_column_features_are_reported_as_warnings() {
+    let ddl = postgresql::generate(&fixture(DETAILS));
+
+    assert_eq!(
+        ddl.warnings,
+        vec![
+            warning("MEMBERS", Some("MEMBER_ID"), WarningKind::UnsignedIgnored),
+            warning(
+                "MEMBERS",
+                Some("MEMBER_ID"),
+                WarningKind::AutoIncrementUnsupported {
+                    column_type: Some(ColumnType::DecimalPS),
+                },
+            ),
+            warning(
+                "MEMBERS",
+                Some("MEMBER_NAME"),
+                WarningKind::MissingColumnType
+            ),
+            warning(
+                "MEMBERS",
+                None,
+                WarningKind::TableConstraintIgnored {
+                    constraint: "ENGINE=InnoDB".to_string(),
+                },
+            ),
+            warning(
+                "MEMBERS",
+                None,
+                WarningKind::TableOptionIgnored {
+                    option: "member option".to_string(),
+                },
+            ),
+            warning(
+                "PARENT_MEMBERS",
+                Some("PARENT_MEMBER_ID"),
+                WarningKind::MissingColumnType,
+            ),
+            warning(
+                "PARENT_MEMBERS",
+                Some("PARENT_MEMBER_CODE"),
+                WarningKind::MissingColumnType,
+            ),
+        ]
+    );
+}
+
+#[test]
+fn enum_values_are_reported_as_warnings() {
+    let mut diagram = fixture(DETAILS);
+    let table = diagram
+        .diagram_walkers
+        .as_mut()
+        .and_then(|walkers| walkers.tables.as_mut())
+        .and_then(|tables| tables.first_mut())
+        .expect("fixture has tables");
+    let Some(ColumnItem::Normal(column)) = table
+        .columns
+        .items
+        .as_mut()
+        .and_then(|items| items.get_mut(1))
+    else {
+        panic!("fixture has MEMBER_NAME");
+    };
+    column.column_type = Some(ColumnType::Enum);
+    column.args = Some("'active','retired'".to_string());
+
+    let ddl = postgresql::generate(&diagram);
+
+    assert!(ddl.sql.contains("  \"MEMBER_NAME\" text,\n"));
+    assert!(ddl.warnings.contains(&warning(
+        "MEMBERS",
+        Some("MEMBER_NAME"),
+        WarningKind::ArgsIgnored {
+            column_type: ColumnType::Enum,
+            args: "'active','retired'".to_string(),
+        },
+    )));
+    assert_eq!(
+        ddl.warnings
+            .iter()
+            .find(|warning| matches!(warning.kind, WarningKind::ArgsIgnored { .. }))
+            .map(ToString::to_string)
+            .as_deref(),
+        Some("MEMBERS.MEMBER_NAME: enum values are not supported: 'active','retired'")
+    );
+}
+
+#[test]
+fn geometry_columns_are_reported_as_needing_postgis() {
+    let mut diagram = fixture(DETAILS);
+    let table = diagram
+        .diagram_walkers
+        .as_mut()
+        .and_then(|walkers| walkers.tables.as_mut())
+        .and_then(|tables| tables.first_mut())
+        .expect("fixture has tables");
+    let Some(ColumnItem::Normal(column)) = table
+        .columns
+        .items
+        .as_mut()
+        .and_then(|items| items.get_mut(1))
+    else {
+        panic!("fixture has MEMBER_NAME");
+    };
+    column.column_type = Some(ColumnType::Geometry);
+
+    let ddl = postgresql::generate(&diagram);
+
+    assert!(ddl.sql.contains("  \"MEMBER_NAME\" geometry,\n"));
+    assert_eq!(
+        ddl.warnings
+            .iter()
+            .find(|warning| matches!(warning.kind, WarningKind::ExtensionRequired { .. }))
+            .map(ToString::to_string)
+            .as_deref(),
+        Some("MEMBERS.MEMBER_NAME: column type requires the PostGIS extension")
+    );
+}
+
+#[test]
+fn identity_replaces_auto_increment_on_integer_columns() {
+    let ddl = postgresql::generate(&fixture(FIXTURES[0]));
+
+    assert!(
+        ddl.sql
+            .contains("  \"MEMBER_ID\" bigint NOT NULL GENERATED BY DEFAULT AS IDENTITY,\n")
+    );
+}
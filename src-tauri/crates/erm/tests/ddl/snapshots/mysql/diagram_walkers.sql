@@ -0,0 +1,8 @@
+CREATE TABLE `MEMBERS` (
+  `MEMBER_ID` NOT NULL,
+  CONSTRAINT `PK_MEMBERS` PRIMARY KEY (`MEMBER_ID`),
+  ENGINE=InnoDB
+) member option COMMENT = 'Members: Member master table';
+
+CREATE TABLE `MEMBER_STATUS` (
+) COMMENT = 'Member Status: Status master table';
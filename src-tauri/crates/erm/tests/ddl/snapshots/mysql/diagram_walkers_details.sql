@@ -0,0 +1,18 @@
+CREATE TABLE `MEMBERS` (
+  `MEMBER_ID` decimal(18,0) UNSIGNED NOT NULL DEFAULT 0 AUTO_INCREMENT UNIQUE COMMENT 'Member ID: Surrogate key',
+  `MEMBER_NAME`,
+  `CREATED_AT` datetime,
+  CONSTRAINT `PK_MEMBERS` PRIMARY KEY (`MEMBER_ID`),
+  CONSTRAINT `UK_MEMBERS_NAME` UNIQUE (`MEMBER_NAME`, `MEMBER_ID`),
+  ENGINE=InnoDB
+) member option COMMENT = 'Members: Member master table';
+
+CREATE TABLE `PARENT_MEMBERS` (
+  `PARENT_MEMBER_ID`,
+  `PARENT_MEMBER_CODE` UNIQUE,
+  CONSTRAINT `UK_PARENT_MEMBERS_CODE` UNIQUE (`PARENT_MEMBER_CODE`)
+) COMMENT = 'Parent Members: Parent member table';
+
+CREATE INDEX `IDX_MEMBERS_NAME` ON `MEMBERS` (`MEMBER_NAME` DESC, `MEMBER_ID`) USING BTREE COMMENT 'Name lookup';
+
+ALTER TABLE `MEMBERS` ADD CONSTRAINT `FK_MEMBERS_PARENT` FOREIGN KEY (`MEMBER_ID`) REFERENCES `PARENT_MEMBERS` (`PARENT_MEMBER_CODE`) ON DELETE CASCADE ON UPDATE RESTRICT;
@@ -0,0 +1,83 @@
+CREATE TABLE `MEMBERS` (
+  `MEMBER_ID` bigint UNSIGNED NOT NULL AUTO_INCREMENT COMMENT '会員ID',
+  `LAST_NAME` varchar(32) NOT NULL COMMENT '苗字',
+  `FIRST_NAME` varchar(32) NOT NULL COMMENT '名前',
+  `CREATED_AT` datetime NOT NULL COMMENT '作成時間',
+  `CREATED_BY` bigint NOT NULL COMMENT '作成会員ID',
+  `UPDATED_AT` datetime NOT NULL COMMENT '更新時間',
+  `UPDATED_BY` bigint NOT NULL COMMENT '更新会員ID',
+  PRIMARY KEY (`MEMBER_ID`)
+) COMMENT = '会員';
+
+CREATE TABLE `MEMBER_PROFILES` (
+  `MEMBER_PROFILE_ID` bigint UNSIGNED NOT NULL AUTO_INCREMENT COMMENT '会員プロフィールID',
+  `MEMBER_ID` bigint UNSIGNED NOT NULL,
+  `SELF_INTRODUCTION` text NOT NULL COMMENT '自己紹介',
+  `PROFILE_IMG_URL` varchar(2048) NOT NULL COMMENT 'プロフィール画像URL',
+  `GENDER_ID` int UNSIGNED,
+  `CREATED_AT` datetime NOT NULL COMMENT '作成時間',
+  `CREATED_BY` bigint NOT NULL COMMENT '作成会員ID',
+  `UPDATED_AT` datetime NOT NULL COMMENT '更新時間',
+  `UPDATED_BY` bigint NOT NULL COMMENT '更新会員ID',
+  PRIMARY KEY (`MEMBER_PROFILE_ID`)
+) COMMENT = '会員プロフィール';
+
+CREATE TABLE `MST_GENDER` (
+  `GENDER_ID` int UNSIGNED NOT NULL AUTO_INCREMENT COMMENT '性別ID',
+  `GENDER` char(2) NOT NULL COMMENT '性別: 「男性」または「女性」',
+  PRIMARY KEY (`GENDER_ID`)
+) COMMENT = 'マスター性別';
+
+CREATE TABLE `POSTS` (
+  `POST_ID` bigint UNSIGNED NOT NULL AUTO_INCREMENT COMMENT '投稿ID',
+  `MEMBER_ID` bigint UNSIGNED NOT NULL,
+  `TITLE` varchar(128) NOT NULL COMMENT 'タイトル',
+  `TEXT` text COMMENT '本文',
+  `IMG_URL` varchar(2048) COMMENT '画像URL',
+  `VIEW_COUNT` bigint NOT NULL DEFAULT 0 COMMENT '閲覧数',
+  `LIKE_COUNT` bigint NOT NULL DEFAULT 0 COMMENT 'いいね数',
+  `PUBLIC_START_AT` datetime NOT NULL COMMENT '公開開始時間',
+  `PUBLIC_END_AT` datetime COMMENT '公開終了時間',
+  `DELETED` boolean NOT NULL DEFAULT FALSE COMMENT '削除済',
+  `CREATED_AT` datetime NOT NULL COMMENT '作成時間',
+  `CREATED_BY` bigint NOT NULL COMMENT '作成会員ID',
+  `UPDATED_AT` datetime NOT NULL COMMENT '更新時間',
+  `UPDATED_BY` bigint NOT NULL COMMENT '更新会員ID',
+  PRIMARY KEY (`POST_ID`)
+) COMMENT = '投稿';
+
+CREATE TABLE `POST_REPLIES` (
+  `POST_REPLY_ID` bigint UNSIGNED NOT NULL AUTO_INCREMENT COMMENT '投稿返信ID',
+  `POST_THREAD_ID` bigint UNSIGNED NOT NULL,
+  `MEMBER_ID` bigint UNSIGNED NOT NULL,
+  `TEXT` text NOT NULL COMMENT '本文',
+  `VIEW_COUNT` bigint NOT NULL DEFAULT 0 COMMENT '閲覧数',
+  `LIKE_COUNT` bigint NOT NULL DEFAULT 0 COMMENT 'いいね数',
+  `CREATED_AT` datetime NOT NULL COMMENT '作成時間',
+  `CREATED_BY` bigint NOT NULL COMMENT '作成会員ID',
+  `UPDATED_AT` datetime NOT NULL COMMENT '更新時間',
+  `UPDATED_BY` bigint NOT NULL COMMENT '更新会員ID',
+  PRIMARY KEY (`POST_REPLY_ID`)
+) COMMENT = '投稿返信';
+
+CREATE TABLE `POST_THREADS` (
+  `POST_THREAD_ID` bigint UNSIGNED NOT NULL AUTO_INCREMENT COMMENT '投稿スレッドID',
+  `POST_ID` bigint UNSIGNED NOT NULL UNIQUE,
+  `CREATED_AT` datetime NOT NULL COMMENT '作成時間',
+  `CREATED_BY` bigint NOT NULL COMMENT '作成会員ID',
+  `UPDATED_AT` datetime NOT NULL COMMENT '更新時間',
+  `UPDATED_BY` bigint NOT NULL COMMENT '更新会員ID',
+  PRIMARY KEY (`POST_THREAD_ID`)
+) COMMENT = '投稿スレッド';
+
+ALTER TABLE `MEMBER_PROFILES` ADD CONSTRAINT `FK_MEMBER_PROFILES_MEMBERS` FOREIGN KEY (`MEMBER_ID`) REFERENCES `MEMBERS` (`MEMBER_ID`) ON DELETE RESTRICT ON UPDATE RESTRICT;
+
+ALTER TABLE `MEMBER_PROFILES` ADD CONSTRAINT `FK_MEMBER_PROFILES_MST_GENDER` FOREIGN KEY (`GENDER_ID`) REFERENCES `MST_GENDER` (`GENDER_ID`) ON DELETE RESTRICT ON UPDATE RESTRICT;
+
+ALTER TABLE `POSTS` ADD CONSTRAINT `FK_MEMBER_POSTS_MEMBERS` FOREIGN KEY (`MEMBER_ID`) REFERENCES `MEMBERS` (`MEMBER_ID`) ON DELETE RESTRICT ON UPDATE RESTRICT;
+
+ALTER TABLE `POST_REPLIES` ADD CONSTRAINT `FK_POST_REPLIES_POST_THREADS` FOREIGN KEY (`POST_THREAD_ID`) REFERENCES `POST_THREADS` (`POST_THREAD_ID`) ON DELETE RESTRICT ON UPDATE RESTRICT;
+
+ALTER TABLE `POST_REPLIES` ADD CONSTRAINT `FK_POST_REPLIES_MEMBERS` FOREIGN KEY (`MEMBER_ID`) REFERENCES `MEMBERS` (`MEMBER_ID`) ON DELETE RESTRICT ON UPDATE RESTRICT;
+
+ALTER TABLE `POST_THREADS` ADD CONSTRAINT `FK_POST_THREADS_MEMBER_POSTS` FOREIGN KEY (`POST_ID`) REFERENCES `POSTS` (`POST_ID`) ON DELETE RESTRICT ON UPDATE RESTRICT;
@@ -0,0 +1,10 @@
+CREATE TABLE `MEMBERS` (
+  `MEMBER_ID` int NOT NULL COMMENT 'Member ID',
+  `MEMBER_NAME` varchar(100) NOT NULL COMMENT 'Member Name',
+  PRIMARY KEY (`MEMBER_ID`)
+) COMMENT = 'Members: Member master table';
+
+CREATE VIEW `MEMBER_NAMES` AS
+SELECT MEMBER_ID, MEMBER_NAME
+FROM MEMBERS
+ORDER BY MEMBER_NAME;
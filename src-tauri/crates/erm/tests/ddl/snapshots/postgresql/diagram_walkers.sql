@@ -0,0 +1,11 @@
+CREATE TABLE "MEMBERS" (
+  "MEMBER_ID" NOT NULL,
+  CONSTRAINT "PK_MEMBERS" PRIMARY KEY ("MEMBER_ID")
+);
+
+COMMENT ON TABLE "MEMBERS" IS 'Members: Member master table';
+
+CREATE TABLE "MEMBER_STATUS" (
+);
+
+COMMENT ON TABLE "MEMBER_STATUS" IS 'Member Status: Status master table';
@@ -0,0 +1,25 @@
+CREATE TABLE "MEMBERS" (
+  "MEMBER_ID" decimal(18,0) NOT NULL DEFAULT 0 UNIQUE,
+  "MEMBER_NAME",
+  "CREATED_AT" timestamp,
+  CONSTRAINT "PK_MEMBERS" PRIMARY KEY ("MEMBER_ID"),
+  CONSTRAINT "UK_MEMBERS_NAME" UNIQUE ("MEMBER_NAME", "MEMBER_ID")
+);
+
+COMMENT ON TABLE "MEMBERS" IS 'Members: Member master table';
+
+COMMENT ON COLUMN "MEMBERS"."MEMBER_ID" IS 'Member ID: Surrogate key';
+
+CREATE TABLE "PARENT_MEMBERS" (
+  "PARENT_MEMBER_ID",
+  "PARENT_MEMBER_CODE" UNIQUE,
+  CONSTRAINT "UK_PARENT_MEMBERS_CODE" UNIQUE ("PARENT_MEMBER_CODE")
+);
+
+COMMENT ON TABLE "PARENT_MEMBERS" IS 'Parent Members: Parent member table';
+
+CREATE INDEX "IDX_MEMBERS_NAME" ON "MEMBERS" USING btree ("MEMBER_NAME" DESC, "MEMBER_ID");
+
+COMMENT ON INDEX "IDX_MEMBERS_NAME" IS 'Name lookup';
+
+ALTER TABLE "MEMBERS" ADD CONSTRAINT "FK_MEMBERS_PARENT" FOREIGN KEY ("MEMBER_ID") REFERENCES "PARENT_MEMBERS" ("PARENT_MEMBER_CODE") ON DELETE CASCADE ON UPDATE RESTRICT;
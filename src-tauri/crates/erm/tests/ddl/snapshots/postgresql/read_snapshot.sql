@@ -0,0 +1,179 @@
+CREATE TABLE "MEMBERS" (
+  "MEMBER_ID" bigint NOT NULL GENERATED BY DEFAULT AS IDENTITY,
+  "LAST_NAME" varchar(32) NOT NULL,
+  "FIRST_NAME" varchar(32) NOT NULL,
+  "CREATED_AT" timestamp NOT NULL,
+  "CREATED_BY" bigint NOT NULL,
+  "UPDATED_AT" timestamp NOT NULL,
+  "UPDATED_BY" bigint NOT NULL,
+  PRIMARY KEY ("MEMBER_ID")
+);
+
+COMMENT ON TABLE "MEMBERS" IS '会員';
+
+COMMENT ON COLUMN "MEMBERS"."MEMBER_ID" IS '会員ID';
+
+COMMENT ON COLUMN "MEMBERS"."LAST_NAME" IS '苗字';
+
+COMMENT ON COLUMN "MEMBERS"."FIRST_NAME" IS '名前';
+
+COMMENT ON COLUMN "MEMBERS"."CREATED_AT" IS '作成時間';
+
+COMMENT ON COLUMN "MEMBERS"."CREATED_BY" IS '作成会員ID';
+
+COMMENT ON COLUMN "MEMBERS"."UPDATED_AT" IS '更新時間';
+
+COMMENT ON COLUMN "MEMBERS"."UPDATED_BY" IS '更新会員ID';
+
+CREATE TABLE "MEMBER_PROFILES" (
+  "MEMBER_PROFILE_ID" bigint NOT NULL GENERATED BY DEFAULT AS IDENTITY,
+  "MEMBER_ID" bigint NOT NULL,
+  "SELF_INTRODUCTION" text NOT NULL,
+  "PROFILE_IMG_URL" varchar(2048) NOT NULL,
+  "GENDER_ID" integer,
+  "CREATED_AT" timestamp NOT NULL,
+  "CREATED_BY" bigint NOT NULL,
+  "UPDATED_AT" timestamp NOT NULL,
+  "UPDATED_BY" bigint NOT NULL,
+  PRIMARY KEY ("MEMBER_PROFILE_ID")
+);
+
+COMMENT ON TABLE "MEMBER_PROFILES" IS '会員プロフィール';
+
+COMMENT ON COLUMN "MEMBER_PROFILES"."MEMBER_PROFILE_ID" IS '会員プロフィールID';
+
+COMMENT ON COLUMN "MEMBER_PROFILES"."SELF_INTRODUCTION" IS '自己紹介';
+
+COMMENT ON COLUMN "MEMBER_PROFILES"."PROFILE_IMG_URL" IS 'プロフィール画像URL';
+
+COMMENT ON COLUMN "MEMBER_PROFILES"."CREATED_AT" IS '作成時間';
+
+COMMENT ON COLUMN "MEMBER_PROFILES"."CREATED_BY" IS '作成会員ID';
+
+COMMENT ON COLUMN "MEMBER_PROFILES"."UPDATED_AT" IS '更新時間';
+
+COMMENT ON COLUMN "MEMBER_PROFILES"."UPDATED_BY" IS '更新会員ID';
+
+CREATE TABLE "MST_GENDER" (
+  "GENDER_ID" integer NOT NULL GENERATED BY DEFAULT AS IDENTITY,
+  "GENDER" char(2) NOT NULL,
+  PRIMARY KEY ("GENDER_ID")
+);
+
+COMMENT ON TABLE "MST_GENDER" IS 'マスター性別';
+
+COMMENT ON COLUMN "MST_GENDER"."GENDER_ID" IS '性別ID';
+
+COMMENT ON COLUMN "MST_GENDER"."GENDER" IS '性別: 「男性」または「女性」';
+
+CREATE TABLE "POSTS" (
+  "POST_ID" bigint NOT NULL GENERATED BY DEFAULT AS IDENTITY,
+  "MEMBER_ID" bigint NOT NULL,
+  "TITLE" varchar(128) NOT NULL,
+  "TEXT" text,
+  "IMG_URL" varchar(2048),
+  "VIEW_COUNT" bigint NOT NULL DEFAULT 0,
+  "LIKE_COUNT" bigint NOT NULL DEFAULT 0,
+  "PUBLIC_START_AT" timestamp NOT NULL,
+  "PUBLIC_END_AT" timestamp,
+  "DELETED" boolean NOT NULL DEFAULT FALSE,
+  "CREATED_AT" timestamp NOT NULL,
+  "CREATED_BY" bigint NOT NULL,
+  "UPDATED_AT" timestamp NOT NULL,
+  "UPDATED_BY" bigint NOT NULL,
+  PRIMARY KEY ("POST_ID")
+);
+
+COMMENT ON TABLE "POSTS" IS '投稿';
+
+COMMENT ON COLUMN "POSTS"."POST_ID" IS '投稿ID';
+
+COMMENT ON COLUMN "POSTS"."TITLE" IS 'タイトル';
+
+COMMENT ON COLUMN "POSTS"."TEXT" IS '本文';
+
+COMMENT ON COLUMN "POSTS"."IMG_URL" IS '画像URL';
+
+COMMENT ON COLUMN "POSTS"."VIEW_COUNT" IS '閲覧数';
+
+COMMENT ON COLUMN "POSTS"."LIKE_COUNT" IS 'いいね数';
+
+COMMENT ON COLUMN "POSTS"."PUBLIC_START_AT" IS '公開開始時間';
+
+COMMENT ON COLUMN "POSTS"."PUBLIC_END_AT" IS '公開終了時間';
+
+COMMENT ON COLUMN "POSTS"."DELETED" IS '削除済';
+
+COMMENT ON COLUMN "POSTS"."CREATED_AT" IS '作成時間';
+
+COMMENT ON COLUMN "POSTS"."CREATED_BY" IS '作成会員ID';
+
+COMMENT ON COLUMN "POSTS"."UPDATED_AT" IS '更新時間';
+
+COMMENT ON COLUMN "POSTS"."UPDATED_BY" IS '更新会員ID';
+
+CREATE TABLE "POST_REPLIES" (
+  "POST_REPLY_ID" bigint NOT NULL GENERATED BY DEFAULT AS IDENTITY,
+  "POST_THREAD_ID" bigint NOT NULL,
+  "MEMBER_ID" bigint NOT NULL,
+  "TEXT" text NOT NULL,
+  "VIEW_COUNT" bigint NOT NULL DEFAULT 0,
+  "LIKE_COUNT" bigint NOT NULL DEFAULT 0,
+  "CREATED_AT" timestamp NOT NULL,
+  "CREATED_BY" bigint NOT NULL,
+  "UPDATED_AT" timestamp NOT NULL,
+  "UPDATED_BY" bigint NOT NULL,
+  PRIMARY KEY ("POST_REPLY_ID")
+);
+
+COMMENT ON TABLE "POST_REPLIES" IS '投稿返信';
+
+COMMENT ON COLUMN "POST_REPLIES"."POST_REPLY_ID" IS '投稿返信ID';
+
+COMMENT ON COLUMN "POST_REPLIES"."TEXT" IS '本文';
+
+COMMENT ON COLUMN "POST_REPLIES"."VIEW_COUNT" IS '閲覧数';
+
+COMMENT ON COLUMN "POST_REPLIES"."LIKE_COUNT" IS 'いいね数';
+
+COMMENT ON COLUMN "POST_REPLIES"."CREATED_AT" IS '作成時間';
+
+COMMENT ON COLUMN "POST_REPLIES"."CREATED_BY" IS '作成会員ID';
+
+COMMENT ON COLUMN "POST_REPLIES"."UPDATED_AT" IS '更新時間';
+
+COMMENT ON COLUMN "POST_REPLIES"."UPDATED_BY" IS '更新会員ID';
+
+CREATE TABLE "POST_THREADS" (
+  "POST_THREAD_ID" bigint NOT NULL GENERATED BY DEFAULT AS IDENTITY,
+  "POST_ID" bigint NOT NULL UNIQUE,
+  "CREATED_AT" timestamp NOT NULL,
+  "CREATED_BY" bigint NOT NULL,
+  "UPDATED_AT" timestamp NOT NULL,
+  "UPDATED_BY" bigint NOT NULL,
+  PRIMARY KEY ("POST_THREAD_ID")
+);
+
+COMMENT ON TABLE "POST_THREADS" IS '投稿スレッド';
+
+COMMENT ON COLUMN "POST_THREADS"."POST_THREAD_ID" IS '投稿スレッドID';
+
+COMMENT ON COLUMN "POST_THREADS"."CREATED_AT" IS '作成時間';
+
+COMMENT ON COLUMN "POST_THREADS"."CREATED_BY" IS '作成会員ID';
+
+COMMENT ON COLUMN "POST_THREADS"."UPDATED_AT" IS '更新時間';
+
+COMMENT ON COLUMN "POST_THREADS"."UPDATED_BY" IS '更新会員ID';
+
+ALTER TABLE "MEMBER_PROFILES" ADD CONSTRAINT "FK_MEMBER_PROFILES_MEMBERS" FOREIGN KEY ("MEMBER_ID") REFERENCES "MEMBERS" ("MEMBER_ID") ON DELETE RESTRICT ON UPDATE RESTRICT;
+
+ALTER TABLE "MEMBER_PROFILES" ADD CONSTRAINT "FK_MEMBER_PROFILES_MST_GENDER" FOREIGN KEY ("GENDER_ID") REFERENCES "MST_GENDER" ("GENDER_ID") ON DELETE RESTRICT ON UPDATE RESTRICT;
+
+ALTER TABLE "POSTS" ADD CONSTRAINT "FK_MEMBER_POSTS_MEMBERS" FOREIGN KEY ("MEMBER_ID") REFERENCES "MEMBERS" ("MEMBER_ID") ON DELETE RESTRICT ON UPDATE RESTRICT;
+
+ALTER TABLE "POST_REPLIES" ADD CONSTRAINT "FK_POST_REPLIES_POST_THREADS" FOREIGN KEY ("POST_THREAD_ID") REFERENCES "POST_THREADS" ("POST_THREAD_ID") ON DELETE RESTRICT ON UPDATE RESTRICT;
+
+ALTER TABLE "POST_REPLIES" ADD CONSTRAINT "FK_POST_REPLIES_MEMBERS" FOREIGN KEY ("MEMBER_ID") REFERENCES "MEMBERS" ("MEMBER_ID") ON DELETE RESTRICT ON UPDATE RESTRICT;
+
+ALTER TABLE "POST_THREADS" ADD CONSTRAINT "FK_POST_THREADS_MEMBER_POSTS" FOREIGN KEY ("POST_ID") REFERENCES "POSTS" ("POST_ID") ON DELETE RESTRICT ON UPDATE RESTRICT;
@@ -0,0 +1,18 @@
+CREATE TABLE "MEMBERS" (
+  "MEMBER_ID" integer NOT NULL,
+  "MEMBER_NAME" varchar(100) NOT NULL,
+  PRIMARY KEY ("MEMBER_ID")
+);
+
+COMMENT ON TABLE "MEMBERS" IS 'Members: Member master table';
+
+COMMENT ON COLUMN "MEMBERS"."MEMBER_ID" IS 'Member ID';
+
+COMMENT ON COLUMN "MEMBERS"."MEMBER_NAME" IS 'Member Name';
+
+CREATE VIEW "MEMBER_NAMES" AS
+SELECT MEMBER_ID, MEMBER_NAME
+FROM MEMBERS
+ORDER BY MEMBER_NAME;
+
+COMMENT ON VIEW "MEMBER_NAMES" IS 'Member Names: Members listed by name';
@@ -0,0 +1,4 @@
+CREATE TABLE "MEMBERS" (
+  "MEMBER_ID" NOT NULL,
+  CONSTRAINT "PK_MEMBERS" PRIMARY KEY ("MEMBER_ID")
+);
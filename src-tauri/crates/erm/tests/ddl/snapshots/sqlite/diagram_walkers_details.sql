@@ -0,0 +1,16 @@
+CREATE TABLE "MEMBERS" (
+  "MEMBER_ID" NUMERIC NOT NULL DEFAULT 0 UNIQUE,
+  "MEMBER_NAME",
+  "CREATED_AT" NUMERIC,
+  CONSTRAINT "PK_MEMBERS" PRIMARY KEY ("MEMBER_ID"),
+  CONSTRAINT "UK_MEMBERS_NAME" UNIQUE ("MEMBER_NAME", "MEMBER_ID"),
+  CONSTRAINT "FK_MEMBERS_PARENT" FOREIGN KEY ("MEMBER_ID") REFERENCES "PARENT_MEMBERS" ("PARENT_MEMBER_CODE") ON DELETE CASCADE ON UPDATE RESTRICT
+);
+
+CREATE TABLE "PARENT_MEMBERS" (
+  "PARENT_MEMBER_ID",
+  "PARENT_MEMBER_CODE" UNIQUE,
+  CONSTRAINT "UK_PARENT_MEMBERS_CODE" UNIQUE ("PARENT_MEMBER_CODE")
+);
+
+CREATE INDEX "IDX_MEMBERS_NAME" ON "MEMBERS" ("MEMBER_NAME" DESC, "MEMBER_ID");
@@ -0,0 +1,71 @@
+CREATE TABLE "MEMBERS" (
+  "MEMBER_ID" INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+  "LAST_NAME" TEXT NOT NULL,
+  "FIRST_NAME" TEXT NOT NULL,
+  "CREATED_AT" NUMERIC NOT NULL,
+  "CREATED_BY" INTEGER NOT NULL,
+  "UPDATED_AT" NUMERIC NOT NULL,
+  "UPDATED_BY" INTEGER NOT NULL
+);
+
+CREATE TABLE "MEMBER_PROFILES" (
+  "MEMBER_PROFILE_ID" INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+  "MEMBER_ID" INTEGER NOT NULL,
+  "SELF_INTRODUCTION" TEXT NOT NULL,
+  "PROFILE_IMG_URL" TEXT NOT NULL,
+  "GENDER_ID" INTEGER,
+  "CREATED_AT" NUMERIC NOT NULL,
+  "CREATED_BY" INTEGER NOT NULL,
+  "UPDATED_AT" NUMERIC NOT NULL,
+  "UPDATED_BY" INTEGER NOT NULL,
+  CONSTRAINT "FK_MEMBER_PROFILES_MEMBERS" FOREIGN KEY ("MEMBER_ID") REFERENCES "MEMBERS" ("MEMBER_ID") ON DELETE RESTRICT ON UPDATE RESTRICT,
+  CONSTRAINT "FK_MEMBER_PROFILES_MST_GENDER" FOREIGN KEY ("GENDER_ID") REFERENCES "MST_GENDER" ("GENDER_ID") ON DELETE RESTRICT ON UPDATE RESTRICT
+);
+
+CREATE TABLE "MST_GENDER" (
+  "GENDER_ID" INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+  "GENDER" TEXT NOT NULL
+);
+
+CREATE TABLE "POSTS" (
+  "POST_ID" INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+  "MEMBER_ID" INTEGER NOT NULL,
+  "TITLE" TEXT NOT NULL,
+  "TEXT" TEXT,
+  "IMG_URL" TEXT,
+  "VIEW_COUNT" INTEGER NOT NULL DEFAULT 0,
+  "LIKE_COUNT" INTEGER NOT NULL DEFAULT 0,
+  "PUBLIC_START_AT" NUMERIC NOT NULL,
+  "PUBLIC_END_AT" NUMERIC,
+  "DELETED" INTEGER NOT NULL DEFAULT FALSE,
+  "CREATED_AT" NUMERIC NOT NULL,
+  "CREATED_BY" INTEGER NOT NULL,
+  "UPDATED_AT" NUMERIC NOT NULL,
+  "UPDATED_BY" INTEGER NOT NULL,
+  CONSTRAINT "FK_MEMBER_POSTS_MEMBERS" FOREIGN KEY ("MEMBER_ID") REFERENCES "MEMBERS" ("MEMBER_ID") ON DELETE RESTRICT ON UPDATE RESTRICT
+);
+
+CREATE TABLE "POST_REPLIES" (
+  "POST_REPLY_ID" INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+  "POST_THREAD_ID" INTEGER NOT NULL,
+  "MEMBER_ID" INTEGER NOT NULL,
+  "TEXT" TEXT NOT NULL,
+  "VIEW_COUNT" INTEGER NOT NULL DEFAULT 0,
+  "LIKE_COUNT" INTEGER NOT NULL DEFAULT 0,
+  "CREATED_AT" NUMERIC NOT NULL,
+  "CREATED_BY" INTEGER NOT NULL,
+  "UPDATED_AT" NUMERIC NOT NULL,
+  "UPDATED_BY" INTEGER NOT NULL,
+  CONSTRAINT "FK_POST_REPLIES_POST_THREADS" FOREIGN KEY ("POST_THREAD_ID") REFERENCES "POST_THREADS" ("POST_THREAD_ID") ON DELETE RESTRICT ON UPDATE RESTRICT,
+  CONSTRAINT "FK_POST_REPLIES_MEMBERS" FOREIGN KEY ("MEMBER_ID") REFERENCES "MEMBERS" ("MEMBER_ID") ON DELETE RESTRICT ON UPDATE RESTRICT
+);
+
+CREATE TABLE "POST_THREADS" (
+  "POST_THREAD_ID" INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+  "POST_ID" INTEGER NOT NULL UNIQUE,
+  "CREATED_AT" NUMERIC NOT NULL,
+  "CREATED_BY" INTEGER NOT NULL,
+  "UPDATED_AT" NUMERIC NOT NULL,
+  "UPDATED_BY" INTEGER NOT NULL,
+  CONSTRAINT "FK_POST_THREADS_MEMBER_POSTS" FOREIGN KEY ("POST_ID") REFERENCES "POSTS" ("POST_ID") ON DELETE RESTRICT ON UPDATE RESTRICT
+);
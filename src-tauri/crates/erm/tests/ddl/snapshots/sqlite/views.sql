@@ -0,0 +1,10 @@
+CREATE TABLE "MEMBERS" (
+  "MEMBER_ID" INTEGER NOT NULL,
+  "MEMBER_NAME" TEXT NOT NULL,
+  PRIMARY KEY ("MEMBER_ID")
+);
+
+CREATE VIEW "MEMBER_NAMES" AS
+SELECT MEMBER_ID, MEMBER_NAME
+FROM MEMBERS
+ORDER BY MEMBER_NAME;
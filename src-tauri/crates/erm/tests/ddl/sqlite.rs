@@ -0,0 +1,113 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use pretty_assertions::assert_eq;
+use rusqlite::Connection;
+
+use erm::ddl::sqlite;
+
+use super::support::{FIXTURES, assert_snapshot, fixture};
+
+fn fixture_paths(directory: &Path, paths: &mut Vec<PathBuf>) {
+    for entry in fs::read_dir(directory).expect("failed to read directory") {
+        let path = entry.expect("failed to read directory entry").path();
+        if path.is_dir() {
+            fixture_paths(&path, paths);
+        } else if path.extension().is_some_and(|extension| extension == "erm") {
+            paths.push(path);
+        }
+    }
+}
+
+fn table_names(connection: &Connection) -> Vec<String> {
+    let mut statement = connection
+        .prepare("SELECT name FROM sqlite_master WHERE type = 'table' AND name NOT LIKE 'sqlite_%' ORDER BY name")
+        .expect("failed to prepare query");
+
+    statement
+        .query_map([], |row| row.get(0))
+        .expect("failed to query tables")
+        .collect::<Result<_, _>>()
+        .expect("failed to read table name")
+}
+
+#[test]
+fn generated_ddl_matches_snapshots() {
+    for path in FIXTURES {
+        assert_snapshot("sqlite", path, &sqlite::generate(&fixture(path)).sql);
+    }
+}
+
+#[test]
+fn generated_ddl_runs_on_sqlite_for_every_fixture() {
+    let mut paths = Vec::new();
+    fixture_paths(Path::new("./tests"), &mut paths);
+    paths.sort();
+    assert!(!paths.is_empty());
+
+    for path in paths {
+        let path = path.to_str().expect("fixture path is UTF-8");
+        let ddl = sqlite::generate(&fixture(path));
+        let connection = Connection::open_in_memory().expect("failed to open SQLite");
+
+        connection
+            .execute_batch(&format!("PRAGMA foreign_keys = ON;\n{}", ddl.sql))
+            .unwrap_or_else(|error| panic!("DDL for {path} failed: {error}\n\n{}", ddl.sql));
+    }
+}
+
+#[test]
+fn foreign_keys_are_enforced_between_generated_tables() {
+    let ddl = sqlite::generate(&fixture(FIXTURES[0]));
+    let connection = Connection::open_in_memory().expect("failed to open SQLite");
+    connection
+        .execute_batch(&format!("PRAGMA foreign_keys = ON;\n{}", ddl.sql))
+        .expect("failed to run DDL");
+
+    assert_eq!(
+        table_names(&connection),
+        vec![
+            "MEMBERS",
+            "MEMBER_PROFILES",
+            "MST_GENDER",
+            "POSTS",
+            "POST_REPLIES",
+            "POST_THREADS",
+        ]
+    );
+    let result = connection.execute(
+        "INSERT INTO POSTS (MEMBER_ID, TITLE, VIEW_COUNT, LIKE_COUNT, PUBLIC_START_AT, DELETED, \
+         CREATED_AT, CREATED_BY, UPDATED_AT, UPDATED_BY) \
+         VALUES (1, 'title', 0, 0, '2024-01-01', 0, '2024-01-01', 1, '2024-01-01', 1)",
+        [],
+    );
+    assert!(result.is_err(), "a post without a member must be rejected");
+}
+
+#[test]
+fn sqlite_incompatible_table_settings_are_reported_as_warnings() {
+    let ddl = sqlite::generate(&fixture(FIXTURES[1]));
+
+    assert_eq!(
+        ddl.warnings
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>(),
+        vec![
+            "MEMBER_STATUS: table without columns is not supported",
+            "MEMBERS.MEMBER_ID: column type is missing",
+            "MEMBERS: table constraint is not supported: ENGINE=InnoDB",
+            "MEMBERS: table option is not supported: member option",
+        ]
+    );
+}
+
+#[test]
+fn auto_increment_primary_keys_become_integer_primary_keys() {
+    let ddl = sqlite::generate(&fixture(FIXTURES[0]));
+
+    assert!(
+        ddl.sql
+            .contains("  \"MEMBER_ID\" INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,\n")
+    );
+}
@@ -0,0 +1,37 @@
+use std::fs;
+use std::path::Path;
+
+use erm::dtos::diagram::Diagram;
+use erm::open;
+use pretty_assertions::assert_eq;
+
+pub(crate) const FIXTURES: [&str; 4] = [
+    "./tests/open/fixtures/read_snapshot.erm",
+    "./tests/open/fixtures/diagram/diagram_walkers.erm",
+    "./tests/open/fixtures/diagram/diagram_walkers_details.erm",
+    "./tests/open/fixtures/diagram/views.erm",
+];
+
+pub(crate) fn fixture(path: &str) -> Diagram {
+    open(path).expect("failed to parse fixture").diagram
+}
+
+/// Compares generated output with the snapshot stored next to the tests.
+///
+/// Set `UPDATE_SNAPSHOTS=1` to rewrite the snapshots from the current output.
+pub(crate) fn assert_snapshot(dialect: &str, fixture_path: &str, actual: &str) {
+    let name = Path::new(fixture_path)
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .expect("fixture has a file name");
+    let snapshot_path = format!("./tests/ddl/snapshots/{dialect}/{name}.sql");
+
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        fs::write(&snapshot_path, actual).expect("failed to write snapshot");
+        return;
+    }
+
+    let expected = fs::read_to_string(&snapshot_path)
+        .unwrap_or_else(|error| panic!("failed to read {snapshot_path}: {error}"));
+    assert_eq!(expected, actual, "snapshot mismatch: {snapshot_path}");
+}
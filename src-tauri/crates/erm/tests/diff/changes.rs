@@ -0,0 +1,364 @@
+use pretty_assertions::assert_eq;
+
+use erm::column_type::ColumnType;
+use erm::diff;
+use erm::diff::{Change, ChangeValue, ColumnProperty, IndexProperty, RelationshipProperty};
+use erm::dtos::diagram::diagram_walkers::tables::columns::ColumnItem;
+use erm::dtos::diagram::diagram_walkers::tables::connections::{ChildCardinality, OnAction};
+use erm::dtos::diagram::diagram_walkers::tables::indexes::{Column, Index};
+
+use super::support::{base, column_items_mut, column_mut, table_mut, tables_mut};
+
+#[test]
+fn identical_diagrams_have_no_changes() {
+    let base = base();
+
+    assert!(diff(&base, &base).is_empty());
+}
+
+#[test]
+fn layout_changes_are_ignored() {
+    let old = base();
+    let mut new = old.clone();
+    let table = table_mut(&mut new, "MEMBERS");
+    table.x += 10;
+    table.y += 10;
+    table.width = Some(500);
+
+    assert!(diff(&old, &new).is_empty());
+}
+
+#[test]
+fn added_and_removed_tables_are_reported() {
+    let old = base();
+    let mut new = old.clone();
+    let mut tags = table_mut(&mut new, "MST_GENDER").clone();
+    tags.physical_name = "TAGS".to_string();
+    tags.columns.items = Some(Vec::new());
+    tables_mut(&mut new).push(tags);
+    tables_mut(&mut new).retain(|table| table.physical_name != "POST_THREADS");
+
+    assert_eq!(
+        diff(&old, &new).changes,
+        [
+            Change::TableRemoved {
+                table: "POST_THREADS".to_string()
+            },
+            Change::TableAdded {
+                table: "TAGS".to_string()
+            },
+        ]
+    );
+}
+
+#[test]
+fn table_with_same_columns_under_a_new_name_is_a_rename() {
+    let old = base();
+    let mut new = old.clone();
+    let table = table_mut(&mut new, "MST_GENDER");
+    table.physical_name = "MST_GENDERS".to_string();
+    table.logical_name = "Genders".to_string();
+
+    let changes = diff(&old, &new).changes;
+
+    assert_eq!(
+        changes[0],
+        Change::TableRenamed {
+            old_name: "MST_GENDER".to_string(),
+            new_name: "MST_GENDERS".to_string(),
+        }
+    );
+    assert!(matches!(
+        &changes[1],
+        Change::TableModified { table, new: Some(ChangeValue::Text(name)), .. }
+            if table == "MST_GENDERS" && name == "Genders"
+    ));
+    assert_eq!(changes.len(), 2);
+}
+
+#[test]
+fn tables_without_columns_are_not_paired_as_a_rename() {
+    let mut old = base();
+    table_mut(&mut old, "MST_GENDER").columns.items = Some(Vec::new());
+    let mut new = old.clone();
+    table_mut(&mut new, "MST_GENDER").physical_name = "TAGS".to_string();
+
+    assert_eq!(
+        diff(&old, &new).changes,
+        [
+            Change::TableRemoved {
+                table: "MST_GENDER".to_string()
+            },
+            Change::TableAdded {
+                table: "TAGS".to_string()
+            },
+        ]
+    );
+}
+
+#[test]
+fn ambiguous_renames_are_reported_as_removed_and_added() {
+    let old = base();
+    let mut new = old.clone();
+    let mut copy = table_mut(&mut new, "MST_GENDER").clone();
+    copy.physical_name = "GENDERS_COPY".to_string();
+    table_mut(&mut new, "MST_GENDER").physical_name = "GENDERS".to_string();
+    tables_mut(&mut new).push(copy);
+
+    let changes = diff(&old, &new).changes;
+
+    assert!(
+        !changes
+            .iter()
+            .any(|change| matches!(change, Change::TableRenamed { .. }))
+    );
+    assert!(changes.contains(&Change::TableRemoved {
+        table: "MST_GENDER".to_string()
+    }));
+    assert!(changes.contains(&Change::TableAdded {
+        table: "GENDERS".to_string()
+    }));
+    assert!(changes.contains(&Change::TableAdded {
+        table: "GENDERS_COPY".to_string()
+    }));
+}
+
+#[test]
+fn column_changes_are_reported() {
+    let old = base();
+    let mut new = old.clone();
+    let column = column_mut(&mut new, "POSTS", "TITLE");
+    column.column_type = Some(ColumnType::Text);
+    column.length = None;
+    column.not_null = Some(false);
+    column.default_value = Some("untitled".to_string());
+    column_items_mut(&mut new, "POSTS").retain(
+        |item| !matches!(item, ColumnItem::Normal(column) if column.physical_name == "IMG_URL"),
+    );
+    let mut slug = column_mut(&mut new, "POSTS", "TITLE").clone();
+    slug.physical_name = "SLUG".to_string();
+    column_items_mut(&mut new, "POSTS").push(ColumnItem::Normal(slug));
+
+    let old_title = column_mut(&mut old.clone(), "POSTS", "TITLE").clone();
+    let modified =
+        |property, old: Option<ChangeValue>, new: Option<ChangeValue>| Change::ColumnModified {
+            table: "POSTS".to_string(),
+            column: "TITLE".to_string(),
+            property,
+            old,
+            new,
+        };
+
+    assert_eq!(
+        diff(&old, &new).changes,
+        [
+            Change::ColumnRemoved {
+                table: "POSTS".to_string(),
+                column: "IMG_URL".to_string(),
+            },
+            modified(
+                ColumnProperty::Type,
+                old_title.column_type.map(Into::into),
+                Some(ChangeValue::Text("text".to_string())),
+            ),
+            modified(
+                ColumnProperty::Length,
+                old_title.length.map(Into::into),
+                None
+            ),
+            modified(
+                ColumnProperty::NotNull,
+                old_title.not_null.map(Into::into),
+                Some(ChangeValue::Bool(false)),
+            ),
+            modified(
+                ColumnProperty::DefaultValue,
+                old_title.default_value.map(Into::into),
+                Some(ChangeValue::Text("untitled".to_string())),
+            ),
+            Change::ColumnAdded {
+                table: "POSTS".to_string(),
+                column: "SLUG".to_string(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn column_with_the_same_definition_under_a_new_name_is_a_rename() {
+    let old = base();
+    let mut new = old.clone();
+    let column = column_mut(&mut new, "POSTS", "TITLE");
+    column.physical_name = "SUBJECT".to_string();
+    column.logical_name = Some("件名".to_string());
+
+    let old_title = column_mut(&mut old.clone(), "POSTS", "TITLE").clone();
+
+    assert_eq!(
+        diff(&old, &new).changes,
+        [
+            Change::ColumnRenamed {
+                table: "POSTS".to_string(),
+                old_name: "TITLE".to_string(),
+                new_name: "SUBJECT".to_string(),
+            },
+            Change::ColumnModified {
+                table: "POSTS".to_string(),
+                column: "SUBJECT".to_string(),
+                property: ColumnProperty::LogicalName,
+                old: old_title.logical_name.map(Into::into),
+                new: Some(ChangeValue::Text("件名".to_string())),
+            },
+        ]
+    );
+}
+
+#[test]
+fn index_changes_are_reported() {
+    let old = base();
+    let mut new = old.clone();
+    let index = |columns: &[&str]| Index {
+        name: "IX_POSTS".to_string(),
+        index_type: "BTREE".to_string(),
+        description: None,
+        full_text: None,
+        non_unique: Some(true),
+        columns: columns
+            .iter()
+            .map(|column| Column {
+                column_id: column.to_string(),
+                desc: None,
+            })
+            .collect(),
+    };
+    let mut changed = old.clone();
+    table_mut(&mut new, "POSTS").indexes = Some(vec![index(&["TITLE"])]);
+    table_mut(&mut changed, "POSTS").indexes = Some(vec![index(&["TITLE", "POST_ID"])]);
+
+    assert_eq!(
+        diff(&old, &new).changes,
+        [Change::IndexAdded {
+            table: "POSTS".to_string(),
+            index: "IX_POSTS".to_string(),
+        }]
+    );
+    assert_eq!(
+        diff(&new, &changed).changes,
+        [Change::IndexModified {
+            table: "POSTS".to_string(),
+            index: "IX_POSTS".to_string(),
+            property: IndexProperty::Columns,
+            old: Some(vec!["TITLE".to_string()].into()),
+            new: Some(vec!["TITLE".to_string(), "POST_ID".to_string()].into()),
+        }]
+    );
+    assert_eq!(
+        diff(&new, &old).changes,
+        [Change::IndexRemoved {
+            table: "POSTS".to_string(),
+            index: "IX_POSTS".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn relationship_changes_are_reported() {
+    let old = base();
+    let mut new = old.clone();
+    let relationship = table_mut(&mut new, "MEMBER_PROFILES")
+        .connections
+        .relationships
+        .as_mut()
+        .and_then(|relationships| relationships.first_mut())
+        .expect("fixture table has relationships");
+    let name = relationship.name.clone();
+    relationship.child_cardinality = ChildCardinality::ZeroOrMore;
+    relationship.on_delete_action = Some(OnAction::Cascade);
+
+    let modified = |property, old: &str, new: &str| Change::RelationshipModified {
+        table: "MEMBER_PROFILES".to_string(),
+        relationship: name.clone(),
+        property,
+        old: Some(ChangeValue::Text(old.to_string())),
+        new: Some(ChangeValue::Text(new.to_string())),
+    };
+
+    assert_eq!(
+        diff(&old, &new).changes,
+        [
+            modified(RelationshipProperty::ChildCardinality, "0..1", "0..n"),
+            modified(RelationshipProperty::OnDeleteAction, "RESTRICT", "CASCADE"),
+        ]
+    );
+}
+
+#[test]
+fn column_group_changes_are_reported() {
+    let old = base();
+    let mut new = old.clone();
+    let group = new
+        .column_groups
+        .as_mut()
+        .and_then(|groups| groups.first_mut())
+        .expect("fixture has column groups");
+    let group_name = group.column_group_name.clone();
+    let columns = group
+        .columns
+        .normal_columns
+        .as_mut()
+        .expect("fixture group has columns");
+    let removed = columns.remove(0).physical_name;
+    columns[0].not_null = Some(!columns[0].not_null.unwrap_or_default());
+    let modified = columns[0].physical_name.clone();
+    column_items_mut(&mut new, "POST_THREADS")
+        .retain(|item| !matches!(item, ColumnItem::Group(group) if *group == group_name));
+
+    let changes = diff(&old, &new).changes;
+
+    assert_eq!(
+        changes[0],
+        Change::ColumnGroupReferenceRemoved {
+            table: "POST_THREADS".to_string(),
+            column_group: group_name.clone(),
+        }
+    );
+    assert_eq!(
+        changes[1],
+        Change::ColumnGroupColumnRemoved {
+            column_group: group_name.clone(),
+            column: removed,
+        }
+    );
+    assert!(matches!(
+        &changes[2],
+        Change::ColumnGroupColumnModified { column, property: ColumnProperty::NotNull, .. }
+            if *column == modified
+    ));
+    assert_eq!(changes.len(), 3);
+}
+
+#[test]
+fn changes_serialize_as_tagged_records() {
+    let old = base();
+    let mut new = old.clone();
+    column_mut(&mut new, "POSTS", "TITLE").length = Some(512);
+    let old_length = column_mut(&mut old.clone(), "POSTS", "TITLE")
+        .length
+        .expect("fixture column has a length");
+
+    let json = serde_json::to_value(diff(&old, &new)).expect("failed to serialize");
+
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "changes": [{
+                "kind": "columnModified",
+                "table": "POSTS",
+                "column": "TITLE",
+                "property": "length",
+                "old": old_length,
+                "new": 512
+            }]
+        })
+    );
+}
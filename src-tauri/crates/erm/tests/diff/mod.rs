@@ -0,0 +1,2 @@
+pub mod changes;
+mod support;
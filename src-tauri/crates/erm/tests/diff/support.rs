@@ -0,0 +1,50 @@
+use erm::dtos::diagram::Diagram;
+use erm::dtos::diagram::diagram_walkers::tables::Table;
+use erm::dtos::diagram::diagram_walkers::tables::columns::{ColumnItem, NormalColumn};
+use erm::open;
+
+const FIXTURE: &str = "./tests/open/fixtures/read_snapshot.erm";
+
+pub(crate) fn base() -> Diagram {
+    open(FIXTURE).expect("failed to parse fixture").diagram
+}
+
+pub(crate) fn tables_mut(diagram: &mut Diagram) -> &mut Vec<Table> {
+    diagram
+        .diagram_walkers
+        .as_mut()
+        .and_then(|walkers| walkers.tables.as_mut())
+        .expect("diagram has tables")
+}
+
+pub(crate) fn table_mut<'a>(diagram: &'a mut Diagram, name: &str) -> &'a mut Table {
+    tables_mut(diagram)
+        .iter_mut()
+        .find(|table| table.physical_name == name)
+        .expect("missing table")
+}
+
+pub(crate) fn column_items_mut<'a>(
+    diagram: &'a mut Diagram,
+    table_name: &str,
+) -> &'a mut Vec<ColumnItem> {
+    table_mut(diagram, table_name)
+        .columns
+        .items
+        .as_mut()
+        .expect("table has columns")
+}
+
+pub(crate) fn column_mut<'a>(
+    diagram: &'a mut Diagram,
+    table_name: &str,
+    name: &str,
+) -> &'a mut NormalColumn {
+    column_items_mut(diagram, table_name)
+        .iter_mut()
+        .find_map(|item| match item {
+            ColumnItem::Normal(column) if column.physical_name == name => Some(column),
+            _ => None,
+        })
+        .expect("missing column")
+}
@@ -0,0 +1,284 @@
+use std::fs;
+
+use pretty_assertions::assert_eq;
+
+use erm::column_type::ColumnType;
+use erm::ddl::{mysql, postgresql};
+use erm::dtos::diagram::Diagram;
+use erm::dtos::diagram::diagram_walkers::tables::Table;
+use erm::dtos::diagram::diagram_walkers::tables::columns::{ColumnItem, NormalColumn};
+use erm::dtos::diagram::diagram_walkers::tables::connections::{
+    ChildCardinality, OnAction, ParentCardinality,
+};
+use erm::errors::Error;
+use erm::import::ddl::{Dialect, parse};
+use erm::lint::{NamingConventions, lint};
+
+fn table<'a>(diagram: &'a Diagram, name: &str) -> &'a Table {
+    diagram
+        .diagram_walkers
+        .as_ref()
+        .and_then(|walkers| walkers.tables.as_ref())
+        .and_then(|tables| tables.iter().find(|table| table.physical_name == name))
+        .expect("table not found")
+}
+
+fn column<'a>(table: &'a Table, name: &str) -> &'a NormalColumn {
+    table
+        .columns
+        .items
+        .iter()
+        .flatten()
+        .find_map(|item| match item {
+            ColumnItem::Normal(column) if column.physical_name == name => Some(column),
+            _ => None,
+        })
+        .expect("column not found")
+}
+
+#[test]
+fn generated_mysql_ddl_is_imported_back_into_the_same_ddl() {
+    let sql = fs::read_to_string("./tests/ddl/snapshots/mysql/read_snapshot.sql").unwrap();
+
+    let diagram = parse(&sql, Dialect::MySql).unwrap();
+
+    assert_eq!(diagram.diagram_settings.database, "MySQL");
+    assert_eq!(mysql::generate(&diagram).sql, sql);
+}
+
+#[test]
+fn generated_postgresql_ddl_is_imported_back_into_the_same_ddl() {
+    let sql = fs::read_to_string("./tests/ddl/snapshots/postgresql/read_snapshot.sql").unwrap();
+
+    let diagram = parse(&sql, Dialect::PostgreSql).unwrap();
+
+    assert_eq!(diagram.diagram_settings.database, "PostgreSQL");
+    assert_eq!(postgresql::generate(&diagram).sql, sql);
+}
+
+#[test]
+fn columns_are_mapped_to_column_types() {
+    let diagram = parse(
+        "CREATE TABLE ITEMS (
+            ID int(11) unsigned NOT NULL AUTO_INCREMENT PRIMARY KEY,
+            CODE char(8) NOT NULL,
+            NAME varchar(64) DEFAULT 'unnamed' COMMENT 'Name: Display name',
+            PRICE decimal(10,2),
+            STATUS enum('draft','published'),
+            RELEASED year
+        );",
+        Dialect::MySql,
+    )
+    .unwrap();
+    let items = table(&diagram, "ITEMS");
+
+    let id = column(items, "ID");
+    assert_eq!(id.column_type, Some(ColumnType::IntN));
+    assert_eq!(id.length, Some(11));
+    assert_eq!(id.unsigned, Some(true));
+    assert_eq!(id.primary_key, Some(true));
+    assert_eq!(id.auto_increment, Some(true));
+
+    let code = column(items, "CODE");
+    assert_eq!(code.column_type, Some(ColumnType::CharN));
+    assert_eq!(code.length, Some(8));
+    assert_eq!(code.not_null, Some(true));
+
+    let name = column(items, "NAME");
+    assert_eq!(name.column_type, Some(ColumnType::VarCharN));
+    assert_eq!(name.default_value.as_deref(), Some("unnamed"));
+    assert_eq!(name.logical_name.as_deref(), Some("Name"));
+    assert_eq!(name.description.as_deref(), Some("Display name"));
+
+    let price = column(items, "PRICE");
+    assert_eq!(price.column_type, Some(ColumnType::DecimalPS));
+    assert_eq!((price.length, price.decimal), (Some(10), Some(2)));
+
+    let status = column(items, "STATUS");
+    assert_eq!(status.column_type, Some(ColumnType::Enum));
+    assert_eq!(status.args.as_deref(), Some("'draft','published'"));
+
+    assert_eq!(
+        column(items, "RELEASED").column_type,
+        Some(ColumnType::Year4)
+    );
+}
+
+#[test]
+fn keys_indexes_and_comments_are_imported() {
+    let diagram = parse(
+        r#"CREATE TABLE "MEMBERS" (
+            "MEMBER_ID" bigserial,
+            "EMAIL" varchar(255) NOT NULL,
+            "TENANT_ID" integer NOT NULL,
+            "CODE" varchar(16) NOT NULL,
+            CONSTRAINT "PK_MEMBERS" PRIMARY KEY ("MEMBER_ID"),
+            CONSTRAINT "UK_MEMBERS_CODE" UNIQUE ("TENANT_ID", "CODE"),
+            UNIQUE ("EMAIL")
+        );
+        CREATE INDEX "IDX_MEMBERS_EMAIL" ON "MEMBERS" USING btree ("EMAIL" DESC, "MEMBER_ID");
+        CREATE UNIQUE INDEX ON "MEMBERS" ("CODE");
+        COMMENT ON TABLE "MEMBERS" IS 'Members: Registered members';
+        COMMENT ON COLUMN "MEMBERS"."EMAIL" IS 'Email';"#,
+        Dialect::PostgreSql,
+    )
+    .unwrap();
+    let members = table(&diagram, "MEMBERS");
+
+    assert_eq!(members.logical_name, "Members");
+    assert_eq!(members.description, "Registered members");
+    assert_eq!(members.primary_key_name.as_deref(), Some("PK_MEMBERS"));
+
+    let member_id = column(members, "MEMBER_ID");
+    assert_eq!(member_id.column_type, Some(ColumnType::BigInt));
+    assert_eq!(member_id.auto_increment, Some(true));
+
+    let email = column(members, "EMAIL");
+    assert_eq!(email.unique_key, Some(true));
+    assert_eq!(email.logical_name.as_deref(), Some("Email"));
+    assert_eq!(email.description, None);
+
+    let keys = members
+        .compound_unique_key_list
+        .compound_unique_keys
+        .as_deref()
+        .unwrap();
+    assert_eq!(keys.len(), 1);
+    assert_eq!(keys[0].name, "UK_MEMBERS_CODE");
+    assert_eq!(
+        keys[0]
+            .columns
+            .iter()
+            .map(|column| column.column_id.as_str())
+            .collect::<Vec<_>>(),
+        vec!["TENANT_ID", "CODE"]
+    );
+
+    let indexes = members.indexes.as_deref().unwrap();
+    assert_eq!(indexes.len(), 2);
+    assert_eq!(indexes[0].name, "IDX_MEMBERS_EMAIL");
+    assert_eq!(indexes[0].index_type, "BTREE");
+    assert_eq!(indexes[0].non_unique, Some(true));
+    assert_eq!(indexes[0].columns[0].column_id, "EMAIL");
+    assert_eq!(indexes[0].columns[0].desc, Some(true));
+    assert_eq!(indexes[0].columns[1].desc, None);
+    assert_eq!(indexes[1].name, "MEMBERS_CODE_idx");
+    assert_eq!(indexes[1].non_unique, Some(false));
+}
+
+#[test]
+fn foreign_keys_become_relationships() {
+    let diagram = parse(
+        "CREATE TABLE POSTS (
+            POST_ID bigint NOT NULL PRIMARY KEY,
+            MEMBER_ID bigint NOT NULL,
+            EDITOR_CODE varchar(16) REFERENCES MEMBERS (CODE)
+        );
+        CREATE TABLE MEMBERS (
+            MEMBER_ID bigint NOT NULL PRIMARY KEY,
+            CODE varchar(16) NOT NULL
+        );
+        ALTER TABLE POSTS ADD CONSTRAINT FK_POSTS_MEMBERS
+            FOREIGN KEY (MEMBER_ID) REFERENCES MEMBERS (MEMBER_ID) ON DELETE CASCADE;",
+        Dialect::PostgreSql,
+    )
+    .unwrap();
+    let posts = table(&diagram, "POSTS");
+    let relationships = posts.connections.relationships.as_deref().unwrap();
+
+    assert_eq!(relationships.len(), 2);
+
+    let editor = &relationships[0];
+    assert_eq!(editor.name, "POSTS_EDITOR_CODE_fkey");
+    assert_eq!(editor.source, "table.MEMBERS");
+    assert_eq!(editor.target, "table.POSTS");
+    assert!(!editor.reference_for_pk);
+    assert_eq!(
+        editor.referred_simple_unique_column.as_deref(),
+        Some("table.MEMBERS.CODE")
+    );
+    assert_eq!(editor.parent_cardinality, ParentCardinality::ZeroOrOne);
+    assert_eq!(
+        column(table(&diagram, "MEMBERS"), "CODE").unique_key,
+        Some(true)
+    );
+
+    let member = &relationships[1];
+    assert_eq!(member.name, "FK_POSTS_MEMBERS");
+    assert!(member.reference_for_pk);
+    assert_eq!(member.parent_cardinality, ParentCardinality::One);
+    assert_eq!(member.child_cardinality, ChildCardinality::ZeroOrMore);
+    assert_eq!(member.on_delete_action, Some(OnAction::Cascade));
+    assert_eq!(member.on_update_action, None);
+    assert_eq!(member.fk_columns.fk_column[0].fk_column_name, "MEMBER_ID");
+
+    let member_id = column(posts, "MEMBER_ID");
+    assert_eq!(
+        member_id.referred_column.as_deref(),
+        Some("table.MEMBERS.MEMBER_ID")
+    );
+    assert_eq!(member_id.relationship.as_deref(), Some("FK_POSTS_MEMBERS"));
+}
+
+#[test]
+fn foreign_keys_to_undeclared_tables_are_dropped() {
+    let diagram = parse(
+        "CREATE TABLE POSTS (
+            POST_ID bigint PRIMARY KEY,
+            MEMBER_ID bigint REFERENCES MEMBERS (MEMBER_ID)
+        );",
+        Dialect::PostgreSql,
+    )
+    .unwrap();
+    let posts = table(&diagram, "POSTS");
+
+    assert_eq!(posts.connections.relationships, None);
+    assert_eq!(column(posts, "MEMBER_ID").relationship, None);
+}
+
+#[test]
+fn tables_are_placed_on_a_grid() {
+    let sql = (0..5)
+        .map(|index| format!("CREATE TABLE T{index} (ID int);"))
+        .collect::<String>();
+
+    let diagram = parse(&sql, Dialect::MySql).unwrap();
+    let positions = diagram
+        .diagram_walkers
+        .unwrap()
+        .tables
+        .unwrap()
+        .iter()
+        .map(|table| (table.x, table.y))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        positions,
+        vec![(50, 50), (350, 50), (650, 50), (950, 50), (50, 300)]
+    );
+}
+
+#[test]
+fn naming_settings_follow_the_case_of_the_imported_names() {
+    let upper = parse("CREATE TABLE MEMBERS (MEMBER_ID int);", Dialect::MySql).unwrap();
+    let lower = parse("CREATE TABLE members (member_id int);", Dialect::MySql).unwrap();
+    let mixed = parse("CREATE TABLE Members (memberId int);", Dialect::MySql).unwrap();
+
+    let settings = |diagram: &Diagram| {
+        (
+            diagram.diagram_settings.capital,
+            diagram.diagram_settings.validate_physical_name,
+        )
+    };
+    assert_eq!(settings(&upper), (true, true));
+    assert_eq!(settings(&lower), (false, true));
+    assert_eq!(settings(&mixed), (false, false));
+    assert!(lint(&lower, &NamingConventions::new(&lower.diagram_settings)).is_empty());
+}
+
+#[test]
+fn invalid_sql_is_reported() {
+    let result = parse("CREATE TABLE (", Dialect::MySql);
+
+    assert!(matches!(result, Err(Error::SqlParse(_))));
+}
@@ -0,0 +1,2 @@
+pub mod ddl;
+pub mod sqlite;
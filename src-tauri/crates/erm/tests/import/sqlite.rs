@@ -0,0 +1,254 @@
+use std::fs;
+use std::path::PathBuf;
+
+use pretty_assertions::assert_eq;
+use rusqlite::Connection;
+
+use erm::column_type::ColumnType;
+use erm::ddl::sqlite as sqlite_ddl;
+use erm::dtos::diagram::Diagram;
+use erm::dtos::diagram::diagram_walkers::tables::Table;
+use erm::dtos::diagram::diagram_walkers::tables::columns::{ColumnItem, NormalColumn};
+use erm::dtos::diagram::diagram_walkers::tables::connections::OnAction;
+use erm::errors::Error;
+use erm::import::sqlite;
+
+fn database(test_name: &str, sql: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!(
+        "erm_import_sqlite_{}_{test_name}.sqlite",
+        std::process::id()
+    ));
+    let _ = fs::remove_file(&path);
+    Connection::open(&path)
+        .and_then(|connection| connection.execute_batch(sql))
+        .expect("failed to create database");
+    path
+}
+
+fn import(test_name: &str, sql: &str) -> Diagram {
+    let path = database(test_name, sql);
+    let diagram = sqlite::open(path.to_str().expect("invalid temp path"));
+    fs::remove_file(&path).expect("failed to remove database");
+    diagram.expect("failed to import database")
+}
+
+fn table<'a>(diagram: &'a Diagram, name: &str) -> &'a Table {
+    diagram
+        .diagram_walkers
+        .as_ref()
+        .and_then(|walkers| walkers.tables.as_ref())
+        .and_then(|tables| tables.iter().find(|table| table.physical_name == name))
+        .expect("table not found")
+}
+
+fn column<'a>(table: &'a Table, name: &str) -> &'a NormalColumn {
+    table
+        .columns
+        .items
+        .iter()
+        .flatten()
+        .find_map(|item| match item {
+            ColumnItem::Normal(column) if column.physical_name == name => Some(column),
+            _ => None,
+        })
+        .expect("column not found")
+}
+
+fn relationships(diagram: &Diagram) -> Vec<(String, String, Vec<String>)> {
+    diagram
+        .diagram_walkers
+        .iter()
+        .flat_map(|walkers| walkers.tables.iter().flatten())
+        .flat_map(|table| table.connections.relationships.iter().flatten())
+        .map(|relationship| {
+            (
+                relationship.source.clone(),
+                relationship.target.clone(),
+                relationship
+                    .fk_columns
+                    .fk_column
+                    .iter()
+                    .map(|fk_column| fk_column.fk_column_name.clone())
+                    .collect(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn database_created_from_generated_ddl_is_imported() {
+    let fixture = erm::open("./tests/open/fixtures/read_snapshot.erm")
+        .unwrap()
+        .diagram;
+    let sql = fs::read_to_string("./tests/ddl/snapshots/sqlite/read_snapshot.sql").unwrap();
+
+    let diagram = import("generated_ddl", &sql);
+
+    assert_eq!(diagram.diagram_settings.database, "SQLite");
+    assert_eq!(relationships(&diagram), relationships(&fixture));
+    // Only the foreign key names, which SQLite does not keep, are lost.
+    let regenerated = sqlite_ddl::generate(&diagram).sql;
+    assert_eq!(
+        regenerated
+            .lines()
+            .filter(|line| !line.contains("FOREIGN KEY"))
+            .collect::<Vec<_>>(),
+        sql.lines()
+            .filter(|line| !line.contains("FOREIGN KEY"))
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn columns_are_mapped_to_column_types() {
+    let diagram = import(
+        "columns",
+        "CREATE TABLE ITEMS (
+            ID INTEGER PRIMARY KEY AUTOINCREMENT,
+            CODE CHAR(8) NOT NULL DEFAULT 'none',
+            NAME VARCHAR(64),
+            PRICE DECIMAL(10,2) DEFAULT 0,
+            PAYLOAD BLOB,
+            CREATED_AT DATETIME DEFAULT CURRENT_TIMESTAMP,
+            NOTE
+        );",
+    );
+    let items = table(&diagram, "ITEMS");
+
+    let id = column(items, "ID");
+    assert_eq!(id.column_type, Some(ColumnType::Int));
+    assert_eq!(id.primary_key, Some(true));
+    assert_eq!(id.auto_increment, Some(true));
+
+    let code = column(items, "CODE");
+    assert_eq!(code.column_type, Some(ColumnType::CharN));
+    assert_eq!(code.length, Some(8));
+    assert_eq!(code.not_null, Some(true));
+    assert_eq!(code.default_value.as_deref(), Some("none"));
+
+    let name = column(items, "NAME");
+    assert_eq!(name.column_type, Some(ColumnType::VarCharN));
+    assert_eq!(name.not_null, None);
+
+    let price = column(items, "PRICE");
+    assert_eq!(price.column_type, Some(ColumnType::DecimalPS));
+    assert_eq!((price.length, price.decimal), (Some(10), Some(2)));
+    assert_eq!(price.default_value.as_deref(), Some("0"));
+
+    assert_eq!(column(items, "PAYLOAD").column_type, Some(ColumnType::Blob));
+    assert_eq!(
+        column(items, "CREATED_AT").default_value.as_deref(),
+        Some("CURRENT_TIMESTAMP")
+    );
+    assert_eq!(column(items, "NOTE").column_type, None);
+}
+
+#[test]
+fn indexes_and_unique_keys_are_imported() {
+    let diagram = import(
+        "indexes",
+        "CREATE TABLE MEMBERS (
+            MEMBER_ID INTEGER PRIMARY KEY,
+            EMAIL TEXT UNIQUE,
+            TENANT_ID INTEGER NOT NULL,
+            CODE TEXT NOT NULL,
+            UNIQUE (TENANT_ID, CODE)
+        );
+        CREATE INDEX IDX_MEMBERS_CODE ON MEMBERS (CODE DESC, MEMBER_ID);
+        CREATE UNIQUE INDEX IDX_MEMBERS_TENANT ON MEMBERS (TENANT_ID, EMAIL);
+        CREATE INDEX IDX_MEMBERS_LOWER_EMAIL ON MEMBERS (lower(EMAIL));",
+    );
+    let members = table(&diagram, "MEMBERS");
+
+    assert_eq!(column(members, "EMAIL").unique_key, Some(true));
+
+    let keys = members
+        .compound_unique_key_list
+        .compound_unique_keys
+        .as_deref()
+        .unwrap();
+    assert_eq!(keys.len(), 1);
+    assert_eq!(keys[0].name, "MEMBERS_TENANT_ID_CODE_key");
+
+    let indexes = members.indexes.as_deref().unwrap();
+    assert_eq!(
+        indexes
+            .iter()
+            .map(|index| (index.name.as_str(), index.non_unique))
+            .collect::<Vec<_>>(),
+        vec![
+            ("IDX_MEMBERS_CODE", Some(true)),
+            ("IDX_MEMBERS_TENANT", Some(false))
+        ]
+    );
+    assert_eq!(indexes[0].columns[0].column_id, "CODE");
+    assert_eq!(indexes[0].columns[0].desc, Some(true));
+    assert_eq!(indexes[0].columns[1].desc, None);
+}
+
+#[test]
+fn foreign_keys_become_relationships() {
+    let diagram = import(
+        "foreign_keys",
+        "CREATE TABLE ORDERS (
+            TENANT_ID INTEGER NOT NULL,
+            ORDER_NO INTEGER NOT NULL,
+            PRIMARY KEY (TENANT_ID, ORDER_NO)
+        );
+        CREATE TABLE ORDER_LINES (
+            LINE_ID INTEGER PRIMARY KEY,
+            TENANT_ID INTEGER NOT NULL,
+            ORDER_NO INTEGER NOT NULL,
+            REPLACED_BY INTEGER REFERENCES ORDER_LINES ON DELETE SET NULL,
+            FOREIGN KEY (TENANT_ID, ORDER_NO) REFERENCES ORDERS (TENANT_ID, ORDER_NO)
+                ON DELETE CASCADE ON UPDATE RESTRICT
+        );",
+    );
+    let order_lines = table(&diagram, "ORDER_LINES");
+    let relationships = order_lines.connections.relationships.as_deref().unwrap();
+
+    assert_eq!(relationships.len(), 2);
+
+    let replaced_by = &relationships[0];
+    assert_eq!(replaced_by.name, "ORDER_LINES_REPLACED_BY_fkey");
+    assert_eq!(replaced_by.source, "table.ORDER_LINES");
+    assert!(replaced_by.reference_for_pk);
+    assert_eq!(replaced_by.on_delete_action, Some(OnAction::SetNull));
+    assert_eq!(
+        column(order_lines, "REPLACED_BY")
+            .referred_column
+            .as_deref(),
+        Some("table.ORDER_LINES.LINE_ID")
+    );
+
+    let order = &relationships[1];
+    assert_eq!(order.source, "table.ORDERS");
+    assert!(order.reference_for_pk);
+    assert_eq!(order.on_delete_action, Some(OnAction::Cascade));
+    assert_eq!(order.on_update_action, Some(OnAction::Restrict));
+    assert_eq!(
+        order
+            .fk_columns
+            .fk_column
+            .iter()
+            .map(|fk_column| fk_column.fk_column_name.as_str())
+            .collect::<Vec<_>>(),
+        vec!["TENANT_ID", "ORDER_NO"]
+    );
+    assert_eq!(
+        column(order_lines, "ORDER_NO").referred_column.as_deref(),
+        Some("table.ORDERS.ORDER_NO")
+    );
+}
+
+#[test]
+fn missing_database_is_reported() {
+    let path = std::env::temp_dir().join(format!(
+        "erm_import_sqlite_{}_missing.sqlite",
+        std::process::id()
+    ));
+
+    let result = sqlite::open(path.to_str().unwrap());
+
+    assert!(matches!(result, Err(Error::Sqlite(_))));
+}
@@ -0,0 +1,386 @@
+use pretty_assertions::assert_eq;
+
+use erm::dtos::diagram::Diagram;
+use erm::dtos::diagram::diagram_walkers::tables::Table;
+use erm::dtos::diagram::diagram_walkers::tables::columns::{ColumnItem, NormalColumn};
+use erm::dtos::diagram::diagram_walkers::tables::indexes::{Column, Index};
+use erm::errors::Error;
+use erm::merge::{MergeConflict, MergeConflictKind};
+use erm::{merge, open};
+
+const FIXTURE: &str = "./tests/open/fixtures/read_snapshot.erm";
+
+#[test]
+fn unchanged_sides_merge_to_base() {
+    let base = base();
+
+    let merged = merge(&base, &base, &base).expect("failed to merge");
+
+    assert_eq!(merged, base);
+}
+
+#[test]
+fn changes_to_different_tables_are_combined() {
+    let base = base();
+    let mut ours = base.clone();
+    let mut theirs = base.clone();
+    table_mut(&mut ours, "MEMBERS").logical_name = "Users".to_string();
+    table_mut(&mut theirs, "POSTS").description = "Articles".to_string();
+
+    let merged = merge(&base, &ours, &theirs).expect("failed to merge");
+
+    assert_eq!(table(&merged, "MEMBERS").logical_name, "Users");
+    assert_eq!(table(&merged, "POSTS").description, "Articles");
+}
+
+#[test]
+fn changes_to_different_properties_of_one_column_are_combined() {
+    let base = base();
+    let mut ours = base.clone();
+    let mut theirs = base.clone();
+    column_mut(&mut ours, "MEMBERS", "LAST_NAME").logical_name = Some("Surname".to_string());
+    column_mut(&mut theirs, "MEMBERS", "LAST_NAME").not_null = Some(false);
+
+    let merged = merge(&base, &ours, &theirs).expect("failed to merge");
+
+    let column = column(&merged, "MEMBERS", "LAST_NAME");
+    assert_eq!(column.logical_name.as_deref(), Some("Surname"));
+    assert_eq!(column.not_null, Some(false));
+}
+
+#[test]
+fn columns_added_on_both_sides_are_kept_in_place() {
+    let base = base();
+    let mut ours = base.clone();
+    let mut theirs = base.clone();
+    insert_column(&mut ours, "MEMBERS", 1, "NICKNAME");
+    insert_column(&mut theirs, "MEMBERS", 2, "EMAIL");
+
+    let merged = merge(&base, &ours, &theirs).expect("failed to merge");
+
+    assert_eq!(
+        column_keys(table(&merged, "MEMBERS")),
+        [
+            "MEMBER_ID",
+            "NICKNAME",
+            "LAST_NAME",
+            "EMAIL",
+            "FIRST_NAME",
+            "COMMON"
+        ]
+    );
+}
+
+#[test]
+fn identical_changes_on_both_sides_do_not_conflict() {
+    let base = base();
+    let mut ours = base.clone();
+    table_mut(&mut ours, "POSTS").logical_name = "Articles".to_string();
+    insert_column(&mut ours, "POSTS", 1, "SLUG");
+
+    let merged = merge(&base, &ours, &ours.clone()).expect("failed to merge");
+
+    assert_eq!(merged, ours);
+}
+
+#[test]
+fn table_deleted_on_one_side_is_removed() {
+    let base = base();
+    let ours = base.clone();
+    let mut theirs = base.clone();
+    remove_table(&mut theirs, "POST_REPLIES");
+
+    let merged = merge(&base, &ours, &theirs).expect("failed to merge");
+
+    assert!(
+        tables(&merged)
+            .iter()
+            .all(|t| t.physical_name != "POST_REPLIES")
+    );
+}
+
+#[test]
+fn same_property_changed_differently_is_a_conflict() {
+    let base = base();
+    let mut ours = base.clone();
+    let mut theirs = base.clone();
+    column_mut(&mut ours, "MEMBERS", "LAST_NAME").length = Some(64);
+    column_mut(&mut theirs, "MEMBERS", "LAST_NAME").length = Some(128);
+    table_mut(&mut ours, "POSTS").x = 10;
+    table_mut(&mut theirs, "POSTS").x = 20;
+
+    let conflicts = conflicts(merge(&base, &ours, &theirs));
+
+    let base_length = column(&base, "MEMBERS", "LAST_NAME")
+        .length
+        .expect("fixture column has a length");
+    assert_eq!(
+        conflicts,
+        [
+            MergeConflict {
+                path: "diagram_walkers.table[MEMBERS].columns.normal_column[LAST_NAME].length"
+                    .to_string(),
+                kind: MergeConflictKind::BothModified,
+                base: Some(base_length.to_string()),
+                ours: Some("64".to_string()),
+                theirs: Some("128".to_string()),
+            },
+            MergeConflict {
+                path: "diagram_walkers.table[POSTS].x".to_string(),
+                kind: MergeConflictKind::BothModified,
+                base: Some(table(&base, "POSTS").x.to_string()),
+                ours: Some("10".to_string()),
+                theirs: Some("20".to_string()),
+            },
+        ]
+    );
+}
+
+#[test]
+fn change_to_an_element_deleted_on_the_other_side_is_a_conflict() {
+    let base = base();
+    let mut ours = base.clone();
+    let mut theirs = base.clone();
+    table_mut(&mut ours, "POST_REPLIES").logical_name = "Comments".to_string();
+    remove_table(&mut theirs, "POST_REPLIES");
+    remove_table(&mut ours, "MST_GENDER");
+    table_mut(&mut theirs, "MST_GENDER").description = "Genders".to_string();
+
+    let conflicts = conflicts(merge(&base, &ours, &theirs));
+
+    assert_eq!(
+        conflicts
+            .iter()
+            .map(|conflict| (conflict.path.as_str(), conflict.kind))
+            .collect::<Vec<_>>(),
+        [
+            (
+                "diagram_walkers.table[POST_REPLIES]",
+                MergeConflictKind::ModifiedAndDeleted
+            ),
+            (
+                "diagram_walkers.table[MST_GENDER]",
+                MergeConflictKind::DeletedAndModified
+            ),
+        ]
+    );
+}
+
+#[test]
+fn different_elements_added_under_the_same_key_are_a_conflict() {
+    let base = base();
+    let mut ours = base.clone();
+    let mut theirs = base.clone();
+    insert_column(&mut ours, "POSTS", 1, "SLUG");
+    insert_column(&mut theirs, "POSTS", 1, "SLUG");
+    column_mut(&mut theirs, "POSTS", "SLUG").length = Some(255);
+
+    let conflicts = conflicts(merge(&base, &ours, &theirs));
+
+    assert_eq!(conflicts.len(), 1);
+    assert_eq!(
+        conflicts[0].path,
+        "diagram_walkers.table[POSTS].columns.normal_column[SLUG]"
+    );
+    assert_eq!(conflicts[0].kind, MergeConflictKind::BothAdded);
+}
+
+#[test]
+fn conflicts_are_reported_readably() {
+    let base = base();
+    let mut ours = base.clone();
+    let mut theirs = base.clone();
+    table_mut(&mut ours, "POSTS").logical_name = "Articles".to_string();
+    table_mut(&mut theirs, "POSTS").logical_name = "Entries".to_string();
+
+    let error = merge(&base, &ours, &theirs).expect_err("expected a conflict");
+
+    assert_eq!(
+        error.to_string(),
+        format!(
+            "Merge conflict\n\n- diagram_walkers.table[POSTS].logical_name: changed on both sides \
+             (base: {}, ours: Articles, theirs: Entries)",
+            table(&base, "POSTS").logical_name
+        )
+    );
+}
+
+#[test]
+fn conflicts_serialize_with_every_side() {
+    let base = base();
+    let mut ours = base.clone();
+    let mut theirs = base.clone();
+    table_mut(&mut ours, "POSTS").logical_name = "Articles".to_string();
+    table_mut(&mut theirs, "POSTS").logical_name = "Entries".to_string();
+
+    let error = merge(&base, &ours, &theirs).expect_err("expected a conflict");
+    let payload = serde_json::to_value(&error).expect("failed to serialize");
+
+    assert_eq!(payload["code"], "E_MERGE_CONFLICT");
+    assert_eq!(
+        payload["conflicts"],
+        serde_json::json!([{
+            "path": "diagram_walkers.table[POSTS].logical_name",
+            "kind": "bothModified",
+            "base": table(&base, "POSTS").logical_name,
+            "ours": "Articles",
+            "theirs": "Entries"
+        }])
+    );
+}
+
+#[test]
+fn conflicting_compound_values_are_reported_by_field() {
+    let base = base();
+    let mut ours = base.clone();
+    let mut theirs = base.clone();
+    table_mut(&mut ours, "POSTS").color.r = 10;
+    table_mut(&mut ours, "POSTS").color.g = 10;
+    table_mut(&mut theirs, "POSTS").color.r = 20;
+
+    let conflicts = conflicts(merge(&base, &ours, &theirs));
+
+    assert_eq!(
+        conflicts,
+        [MergeConflict {
+            path: "diagram_walkers.table[POSTS].color.r".to_string(),
+            kind: MergeConflictKind::BothModified,
+            base: Some(table(&base, "POSTS").color.r.to_string()),
+            ours: Some("10".to_string()),
+            theirs: Some("20".to_string()),
+        }]
+    );
+}
+
+#[test]
+fn merged_diagram_must_pass_validation() {
+    let base = base();
+    let mut ours = base.clone();
+    let mut theirs = base.clone();
+    table_mut(&mut ours, "POSTS").indexes = Some(vec![Index {
+        name: "IX_POSTS_TITLE".to_string(),
+        index_type: "BTREE".to_string(),
+        description: None,
+        full_text: None,
+        non_unique: Some(true),
+        columns: vec![Column {
+            column_id: "TITLE".to_string(),
+            desc: None,
+        }],
+    }]);
+    remove_column(&mut theirs, "POSTS", "TITLE");
+
+    let error = merge(&base, &ours, &theirs).expect_err("expected a validation error");
+
+    assert!(matches!(error, Error::Validation(_)), "{error}");
+}
+
+fn base() -> Diagram {
+    open(FIXTURE).expect("failed to parse fixture").diagram
+}
+
+fn conflicts(result: Result<Diagram, Error>) -> Vec<MergeConflict> {
+    match result {
+        Err(Error::Merge(conflicts)) => conflicts.conflicts,
+        other => panic!("expected merge conflicts, got {other:?}"),
+    }
+}
+
+fn tables(diagram: &Diagram) -> &[Table] {
+    diagram
+        .diagram_walkers
+        .as_ref()
+        .and_then(|walkers| walkers.tables.as_deref())
+        .expect("diagram has tables")
+}
+
+fn tables_mut(diagram: &mut Diagram) -> &mut Vec<Table> {
+    diagram
+        .diagram_walkers
+        .as_mut()
+        .and_then(|walkers| walkers.tables.as_mut())
+        .expect("diagram has tables")
+}
+
+fn table<'a>(diagram: &'a Diagram, name: &str) -> &'a Table {
+    tables(diagram)
+        .iter()
+        .find(|table| table.physical_name == name)
+        .expect("missing table")
+}
+
+fn table_mut<'a>(diagram: &'a mut Diagram, name: &str) -> &'a mut Table {
+    tables_mut(diagram)
+        .iter_mut()
+        .find(|table| table.physical_name == name)
+        .expect("missing table")
+}
+
+fn remove_table(diagram: &mut Diagram, name: &str) {
+    tables_mut(diagram).retain(|table| table.physical_name != name);
+}
+
+fn column<'a>(diagram: &'a Diagram, table_name: &str, name: &str) -> &'a NormalColumn {
+    table(diagram, table_name)
+        .columns
+        .items
+        .iter()
+        .flatten()
+        .find_map(|item| match item {
+            ColumnItem::Normal(column) if column.physical_name == name => Some(column),
+            _ => None,
+        })
+        .expect("missing column")
+}
+
+fn column_mut<'a>(diagram: &'a mut Diagram, table_name: &str, name: &str) -> &'a mut NormalColumn {
+    table_mut(diagram, table_name)
+        .columns
+        .items
+        .iter_mut()
+        .flatten()
+        .find_map(|item| match item {
+            ColumnItem::Normal(column) if column.physical_name == name => Some(column),
+            _ => None,
+        })
+        .expect("missing column")
+}
+
+fn insert_column(diagram: &mut Diagram, table_name: &str, index: usize, name: &str) {
+    let mut column = column(diagram, table_name, "MEMBER_ID").clone();
+    column.physical_name = name.to_string();
+    column.logical_name = Some(name.to_lowercase());
+    column.primary_key = None;
+    column.auto_increment = None;
+    column.referred_column = None;
+    column.relationship = None;
+
+    table_mut(diagram, table_name)
+        .columns
+        .items
+        .as_mut()
+        .expect("table has columns")
+        .insert(index, ColumnItem::Normal(column));
+}
+
+fn remove_column(diagram: &mut Diagram, table_name: &str, name: &str) {
+    table_mut(diagram, table_name)
+        .columns
+        .items
+        .as_mut()
+        .expect("table has columns")
+        .retain(|item| !matches!(item, ColumnItem::Normal(column) if column.physical_name == name));
+}
+
+fn column_keys(table: &Table) -> Vec<&str> {
+    table
+        .columns
+        .items
+        .iter()
+        .flatten()
+        .map(|item| match item {
+            ColumnItem::Normal(column) => column.physical_name.as_str(),
+            ColumnItem::Group(group) => group.as_str(),
+        })
+        .collect()
+}
@@ -0,0 +1,85 @@
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+use crate::open::support;
+
+const DIAGRAM_WALKERS_DETAILS_FIXTURE: &str =
+    "./tests/open/fixtures/diagram/diagram_walkers_details.erm";
+const TEMP_PREFIX: &str = "erm_error_payload";
+const ASSERTIONS: support::FixtureAssertions =
+    support::FixtureAssertions::new(DIAGRAM_WALKERS_DETAILS_FIXTURE, TEMP_PREFIX, "      ");
+
+#[test]
+fn validation_errors_serialize_with_their_targets() {
+    let error = ASSERTIONS
+        .open_replaced_fixture(
+            "<fk_column_name>MEMBER_ID</fk_column_name>",
+            "<fk_column_name>UNKNOWN_ID</fk_column_name>",
+            "validation_errors_serialize_with_their_targets",
+        )
+        .expect_err("expected validation errors");
+
+    let payload = serde_json::to_value(&error).expect("failed to serialize");
+
+    assert_eq!(payload["code"], "E_VALIDATION");
+    assert_eq!(payload["kind"], "validation");
+    assert_eq!(payload["message"], error.to_string());
+    assert_eq!(payload["span"], json!(null));
+    assert_eq!(
+        payload["errors"][0],
+        json!({
+            "code": "ERM0019",
+            "kind": "validation",
+            "severity": "error",
+            "path": "diagram_walkers.table[0].connections.relationship[0].fk_columns.fk_column[0].fk_column_name",
+            "message": "unknown relationship fk_column_name: UNKNOWN_ID",
+            "targets": [
+                { "label": "table name", "value": "MEMBERS" },
+                { "label": "relationship name", "value": "FK_MEMBERS_PARENT" },
+            ],
+            "span": { "start": 1575, "end": 1618, "line": 52, "column": 15 },
+        })
+    );
+}
+
+#[test]
+fn parse_errors_serialize_with_their_location() {
+    let error = ASSERTIONS
+        .open_replaced_fixture(
+            "<length>18</length>",
+            "<length>eighteen</length>",
+            "parse_errors_serialize_with_their_location",
+        )
+        .expect_err("expected parse error");
+
+    let payload = serde_json::to_value(&error).expect("failed to serialize");
+
+    assert_eq!(payload["code"], "E_PARSE");
+    assert_eq!(payload["kind"], "parse");
+    assert_eq!(payload["span"]["line"], 72);
+    assert_eq!(payload["errors"], json!([]));
+}
+
+#[test]
+fn io_errors_serialize_without_a_location() {
+    let error = erm::open("./tests/open/fixtures/missing.erm").expect_err("expected io error");
+
+    let payload = serde_json::to_value(&error).expect("failed to serialize");
+
+    assert_eq!(payload["code"], "E_IO");
+    assert_eq!(payload["kind"], "io");
+    assert_eq!(payload["span"], json!(null));
+    assert_eq!(payload["errors"], json!([]));
+    assert_eq!(payload["conflicts"], json!([]));
+}
+
+#[test]
+fn warnings_serialize_with_their_severity() {
+    let opened = erm::open(DIAGRAM_WALKERS_DETAILS_FIXTURE).expect("failed to parse");
+
+    let payload = serde_json::to_value(&opened.warnings[2]).expect("failed to serialize");
+
+    assert_eq!(payload["code"], "ERM0037");
+    assert_eq!(payload["severity"], "warning");
+    assert_eq!(payload["targets"][0]["value"], "PARENT_MEMBERS");
+}
@@ -1,4 +1,6 @@
+pub mod error_payload;
 pub mod parse;
 pub mod read_snapshot;
+pub mod source_spans;
 mod support;
 pub mod validation;
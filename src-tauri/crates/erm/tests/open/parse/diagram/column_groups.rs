@@ -12,7 +12,9 @@ const ASSERTIONS: support::FixtureAssertions =
 
 #[test]
 fn column_groups_tags_keep_valid_values() {
-    let diagram = open(COLUMN_GROUPS_FIXTURE).expect("failed to parse");
+    let diagram = open(COLUMN_GROUPS_FIXTURE)
+        .expect("failed to parse")
+        .diagram;
 
     assert_eq!(
         diagram.column_groups,
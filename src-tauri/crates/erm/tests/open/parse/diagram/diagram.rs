@@ -12,7 +12,7 @@ const ASSERTIONS: support::FixtureAssertions =
 
 #[test]
 fn diagram_tags_keep_valid_values() {
-    let diagram = open(DIAGRAM_FIXTURE).expect("failed to parse");
+    let diagram = open(DIAGRAM_FIXTURE).expect("failed to parse").diagram;
 
     assert_eq!(diagram.presenter, Some("ERFlute".to_string()));
     assert_eq!(diagram.category_index, Some(2));
@@ -12,7 +12,9 @@ const ASSERTIONS: support::FixtureAssertions =
 
 #[test]
 fn diagram_settings_tags_keep_valid_values() {
-    let diagram = open(DIAGRAM_SETTINGS_FIXTURE).expect("failed to parse");
+    let diagram = open(DIAGRAM_SETTINGS_FIXTURE)
+        .expect("failed to parse")
+        .diagram;
 
     assert_eq!(
         diagram.diagram_settings,
@@ -33,12 +35,42 @@ fn diagram_settings_tags_keep_valid_values() {
             title_font_em: Some(1.5),
             master_data_base_path: Some("master.db".to_string()),
             use_view_object: true,
-            export_settings: diagram_settings::ExportSettings {},
+            export_settings: diagram_settings::ExportSettings {
+                category_name_to_export: Some("main".to_string()),
+                ddl_output: Some("ddl".to_string()),
+                excel_output: Some("excel".to_string()),
+                excel_template: Some("template".to_string()),
+                image_output: Some("image".to_string()),
+                put_diagram_on_excel: Some(true),
+                use_logical_name_as_sheet: Some(false),
+                open_after_saved: Some(false),
+                create_comment: Some(true),
+                create_foreign_key: Some(true),
+                create_index: Some(true),
+                create_sequence: Some(true),
+                create_table: Some(true),
+                create_tablespace: Some(true),
+                create_trigger: Some(true),
+                create_view: Some(true),
+                drop_index: Some(false),
+                drop_sequence: Some(false),
+                drop_table: Some(false),
+                drop_tablespace: Some(false),
+                drop_trigger: Some(false),
+                drop_view: Some(false),
+                inline_column_comment: Some(false),
+                inline_table_comment: Some(false),
+                comment_value_description: Some(false),
+                comment_value_logical_name: Some(false),
+                comment_value_logical_name_description: Some(true),
+                comment_replace_line_feed: Some(false),
+                comment_replace_string: Some("line".to_string()),
+            },
             category_settings: diagram_settings::CategorySettings {},
             model_properties: diagram_settings::ModelProperties {},
             table_properties: diagram_settings::TableProperties {},
             environment_settings: Some(diagram_settings::EnvironmentSettings {}),
-            design_settings: Some(diagram_settings::DesignSettings {}),
+            design_settings: Some(diagram_settings::DesignSettings::default()),
         }
     );
 }
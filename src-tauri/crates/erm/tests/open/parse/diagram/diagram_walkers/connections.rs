@@ -35,6 +35,7 @@ fn connections_tags_keep_valid_values() {
                 ),
                 referred_compound_unique_key: None,
             }]),
+            comment_connections: None,
         }
     );
 }
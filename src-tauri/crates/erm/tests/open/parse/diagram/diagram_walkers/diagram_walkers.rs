@@ -13,7 +13,9 @@ const ASSERTIONS: support::FixtureAssertions =
 
 #[test]
 fn diagram_walkers_table_tags_keep_valid_values() {
-    let diagram = open(DIAGRAM_WALKERS_FIXTURE).expect("failed to parse");
+    let diagram = open(DIAGRAM_WALKERS_FIXTURE)
+        .expect("failed to parse")
+        .diagram;
     let diagram_walkers = diagram.diagram_walkers.expect("missing diagram walkers");
     let tables = diagram_walkers.tables.expect("missing tables");
 
@@ -37,6 +39,7 @@ fn diagram_walkers_table_tags_keep_valid_values() {
             },
             connections: tables::connections::Connections {
                 relationships: None,
+                comment_connections: None,
             },
             table_constraint: Some("ENGINE=InnoDB".to_string()),
             primary_key_name: Some("PK_MEMBERS".to_string()),
@@ -75,6 +78,7 @@ fn diagram_walkers_table_tags_keep_valid_values() {
             },
             connections: tables::connections::Connections {
                 relationships: None,
+                comment_connections: None,
             },
             table_constraint: None,
             primary_key_name: None,
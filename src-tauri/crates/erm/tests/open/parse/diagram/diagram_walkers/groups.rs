@@ -0,0 +1,105 @@
+use pretty_assertions::assert_eq;
+
+use erm::dtos::diagram::diagram_walkers::groups;
+use erm::open;
+
+use crate::open::support;
+
+const GROUPS_FIXTURE: &str = "./tests/open/fixtures/diagram/groups.erm";
+const TEMP_PREFIX: &str = "erm_groups";
+const ASSERTIONS: support::FixtureAssertions =
+    support::FixtureAssertions::new(GROUPS_FIXTURE, TEMP_PREFIX, "      ");
+
+#[test]
+fn walker_group_tags_keep_valid_values() {
+    let diagram = open(GROUPS_FIXTURE).expect("failed to parse").diagram;
+    let groups = diagram
+        .diagram_walkers
+        .expect("missing diagram walkers")
+        .groups
+        .expect("missing groups");
+
+    assert_eq!(
+        groups,
+        vec![groups::WalkerGroup {
+            id: None,
+            height: None,
+            width: None,
+            font_name: None,
+            font_size: None,
+            x: Some(140),
+            y: Some(80),
+            color: None,
+            connections: None,
+            walker_group_name: Some("Membership".to_string()),
+            members: Some(vec![
+                "table.MEMBERS".to_string(),
+                "view.MEMBER_NAMES".to_string(),
+            ]),
+        }]
+    );
+}
+
+#[test]
+fn legacy_group_tags_are_accepted() {
+    for (target, replacement, test_name) in [
+        ("walker_group>", "group>", "legacy_group_tag"),
+        ("walker_group_name>", "name>", "legacy_group_name_tag"),
+        (
+            "diagram_walker>",
+            "node_element>",
+            "legacy_group_member_tag",
+        ),
+    ] {
+        let diagram = ASSERTIONS
+            .open_replaced_fixture(target, replacement, test_name)
+            .expect("failed to parse");
+        let group = &diagram
+            .diagram_walkers
+            .and_then(|walkers| walkers.groups)
+            .expect("missing groups")[0];
+
+        assert_eq!(group.walker_group_name.as_deref(), Some("Membership"));
+        assert_eq!(group.members.as_ref().map(Vec::len), Some(2));
+    }
+}
+
+#[test]
+fn moving_a_group_moves_its_members() {
+    let mut walkers = open(GROUPS_FIXTURE)
+        .expect("failed to parse")
+        .diagram
+        .diagram_walkers
+        .expect("missing diagram walkers");
+
+    assert!(walkers.move_group("Membership", -150, 40));
+    assert!(!walkers.move_group("Billing", 10, 10));
+
+    let group = &walkers.groups.as_ref().expect("missing groups")[0];
+    assert_eq!((group.x, group.y), (Some(0), Some(120)));
+
+    let table = &walkers.tables.as_ref().expect("missing tables")[0];
+    assert_eq!((table.x, table.y), (10, 146));
+
+    let view = &walkers.views.as_ref().expect("missing views")[0];
+    assert_eq!((view.x, view.y), (Some(270), Some(146)));
+}
+
+#[test]
+fn removing_a_group_keeps_its_members() {
+    let mut walkers = open(GROUPS_FIXTURE)
+        .expect("failed to parse")
+        .diagram
+        .diagram_walkers
+        .expect("missing diagram walkers");
+
+    let removed = walkers
+        .remove_group("Membership")
+        .expect("missing removed group");
+
+    assert_eq!(removed.walker_group_name.as_deref(), Some("Membership"));
+    assert_eq!(walkers.groups, Some(Vec::new()));
+    assert!(walkers.remove_group("Membership").is_none());
+    assert_eq!(walkers.tables.map(|tables| tables.len()), Some(1));
+    assert_eq!(walkers.views.map(|views| views.len()), Some(1));
+}
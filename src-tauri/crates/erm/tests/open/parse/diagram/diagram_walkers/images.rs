@@ -0,0 +1,115 @@
+use pretty_assertions::assert_eq;
+
+use erm::dtos::diagram::diagram_walkers::images::{self, ImageFormat};
+use erm::{open, open_image};
+
+use crate::open::support;
+
+const IMAGES_FIXTURE: &str = "./tests/open/fixtures/diagram/images.erm";
+const TEMP_PREFIX: &str = "erm_images";
+const ASSERTIONS: support::FixtureAssertions =
+    support::FixtureAssertions::new(IMAGES_FIXTURE, TEMP_PREFIX, "      ");
+const PNG_DATA: &str = "iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAYAAAAfFcSJAAAADUlEQVR42mNk+M9QDwADhgGAWjR9awAAAABJRU5ErkJggg==";
+
+#[test]
+fn image_tags_keep_valid_values() {
+    let diagram = open(IMAGES_FIXTURE).expect("failed to parse").diagram;
+    let images = diagram
+        .diagram_walkers
+        .expect("missing diagram walkers")
+        .images
+        .expect("missing images");
+
+    assert!(images[0].data_digest.is_some());
+    assert_eq!(
+        images,
+        vec![images::Image {
+            id: Some("image.1".to_string()),
+            height: Some(48),
+            width: Some(64),
+            font_name: None,
+            font_size: None,
+            x: Some(420),
+            y: Some(106),
+            color: None,
+            connections: None,
+            data: PNG_DATA.to_string(),
+            data_digest: images[0].data_digest.clone(),
+            format: Some(ImageFormat::Png),
+            hue: 0,
+            saturation: 0,
+            brightness: 0,
+            alpha: Some(255),
+            fix_aspect_ratio: Some(true),
+        }]
+    );
+}
+
+#[test]
+fn image_formats_are_sniffed_from_the_data() {
+    for (data, format, test_name) in [
+        ("/9j/4AAQSkZJRgABAQ==", ImageFormat::Jpeg, "jpeg_image"),
+        ("R0lGODlhAQABAAAAACw=", ImageFormat::Gif, "gif_image"),
+        (
+            "iVBORw0KGgoAAAAN\n        SUhEUgAAAAEAAAAB",
+            ImageFormat::Png,
+            "wrapped_png_image",
+        ),
+    ] {
+        let diagram = ASSERTIONS
+            .open_replaced_fixture(PNG_DATA, data, test_name)
+            .expect("failed to parse");
+        let image = &diagram
+            .diagram_walkers
+            .and_then(|walkers| walkers.images)
+            .expect("missing images")[0];
+
+        assert_eq!(image.format, Some(format));
+    }
+}
+
+#[test]
+fn legacy_inserted_image_tag_is_accepted() {
+    let diagram = ASSERTIONS
+        .open_replaced_fixture("image>", "inserted_image>", "legacy_inserted_image_tag")
+        .expect("failed to parse");
+
+    assert_eq!(
+        diagram
+            .diagram_walkers
+            .and_then(|walkers| walkers.images)
+            .map(|images| images.len()),
+        Some(1)
+    );
+}
+
+#[test]
+fn image_without_hue_is_rejected() {
+    ASSERTIONS.assert_removed_line_parse_error("hue", "image_without_hue");
+}
+
+#[test]
+fn image_data_is_left_out_of_the_diagram_payload() {
+    let diagram = open(IMAGES_FIXTURE).expect("failed to parse").diagram;
+
+    let payload = serde_json::to_value(&diagram).expect("failed to serialize");
+    let image = &payload["diagramWalkers"]["images"][0];
+    assert_eq!(image["format"], "png");
+    assert!(image.get("data").is_none());
+}
+
+#[test]
+fn image_data_is_opened_on_demand() {
+    let image = open_image(IMAGES_FIXTURE, 0)
+        .expect("failed to parse")
+        .expect("missing image");
+
+    assert_eq!(image.format, ImageFormat::Png);
+    assert_eq!(image.format.mime_type(), "image/png");
+    assert_eq!(image.data, PNG_DATA);
+    assert!(
+        open_image(IMAGES_FIXTURE, 1)
+            .expect("failed to parse")
+            .is_none()
+    );
+}
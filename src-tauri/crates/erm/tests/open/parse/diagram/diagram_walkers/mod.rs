@@ -2,6 +2,10 @@ pub mod columns;
 pub mod compound_unique_key_list;
 pub mod connections;
 pub mod diagram_walkers;
+pub mod groups;
+pub mod images;
 pub mod indexes;
+pub mod notes;
 mod support;
 pub mod table_properties;
+pub mod views;
@@ -0,0 +1,79 @@
+use pretty_assertions::assert_eq;
+
+use erm::dtos::diagram::diagram_walkers::{notes, tables};
+use erm::open;
+
+use crate::open::support;
+
+const NOTES_FIXTURE: &str = "./tests/open/fixtures/diagram/notes.erm";
+const TEMP_PREFIX: &str = "erm_notes";
+const ASSERTIONS: support::FixtureAssertions =
+    support::FixtureAssertions::new(NOTES_FIXTURE, TEMP_PREFIX, "      ");
+
+#[test]
+fn walker_note_tags_keep_valid_values() {
+    let diagram = open(NOTES_FIXTURE).expect("failed to parse").diagram;
+    let notes = diagram
+        .diagram_walkers
+        .expect("missing diagram walkers")
+        .notes
+        .expect("missing notes");
+
+    assert_eq!(
+        notes,
+        vec![notes::WalkerNote {
+            id: Some("note.1".to_string()),
+            height: Some(60),
+            width: Some(180),
+            font_name: Some("Ubuntu".to_string()),
+            font_size: Some(9),
+            x: Some(40),
+            y: Some(20),
+            color: Some(tables::Color {
+                r: 255,
+                g: 255,
+                b: 206,
+            }),
+            connections: Some(tables::connections::Connections {
+                relationships: None,
+                comment_connections: Some(vec![tables::connections::CommentConnection {
+                    id: Some("comment.1".to_string()),
+                    source: "note.1".to_string(),
+                    target: "table.MEMBERS".to_string(),
+                    bendpoints: Some(vec![tables::connections::Bendpoint {
+                        relative: false,
+                        x: 120,
+                        y: 60,
+                    }]),
+                }]),
+            }),
+            note_text: Some("Members are never deleted,\nonly deactivated.".to_string()),
+        }]
+    );
+}
+
+#[test]
+fn legacy_note_tags_are_accepted() {
+    for (target, replacement, test_name) in [
+        ("walker_note>", "note>", "legacy_note_tag"),
+        ("note_text>", "text>", "legacy_note_text_tag"),
+    ] {
+        let diagram = ASSERTIONS
+            .open_replaced_fixture(target, replacement, test_name)
+            .expect("failed to parse");
+        let note = &diagram
+            .diagram_walkers
+            .and_then(|walkers| walkers.notes)
+            .expect("missing notes")[0];
+
+        assert_eq!(
+            note.note_text.as_deref(),
+            Some("Members are never deleted,\nonly deactivated.")
+        );
+    }
+}
+
+#[test]
+fn comment_connection_without_target_is_rejected() {
+    ASSERTIONS.assert_removed_line_parse_error("target", "comment_connection_without_target");
+}
@@ -10,7 +10,9 @@ pub(super) const DIAGRAM_WALKERS_DETAILS_FIXTURE: &str =
 const TEMP_PREFIX: &str = "erm_diagram_walkers_details";
 
 pub(super) fn first_table() -> tables::Table {
-    let diagram = open(DIAGRAM_WALKERS_DETAILS_FIXTURE).expect("failed to parse");
+    let diagram = open(DIAGRAM_WALKERS_DETAILS_FIXTURE)
+        .expect("failed to parse")
+        .diagram;
     diagram
         .diagram_walkers
         .expect("missing diagram walkers")
@@ -6,7 +6,9 @@ use super::support;
 
 #[test]
 fn table_properties_detail_tags_are_accepted() {
-    let diagram = open(support::DIAGRAM_WALKERS_DETAILS_FIXTURE).expect("failed to parse");
+    let diagram = open(support::DIAGRAM_WALKERS_DETAILS_FIXTURE)
+        .expect("failed to parse")
+        .diagram;
     let table = diagram
         .diagram_walkers
         .expect("missing diagram walkers")
@@ -0,0 +1,86 @@
+use pretty_assertions::assert_eq;
+
+use erm::dtos::diagram::diagram_walkers::{tables, views};
+use erm::open;
+
+use crate::open::support;
+
+const VIEWS_FIXTURE: &str = "./tests/open/fixtures/diagram/views.erm";
+const TEMP_PREFIX: &str = "erm_views";
+const ASSERTIONS: support::FixtureAssertions =
+    support::FixtureAssertions::new(VIEWS_FIXTURE, TEMP_PREFIX, "      ");
+
+#[test]
+fn view_tags_keep_valid_values() {
+    let diagram = open(VIEWS_FIXTURE).expect("failed to parse").diagram;
+    let views = diagram
+        .diagram_walkers
+        .expect("missing diagram walkers")
+        .views
+        .expect("missing views");
+
+    assert_eq!(
+        views,
+        vec![views::View {
+            physical_name: "MEMBER_NAMES".to_string(),
+            logical_name: "Member Names".to_string(),
+            description: "Members listed by name".to_string(),
+            height: Some(72),
+            width: Some(160),
+            font_name: Some("Ubuntu".to_string()),
+            font_size: Some(9),
+            x: Some(420),
+            y: Some(106),
+            color: Some(tables::Color {
+                r: 200,
+                g: 220,
+                b: 240,
+            }),
+            connections: Some(tables::connections::Connections {
+                relationships: None,
+                comment_connections: None,
+            }),
+            sql: "SELECT MEMBER_ID, MEMBER_NAME\nFROM MEMBERS\nORDER BY MEMBER_NAME;".to_string(),
+            columns: tables::columns::Columns {
+                items: Some(vec![
+                    tables::columns::ColumnItem::Normal(tables::columns::NormalColumn {
+                        physical_name: "MEMBER_ID".to_string(),
+                        referred_column: Some("table.MEMBERS.MEMBER_ID".to_string()),
+                        ..Default::default()
+                    }),
+                    tables::columns::ColumnItem::Normal(tables::columns::NormalColumn {
+                        physical_name: "MEMBER_NAME".to_string(),
+                        logical_name: Some("Member Name".to_string()),
+                        column_type: Some(tables::columns::ColumnType::VarCharN),
+                        length: Some(100),
+                        ..Default::default()
+                    }),
+                ]),
+            },
+        }]
+    );
+}
+
+#[test]
+fn view_without_position_is_accepted() {
+    let diagram = ASSERTIONS
+        .open_replaced_fixture(
+            "      <font_name>Ubuntu</font_name>\n      <font_size>9</font_size>\n      <x>420</x>",
+            "",
+            "view_without_position",
+        )
+        .expect("failed to parse");
+    let view = &diagram
+        .diagram_walkers
+        .and_then(|walkers| walkers.views)
+        .expect("missing views")[0];
+
+    assert_eq!(view.font_name, None);
+    assert_eq!(view.x, None);
+    assert_eq!(view.y, Some(106));
+}
+
+#[test]
+fn view_without_sql_is_rejected() {
+    ASSERTIONS.assert_removed_element_parse_error("sql", "view_without_sql");
+}
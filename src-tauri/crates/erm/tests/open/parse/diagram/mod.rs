@@ -3,3 +3,4 @@ pub mod diagram;
 pub mod diagram_settings;
 pub mod diagram_walkers;
 pub mod page_settings;
+pub mod virtual_diagrams;
@@ -12,7 +12,9 @@ const ASSERTIONS: support::FixtureAssertions =
 
 #[test]
 fn page_settings_tags_keep_valid_values() {
-    let diagram = open(PAGE_SETTINGS_FIXTURE).expect("failed to parse");
+    let diagram = open(PAGE_SETTINGS_FIXTURE)
+        .expect("failed to parse")
+        .diagram;
 
     assert_eq!(
         diagram.page_settings,
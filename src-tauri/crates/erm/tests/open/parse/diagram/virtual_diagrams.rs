@@ -0,0 +1,194 @@
+use pretty_assertions::assert_eq;
+
+use erm::dtos::diagram::diagram_walkers::{groups, notes};
+use erm::dtos::diagram::{self, virtual_diagrams};
+use erm::errors::Error;
+use erm::{open, open_virtual_diagram};
+
+use crate::open::support;
+
+const VIRTUAL_DIAGRAMS_FIXTURE: &str = "./tests/open/fixtures/diagram/virtual_diagrams.erm";
+const TEMP_PREFIX: &str = "erm_virtual_diagrams";
+const ASSERTIONS: support::FixtureAssertions =
+    support::FixtureAssertions::new(VIRTUAL_DIAGRAMS_FIXTURE, TEMP_PREFIX, "      ");
+
+fn members_note() -> notes::WalkerNote {
+    notes::WalkerNote {
+        id: Some("note.1".to_string()),
+        height: None,
+        width: None,
+        font_name: None,
+        font_size: None,
+        x: Some(300),
+        y: Some(30),
+        color: None,
+        connections: None,
+        note_text: Some("Members without their parents.".to_string()),
+    }
+}
+
+fn core_group() -> groups::WalkerGroup {
+    groups::WalkerGroup {
+        id: None,
+        height: None,
+        width: None,
+        font_name: None,
+        font_size: None,
+        x: None,
+        y: None,
+        color: None,
+        connections: None,
+        walker_group_name: Some("Core".to_string()),
+        members: Some(vec!["table.MEMBERS".to_string()]),
+    }
+}
+
+fn virtual_table(table_id: &str, x: u16, y: u16) -> virtual_diagrams::VirtualTable {
+    virtual_diagrams::VirtualTable {
+        table_id: table_id.to_string(),
+        x: Some(x),
+        y: Some(y),
+        width: None,
+        height: None,
+        font_name: None,
+        font_size: None,
+    }
+}
+
+#[test]
+fn virtual_diagram_tags_keep_valid_values() {
+    let diagram = open(VIRTUAL_DIAGRAMS_FIXTURE)
+        .expect("failed to parse")
+        .diagram;
+
+    assert_eq!(
+        diagram.virtual_diagrams,
+        Some(vec![
+            virtual_diagrams::VirtualDiagram {
+                vdiagram_id: Some("vdiagram.1".to_string()),
+                vdiagram_name: Some("Members".to_string()),
+                color: Some(diagram::Color {
+                    r: 200,
+                    g: 220,
+                    b: 240,
+                }),
+                tables: Some(vec![virtual_diagrams::VirtualTable {
+                    font_size: Some(11),
+                    ..virtual_table("table.MEMBERS", 40, 30)
+                }]),
+                notes: Some(vec![members_note()]),
+                groups: Some(vec![core_group()]),
+            },
+            virtual_diagrams::VirtualDiagram {
+                vdiagram_id: None,
+                vdiagram_name: Some("Hierarchy".to_string()),
+                color: None,
+                tables: Some(vec![
+                    virtual_table("table.MEMBERS", 40, 200),
+                    virtual_table("table.PARENT_MEMBERS", 40, 30),
+                ]),
+                notes: None,
+                groups: None,
+            },
+        ])
+    );
+}
+
+#[test]
+fn legacy_virtual_diagram_tags_are_accepted() {
+    for (target, replacement, test_name) in [
+        ("vdiagrams>", "ermodels>", "legacy_ermodels_tag"),
+        ("vdiagram>", "ermodel>", "legacy_ermodel_tag"),
+        ("vdiagram_name>", "name>", "legacy_vdiagram_name_tag"),
+        ("table_id>", "id>", "legacy_vtable_id_tag"),
+        ("walker_notes>", "notes>", "legacy_notes_tag"),
+        ("walker_groups>", "groups>", "legacy_groups_tag"),
+    ] {
+        let diagram = ASSERTIONS
+            .open_replaced_fixture(target, replacement, test_name)
+            .expect("failed to parse");
+        let virtual_diagram = &diagram.virtual_diagrams.expect("missing virtual diagrams")[0];
+
+        assert_eq!(virtual_diagram.vdiagram_name.as_deref(), Some("Members"));
+        assert_eq!(
+            virtual_diagram
+                .tables
+                .as_ref()
+                .map(|tables| tables[0].table_id.as_str()),
+            Some("table.MEMBERS")
+        );
+        assert_eq!(virtual_diagram.notes, Some(vec![members_note()]));
+        assert_eq!(virtual_diagram.groups, Some(vec![core_group()]));
+    }
+}
+
+#[test]
+fn vtable_without_table_id_is_rejected() {
+    ASSERTIONS.assert_removed_line_parse_error("table_id", "vtable_without_table_id");
+}
+
+#[test]
+fn projection_keeps_the_tables_and_positions_of_the_virtual_diagram() {
+    let diagram = open_virtual_diagram(VIRTUAL_DIAGRAMS_FIXTURE, "Members")
+        .expect("failed to parse")
+        .diagram;
+    let walkers = diagram.diagram_walkers.expect("missing diagram walkers");
+    let tables = walkers.tables.expect("missing tables");
+
+    assert_eq!(diagram.current_ermodel.as_deref(), Some("Members"));
+    assert_eq!(
+        tables
+            .iter()
+            .map(|table| (
+                table.physical_name.as_str(),
+                table.x,
+                table.y,
+                table.font_size
+            ))
+            .collect::<Vec<_>>(),
+        vec![("MEMBERS", 40, 30, 11)]
+    );
+    assert_eq!(tables[0].connections.relationships, Some(Vec::new()));
+    assert_eq!(walkers.notes, Some(vec![members_note()]));
+    assert_eq!(walkers.groups, Some(vec![core_group()]));
+    assert_eq!(
+        diagram.virtual_diagrams.map(|diagrams| diagrams.len()),
+        Some(2)
+    );
+}
+
+#[test]
+fn projection_keeps_relationships_between_the_tables_shown() {
+    let diagram = open_virtual_diagram(VIRTUAL_DIAGRAMS_FIXTURE, "Hierarchy")
+        .expect("failed to parse")
+        .diagram;
+    let tables = diagram
+        .diagram_walkers
+        .and_then(|walkers| walkers.tables)
+        .expect("missing tables");
+
+    assert_eq!(
+        tables
+            .iter()
+            .map(|table| (table.physical_name.as_str(), table.x, table.y))
+            .collect::<Vec<_>>(),
+        vec![("MEMBERS", 40, 200), ("PARENT_MEMBERS", 40, 30)]
+    );
+    assert_eq!(
+        tables[0]
+            .connections
+            .relationships
+            .as_ref()
+            .map(|relationships| relationships[0].name.as_str()),
+        Some("FK_MEMBERS_PARENT")
+    );
+}
+
+#[test]
+fn projection_onto_an_unknown_virtual_diagram_is_an_error() {
+    let error = open_virtual_diagram(VIRTUAL_DIAGRAMS_FIXTURE, "Billing")
+        .expect_err("expected an unknown virtual diagram");
+
+    assert!(matches!(&error, Error::UnknownVirtualDiagram(name) if name == "Billing"));
+    assert_eq!(error.code(), "E_UNKNOWN_VIRTUAL_DIAGRAM");
+}
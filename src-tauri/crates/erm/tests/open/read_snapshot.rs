@@ -9,13 +9,16 @@ use erm::dtos::diagram::diagram_walkers::tables::columns;
 use erm::dtos::diagram::diagram_walkers::tables::compound_unique_key_list;
 use erm::dtos::diagram::diagram_walkers::tables::connections;
 use erm::dtos::diagram::page_settings;
+use erm::dtos::diagram::virtual_diagrams;
 use erm::open;
 
 // TODO: Add test cases of detailed condition for each field in https://github.com/s-kai273/erflute/issues/22
 
 #[test]
 fn test_read_snapshot() {
-    let diagram = open("./tests/open/fixtures/read_snapshot.erm").expect("failed to parse");
+    let diagram = open("./tests/open/fixtures/read_snapshot.erm")
+        .expect("failed to parse")
+        .diagram;
     assert_eq!(
         diagram,
         diagram::Diagram {
@@ -59,7 +62,37 @@ fn test_read_snapshot() {
                 title_font_em: Some(1.5),
                 master_data_base_path: Some("".to_string()),
                 use_view_object: false,
-                export_settings: diagram_settings::ExportSettings {},
+                export_settings: diagram_settings::ExportSettings {
+                    category_name_to_export: Some("".to_string()),
+                    ddl_output: Some("".to_string()),
+                    excel_output: Some("".to_string()),
+                    excel_template: Some("".to_string()),
+                    image_output: Some("".to_string()),
+                    put_diagram_on_excel: Some(false),
+                    use_logical_name_as_sheet: Some(false),
+                    open_after_saved: Some(false),
+                    create_comment: Some(true),
+                    create_foreign_key: Some(true),
+                    create_index: Some(true),
+                    create_sequence: Some(true),
+                    create_table: Some(true),
+                    create_tablespace: Some(true),
+                    create_trigger: Some(true),
+                    create_view: Some(true),
+                    drop_index: Some(false),
+                    drop_sequence: Some(false),
+                    drop_table: Some(false),
+                    drop_tablespace: Some(false),
+                    drop_trigger: Some(false),
+                    drop_view: Some(false),
+                    inline_column_comment: Some(false),
+                    inline_table_comment: Some(false),
+                    comment_value_description: Some(false),
+                    comment_value_logical_name: Some(false),
+                    comment_value_logical_name_description: Some(true),
+                    comment_replace_line_feed: Some(false),
+                    comment_replace_string: Some("".to_string()),
+                },
                 category_settings: diagram_settings::CategorySettings {},
                 model_properties: diagram_settings::ModelProperties {},
                 table_properties: diagram_settings::TableProperties {},
@@ -85,6 +118,7 @@ fn test_read_snapshot() {
                         },
                         connections: connections::Connections {
                             relationships: None,
+                            comment_connections: None,
                         },
                         table_constraint: None,
                         primary_key_name: None,
@@ -178,7 +212,8 @@ fn test_read_snapshot() {
                                     referred_simple_unique_column: None,
                                     referred_compound_unique_key: None,
                                 }
-                            ])
+                            ]),
+                            comment_connections: None,
                         },
                         table_constraint: None,
                         primary_key_name: None,
@@ -248,6 +283,7 @@ fn test_read_snapshot() {
                         },
                         connections: connections::Connections {
                             relationships: None,
+                            comment_connections: None,
                         },
                         table_constraint: None,
                         primary_key_name: None,
@@ -313,7 +349,8 @@ fn test_read_snapshot() {
                                 on_update_action: Some(connections::OnAction::Restrict),
                                 referred_simple_unique_column: None,
                                 referred_compound_unique_key: None,
-                            }])
+                            }]),
+                            comment_connections: None,
                         },
                         table_constraint: None,
                         primary_key_name: None,
@@ -456,7 +493,8 @@ fn test_read_snapshot() {
                                     referred_simple_unique_column: None,
                                     referred_compound_unique_key: None,
                                 },
-                            ])
+                            ]),
+                            comment_connections: None,
                         },
                         table_constraint: None,
                         primary_key_name: None,
@@ -553,7 +591,8 @@ fn test_read_snapshot() {
                                 on_update_action: Some(connections::OnAction::Restrict),
                                 referred_simple_unique_column: None,
                                 referred_compound_unique_key: None,
-                            }])
+                            }]),
+                            comment_connections: None,
                         },
                         table_constraint: None,
                         primary_key_name: None,
@@ -587,7 +626,27 @@ fn test_read_snapshot() {
                         },
                     }
                 ]),
+                views: None,
+                notes: None,
+                groups: None,
+                images: None,
             }),
+            virtual_diagrams: Some(vec![virtual_diagrams::VirtualDiagram {
+                vdiagram_id: None,
+                vdiagram_name: Some("sample".to_string()),
+                color: None,
+                tables: Some(vec![virtual_diagrams::VirtualTable {
+                    table_id: "table.MEMBERS".to_string(),
+                    x: Some(264),
+                    y: Some(182),
+                    width: None,
+                    height: None,
+                    font_name: Some("Ubuntu".to_string()),
+                    font_size: Some(9),
+                }]),
+                notes: None,
+                groups: None,
+            }]),
             column_groups: Some(vec![column_groups::ColumnGroup {
                 column_group_name: "COMMON".to_string(),
                 columns: column_groups::Columns {
@@ -622,7 +681,10 @@ fn test_read_snapshot() {
                         }
                     ])
                 }
-            }])
+            }]),
+            // Preservation of unmodelled elements is covered by the save tests.
+            unknown_elements: diagram.unknown_elements.clone(),
+            xml_layout: diagram.xml_layout.clone(),
         }
     )
 }
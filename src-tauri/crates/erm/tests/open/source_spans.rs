@@ -0,0 +1,116 @@
+use std::fs;
+
+use pretty_assertions::assert_eq;
+
+use erm::errors::{Error, SourceSpan};
+
+use crate::open::support;
+
+const DIAGRAM_WALKERS_DETAILS_FIXTURE: &str =
+    "./tests/open/fixtures/diagram/diagram_walkers_details.erm";
+const TEMP_PREFIX: &str = "erm_source_spans";
+const ASSERTIONS: support::FixtureAssertions =
+    support::FixtureAssertions::new(DIAGRAM_WALKERS_DETAILS_FIXTURE, TEMP_PREFIX, "      ");
+
+fn fixture() -> String {
+    fs::read_to_string(DIAGRAM_WALKERS_DETAILS_FIXTURE).expect("failed to read fixture")
+}
+
+#[test]
+fn validation_errors_point_at_the_offending_element() {
+    let result = ASSERTIONS.open_replaced_fixture(
+        "<fk_column_name>MEMBER_ID</fk_column_name>",
+        "<fk_column_name>UNKNOWN_ID</fk_column_name>",
+        "validation_errors_point_at_the_offending_element",
+    );
+
+    let Err(Error::Validation(errors)) = result else {
+        panic!("expected validation errors");
+    };
+    let error = &errors.errors[0];
+    let span = error.span.expect("missing source span");
+    let content = fixture().replace(
+        "<fk_column_name>MEMBER_ID</fk_column_name>",
+        "<fk_column_name>UNKNOWN_ID</fk_column_name>",
+    );
+
+    assert_eq!(
+        &content[span.start..span.end],
+        "<fk_column_name>UNKNOWN_ID</fk_column_name>"
+    );
+    assert_eq!((span.line, span.column), (52, 15));
+    assert!(
+        error
+            .to_string()
+            .ends_with("\n- location: line 52, column 15")
+    );
+}
+
+#[test]
+fn missing_elements_point_at_their_parent() {
+    let opened = erm::open(DIAGRAM_WALKERS_DETAILS_FIXTURE).expect("failed to parse");
+
+    let warning = opened
+        .warnings
+        .iter()
+        .find(|warning| warning.message == "column logical_name is empty: MEMBER_NAME")
+        .expect("missing warning");
+    let span = warning.span.expect("missing source span");
+
+    assert!(
+        fixture()[span.start..span.end]
+            .starts_with("<normal_column>\n          <physical_name>MEMBER_NAME</physical_name>")
+    );
+}
+
+#[test]
+fn syntax_errors_carry_their_location() {
+    let result = ASSERTIONS.open_replaced_fixture(
+        "<option>member option</option>",
+        "<option>member option</opt>",
+        "syntax_errors_carry_their_location",
+    );
+
+    let Err(Error::Parse { span, .. }) = result else {
+        panic!("expected parse error");
+    };
+    let span = span.expect("missing source span");
+
+    assert_eq!(span.line, 65);
+}
+
+#[test]
+fn invalid_values_point_at_their_element() {
+    let result = ASSERTIONS.open_replaced_fixture(
+        "<length>18</length>",
+        "<length>eighteen</length>",
+        "invalid_values_point_at_their_element",
+    );
+
+    let Err(error @ Error::Parse { span, .. }) = result else {
+        panic!("expected parse error");
+    };
+
+    assert_eq!(
+        span.map(|span| (span.line, span.column)),
+        Some((72, 11)),
+        "{error}"
+    );
+    assert!(error.to_string().ends_with("(at line 72, column 11)"));
+}
+
+#[test]
+fn spans_count_characters_for_columns() {
+    let result = ASSERTIONS.open_replaced_fixture(
+        "<physical_name>MEMBER_NAME</physical_name>",
+        "<physical_name>MEMBER_NAME</physical_name><!-- 名前 --><relationship>UNKNOWN</relationship>",
+        "spans_count_characters_for_columns",
+    );
+
+    let Err(Error::Validation(errors)) = result else {
+        panic!("expected validation errors");
+    };
+    let span: SourceSpan = errors.errors[0].span.expect("missing source span");
+
+    assert_eq!((span.line, span.column), (85, 64));
+}
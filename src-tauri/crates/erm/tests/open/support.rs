@@ -178,7 +178,7 @@ fn open_content(content: String, temp_prefix: &str, test_name: &str) -> Result<D
 
     fs::write(&path, content).expect("failed to write fixture");
 
-    let result = open(path.to_str().expect("invalid fixture path"));
+    let result = open(path.to_str().expect("invalid fixture path")).map(|opened| opened.diagram);
 
     fs::remove_file(&path).expect("failed to remove fixture");
     result
@@ -0,0 +1,58 @@
+use pretty_assertions::assert_eq;
+
+use erm::errors::Error;
+
+use crate::open::support;
+
+const DIAGRAM_WALKERS_DETAILS_FIXTURE: &str =
+    "./tests/open/fixtures/diagram/diagram_walkers_details.erm";
+const TEMP_PREFIX: &str = "erm_all_errors_validation";
+const ASSERTIONS: support::FixtureAssertions =
+    support::FixtureAssertions::new(DIAGRAM_WALKERS_DETAILS_FIXTURE, TEMP_PREFIX, "      ");
+
+#[test]
+fn every_validation_error_is_reported() {
+    let result = ASSERTIONS.open_replaced_fixture(
+        "<column_id>MEMBER_ID</column_id>",
+        "<column_id>UNKNOWN_ID</column_id>",
+        "every_validation_error_is_reported",
+    );
+
+    let Err(Error::Validation(errors)) = result else {
+        panic!("expected validation errors");
+    };
+    let errors = errors
+        .errors
+        .iter()
+        .map(|error| (error.path.as_str(), error.message.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        errors,
+        vec![
+            (
+                "diagram_walkers.table[0].indexes[0].columns[1].column_id",
+                "unknown index column_id: UNKNOWN_ID",
+            ),
+            (
+                "diagram_walkers.table[0].compound_unique_key_list.compound_unique_key[0].columns[1].column_id",
+                "unknown compound unique key column_id: UNKNOWN_ID",
+            ),
+        ]
+    );
+}
+
+#[test]
+fn validation_errors_are_numbered_in_the_message() {
+    let result = ASSERTIONS.open_replaced_fixture(
+        "<column_id>MEMBER_ID</column_id>",
+        "<column_id>UNKNOWN_ID</column_id>",
+        "validation_errors_are_numbered_in_the_message",
+    );
+
+    let Err(error) = result else {
+        panic!("expected validation errors");
+    };
+    let message = error.to_string();
+    assert!(message.starts_with("2 validation errors\n\n1. Validation error\n"));
+    assert!(message.contains("\n\n2. Validation error\n\nunknown compound unique key column_id"));
+}
@@ -0,0 +1,83 @@
+use pretty_assertions::assert_eq;
+
+use erm::errors::Error;
+
+use crate::open::validation::support::open_with_settings;
+
+const GROUPS_FIXTURE: &str = "./tests/open/fixtures/diagram/groups.erm";
+const TEMP_PREFIX: &str = "erm_groups_validation";
+
+#[test]
+fn unknown_and_repeated_group_members_are_rejected() {
+    let second_group = "    <walker_group>
+      <walker_group_name>Reporting</walker_group_name>
+      <diagram_walker>view.MEMBER_NAMES</diagram_walker>
+    </walker_group>
+  </diagram_walkers>";
+    let result = open_with_settings(
+        GROUPS_FIXTURE,
+        &[
+            (
+                "<diagram_walker>table.MEMBERS</diagram_walker>",
+                "<diagram_walker>table.ACCOUNTS</diagram_walker>",
+            ),
+            ("  </diagram_walkers>", second_group),
+        ],
+        None,
+        TEMP_PREFIX,
+        "unknown_and_repeated_group_members_are_rejected",
+    );
+
+    let Err(Error::Validation(errors)) = result else {
+        panic!("expected validation errors, got {result:?}");
+    };
+    let errors = errors
+        .errors
+        .iter()
+        .map(|error| {
+            (
+                error.code(),
+                error.path.as_str(),
+                error.message.as_str(),
+                error.target("group name"),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        errors,
+        vec![
+            (
+                "ERM0050",
+                "diagram_walkers.walker_group[0].diagram_walker[0]",
+                "unknown group member: table.ACCOUNTS",
+                Some("Membership"),
+            ),
+            (
+                "ERM0051",
+                "diagram_walkers.walker_group[1].diagram_walker[0]",
+                "view.MEMBER_NAMES already belongs to group Membership",
+                Some("Reporting"),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn groups_of_existing_walkers_are_passed_through() {
+    let opened = open_with_settings(
+        GROUPS_FIXTURE,
+        &[],
+        None,
+        TEMP_PREFIX,
+        "groups_of_existing_walkers_are_passed_through",
+    )
+    .expect("failed to parse");
+
+    assert!(opened.warnings.is_empty());
+
+    let payload = serde_json::to_value(&opened.diagram).expect("failed to serialize");
+    let group = &payload["diagramWalkers"]["groups"][0];
+    assert_eq!(group["walkerGroupName"], "Membership");
+    assert_eq!(group["members"][1], "view.MEMBER_NAMES");
+}
@@ -0,0 +1,59 @@
+use pretty_assertions::assert_eq;
+
+use erm::errors::Error;
+
+use crate::open::validation::support::open_with_settings;
+
+const IMAGES_FIXTURE: &str = "./tests/open/fixtures/diagram/images.erm";
+const TEMP_PREFIX: &str = "erm_images_validation";
+const PNG_DATA: &str = "iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAYAAAAfFcSJAAAADUlEQVR42mNk+M9QDwADhgGAWjR9awAAAABJRU5ErkJggg==";
+
+#[test]
+fn corrupt_image_data_is_rejected() {
+    for (data, message, test_name) in [
+        (
+            "iVBORw0K*Ggo=",
+            "image data is not valid base64: Invalid symbol 42, offset 8.",
+            "image_data_with_invalid_base64_is_rejected",
+        ),
+        (
+            "aGVsbG8gd29ybGQ=",
+            "image data is not a PNG, JPEG or GIF image",
+            "image_data_of_unknown_format_is_rejected",
+        ),
+    ] {
+        let result = open_with_settings(
+            IMAGES_FIXTURE,
+            &[(PNG_DATA, data)],
+            None,
+            TEMP_PREFIX,
+            test_name,
+        );
+
+        let Err(Error::Validation(errors)) = result else {
+            panic!("expected validation errors, got {result:?}");
+        };
+        let errors = errors
+            .errors
+            .iter()
+            .map(|error| {
+                (
+                    error.code(),
+                    error.path.as_str(),
+                    error.message.as_str(),
+                    error.target("image id"),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            errors,
+            vec![(
+                "ERM0052",
+                "diagram_walkers.image[0].data",
+                message,
+                Some("image.1"),
+            )]
+        );
+    }
+}
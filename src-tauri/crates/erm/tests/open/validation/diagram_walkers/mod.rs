@@ -1,2 +1,6 @@
 pub mod diagram_walkers;
+pub mod groups;
+pub mod images;
+pub mod notes;
 pub mod tables;
+pub mod views;
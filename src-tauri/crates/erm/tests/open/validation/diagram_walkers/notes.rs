@@ -0,0 +1,84 @@
+use pretty_assertions::assert_eq;
+
+use erm::errors::Error;
+
+use crate::open::validation::support::open_with_settings;
+
+const NOTES_FIXTURE: &str = "./tests/open/fixtures/diagram/notes.erm";
+const TEMP_PREFIX: &str = "erm_notes_validation";
+
+#[test]
+fn comment_connections_to_unknown_walkers_are_rejected() {
+    let result = open_with_settings(
+        NOTES_FIXTURE,
+        &[
+            ("<source>note.1</source>", "<source>note.2</source>"),
+            (
+                "<target>table.MEMBERS</target>",
+                "<target>view.MEMBERS</target>",
+            ),
+        ],
+        None,
+        TEMP_PREFIX,
+        "comment_connections_to_unknown_walkers_are_rejected",
+    );
+
+    let Err(Error::Validation(errors)) = result else {
+        panic!("expected validation errors, got {result:?}");
+    };
+    let errors = errors
+        .errors
+        .iter()
+        .map(|error| {
+            (
+                error.code(),
+                error.path.as_str(),
+                error.message.as_str(),
+                error.target("note id"),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        errors,
+        vec![
+            (
+                "ERM0049",
+                "diagram_walkers.walker_note[0].connections.comment_connection[0].source",
+                "unknown comment connection source: note.2",
+                Some("note.1"),
+            ),
+            (
+                "ERM0049",
+                "diagram_walkers.walker_note[0].connections.comment_connection[0].target",
+                "unknown comment connection target: view.MEMBERS",
+                Some("note.1"),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn comment_connections_to_existing_walkers_are_passed_through() {
+    let opened = open_with_settings(
+        NOTES_FIXTURE,
+        &[],
+        None,
+        TEMP_PREFIX,
+        "comment_connections_to_existing_walkers_are_passed_through",
+    )
+    .expect("failed to parse");
+
+    assert!(opened.warnings.is_empty());
+
+    let payload = serde_json::to_value(&opened.diagram).expect("failed to serialize");
+    let note = &payload["diagramWalkers"]["notes"][0];
+    assert_eq!(
+        note["noteText"],
+        "Members are never deleted,\nonly deactivated."
+    );
+    assert_eq!(
+        note["connections"]["commentConnections"][0]["target"],
+        "table.MEMBERS"
+    );
+}
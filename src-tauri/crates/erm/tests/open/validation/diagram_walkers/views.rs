@@ -0,0 +1,91 @@
+use crate::open::support;
+use crate::open::validation::support::{assert_validation_error_with_targets, open_with_settings};
+
+const VIEWS_FIXTURE: &str = "./tests/open/fixtures/diagram/views.erm";
+const TEMP_PREFIX: &str = "erm_views_validation";
+const ASSERTIONS: support::FixtureAssertions =
+    support::FixtureAssertions::new(VIEWS_FIXTURE, TEMP_PREFIX, "      ");
+
+#[test]
+fn view_sharing_a_table_physical_name_is_rejected() {
+    let result = ASSERTIONS.open_replaced_fixture(
+        "<physical_name>MEMBER_NAMES</physical_name>",
+        "<physical_name>MEMBERS</physical_name>",
+        "view_sharing_a_table_physical_name",
+    );
+
+    assert_validation_error_with_targets(
+        result,
+        "diagram_walkers.view[0].physical_name",
+        "duplicate view physical_name: MEMBERS",
+        &[("view name", "MEMBERS")],
+    );
+}
+
+#[test]
+fn duplicate_view_column_physical_name_is_rejected() {
+    let result = ASSERTIONS.open_replaced_fixture(
+        "<physical_name>MEMBER_ID</physical_name>\n          <referred_column>",
+        "<physical_name>MEMBER_NAME</physical_name>\n          <referred_column>",
+        "duplicate_view_column_physical_name",
+    );
+
+    assert_validation_error_with_targets(
+        result,
+        "diagram_walkers.view[0].columns.normal_column[1].physical_name",
+        "duplicate view column physical_name: MEMBER_NAME",
+        &[
+            ("view name", "MEMBER_NAMES"),
+            ("column name", "MEMBER_NAME"),
+        ],
+    );
+}
+
+#[test]
+fn unknown_view_referred_column_is_rejected() {
+    let result = ASSERTIONS.open_replaced_fixture(
+        "<referred_column>table.MEMBERS.MEMBER_ID</referred_column>",
+        "<referred_column>table.MEMBERS.MEMBER_CODE</referred_column>",
+        "unknown_view_referred_column",
+    );
+
+    assert_validation_error_with_targets(
+        result,
+        "diagram_walkers.view[0].columns.normal_column[0].referred_column",
+        "unknown referred column: table.MEMBERS.MEMBER_CODE",
+        &[("view name", "MEMBER_NAMES"), ("column name", "MEMBER_ID")],
+    );
+}
+
+#[test]
+fn unknown_view_relationship_is_rejected() {
+    let result = ASSERTIONS.open_replaced_fixture(
+        "<referred_column>table.MEMBERS.MEMBER_ID</referred_column>",
+        "<referred_column>table.MEMBERS.MEMBER_ID</referred_column>\n          <relationship>FK_UNKNOWN</relationship>",
+        "unknown_view_relationship",
+    );
+
+    assert_validation_error_with_targets(
+        result,
+        "diagram_walkers.view[0].columns.normal_column[0].relationship",
+        "unknown relationship: FK_UNKNOWN",
+        &[("view name", "MEMBER_NAMES"), ("column name", "MEMBER_ID")],
+    );
+}
+
+#[test]
+fn view_problems_can_be_suppressed_by_view_name() {
+    open_with_settings(
+        VIEWS_FIXTURE,
+        &[(
+            "<referred_column>table.MEMBERS.MEMBER_ID</referred_column>",
+            "<referred_column>table.UNKNOWN.MEMBER_ID</referred_column>",
+        )],
+        Some(
+            "<validation><suppressions><suppression><table>MEMBER_NAMES</table></suppression></suppressions></validation>",
+        ),
+        TEMP_PREFIX,
+        "view_problems_can_be_suppressed_by_view_name",
+    )
+    .expect("failed to parse");
+}
@@ -1,3 +1,9 @@
+pub mod all_errors;
 pub mod column_groups;
 pub mod diagram_walkers;
+pub mod naming;
+pub mod rules;
+pub mod settings;
 mod support;
+pub mod virtual_diagrams;
+pub mod warnings;
@@ -0,0 +1,242 @@
+use pretty_assertions::assert_eq;
+
+use erm::OpenedDiagram;
+use erm::lint::{self, Case, CharacterSet, Dialect, NamingConventions};
+
+use crate::open::validation::support::{open_with_settings, warning_codes};
+
+const DIAGRAM_WALKERS_DETAILS_FIXTURE: &str =
+    "./tests/open/fixtures/diagram/diagram_walkers_details.erm";
+const TEMP_PREFIX: &str = "erm_naming_validation";
+
+fn open_fixture(replacements: &[(&str, &str)], test_name: &str) -> OpenedDiagram {
+    open_with_settings(
+        DIAGRAM_WALKERS_DETAILS_FIXTURE,
+        replacements,
+        None,
+        TEMP_PREFIX,
+        test_name,
+    )
+    .expect("failed to parse")
+}
+
+fn naming_warnings(opened: &OpenedDiagram) -> Vec<(&str, &str, &str)> {
+    opened
+        .warnings
+        .iter()
+        .filter(|warning| lint::RULES.contains(warning.rule))
+        .map(|warning| {
+            (
+                warning.code(),
+                warning.path.as_str(),
+                warning.message.as_str(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn conventions_follow_the_diagram_settings() {
+    let opened = open_fixture(
+        &[(
+            "<table_properties />\n  </diagram_settings>",
+            "<table_properties />
+    <design_settings>
+      <foreign_key><default_prefix>FK_</default_prefix></foreign_key>
+      <unique><default_prefix /></unique>
+    </design_settings>
+  </diagram_settings>",
+        )],
+        "conventions_follow_the_diagram_settings",
+    );
+
+    let conventions = NamingConventions::new(&opened.diagram.diagram_settings);
+
+    assert_eq!(
+        conventions,
+        NamingConventions {
+            case: Some(Case::Upper),
+            character_set: CharacterSet::Unicode,
+            max_length: Some(64),
+            reserved_words: Dialect::MySql.reserved_words(),
+            foreign_key_prefix: Some("FK_".to_string()),
+            unique_prefix: None,
+            index_prefix: None,
+        }
+    );
+}
+
+#[test]
+fn dialects_are_read_from_the_database_setting() {
+    assert_eq!(Dialect::from_database("PostgreSQL"), Dialect::PostgreSql);
+    assert_eq!(Dialect::from_database("SQLServer 2008"), Dialect::SqlServer);
+    assert_eq!(Dialect::from_database("HSQLDB"), Dialect::Standard);
+    assert_eq!(Dialect::Oracle.max_identifier_length(), Some(30));
+    assert_eq!(Dialect::Sqlite.max_identifier_length(), None);
+}
+
+#[test]
+fn names_in_the_wrong_case_are_reported() {
+    let opened = open_fixture(
+        &[("PARENT_MEMBER_CODE", "parent_member_code")],
+        "names_in_the_wrong_case_are_reported",
+    );
+
+    assert_eq!(
+        naming_warnings(&opened),
+        vec![(
+            "ERM0043",
+            "diagram_walkers.table[1].columns.normal_column[1].physical_name",
+            "column physical_name is not UPPER_CASE: parent_member_code",
+        )]
+    );
+    assert_eq!(
+        opened
+            .warnings
+            .last()
+            .and_then(|warning| warning.span)
+            .map(|span| span.line),
+        Some(149)
+    );
+}
+
+#[test]
+fn lower_case_names_are_expected_without_capital() {
+    let opened = open_fixture(
+        &[("<capital>true</capital>", "<capital>false</capital>")],
+        "lower_case_names_are_expected_without_capital",
+    );
+
+    let warnings = naming_warnings(&opened);
+
+    assert!(!warnings.is_empty());
+    assert!(
+        warnings.iter().all(|(code, _, message)| {
+            *code == "ERM0043" && message.contains("is not snake_case")
+        })
+    );
+}
+
+#[test]
+fn names_longer_than_the_database_allows_are_reported() {
+    let long_name = "PARENT_MEMBER_CODE_".repeat(4);
+    let opened = open_fixture(
+        &[
+            (
+                "<database>MySQL</database>",
+                "<database>PostgreSQL</database>",
+            ),
+            ("PARENT_MEMBER_CODE", &long_name),
+        ],
+        "names_longer_than_the_database_allows_are_reported",
+    );
+
+    assert_eq!(
+        naming_warnings(&opened),
+        vec![(
+            "ERM0044",
+            "diagram_walkers.table[1].columns.normal_column[1].physical_name",
+            format!("column physical_name is longer than 63 characters: {long_name}").as_str(),
+        )]
+    );
+}
+
+#[test]
+fn reserved_words_are_reported() {
+    let opened = open_fixture(
+        &[("PARENT_MEMBER_CODE", "ORDER")],
+        "reserved_words_are_reported",
+    );
+
+    assert_eq!(
+        naming_warnings(&opened),
+        vec![(
+            "ERM0045",
+            "diagram_walkers.table[1].columns.normal_column[1].physical_name",
+            "column physical_name is a reserved word: ORDER",
+        )]
+    );
+}
+
+#[test]
+fn constraint_names_without_their_prefix_are_reported() {
+    let opened = open_fixture(
+        &[(
+            "<table_properties />\n  </diagram_settings>",
+            "<table_properties />
+    <design_settings>
+      <foreign_key><default_prefix>FK_</default_prefix></foreign_key>
+      <unique><default_prefix>UQ_</default_prefix></unique>
+      <index><default_prefix>IX_</default_prefix></index>
+    </design_settings>
+  </diagram_settings>",
+        )],
+        "constraint_names_without_their_prefix_are_reported",
+    );
+
+    assert_eq!(
+        naming_warnings(&opened),
+        vec![
+            (
+                "ERM0046",
+                "diagram_walkers.table[0].indexes[0].name",
+                "index name does not start with IX_: IDX_MEMBERS_NAME",
+            ),
+            (
+                "ERM0046",
+                "diagram_walkers.table[0].compound_unique_key_list.compound_unique_key[0].name",
+                "compound unique key name does not start with UQ_: UK_MEMBERS_NAME",
+            ),
+            (
+                "ERM0046",
+                "diagram_walkers.table[1].compound_unique_key_list.compound_unique_key[0].name",
+                "compound unique key name does not start with UQ_: UK_PARENT_MEMBERS_CODE",
+            ),
+        ]
+    );
+}
+
+#[test]
+fn validate_physical_name_off_turns_off_every_naming_rule() {
+    let opened = open_fixture(
+        &[
+            (
+                "<validate_physical_name>true</validate_physical_name>",
+                "<validate_physical_name>false</validate_physical_name>",
+            ),
+            ("PARENT_MEMBER_CODE", "order"),
+        ],
+        "validate_physical_name_off_turns_off_every_naming_rule",
+    );
+
+    assert_eq!(naming_warnings(&opened), Vec::new());
+    assert_eq!(
+        warning_codes(&opened),
+        vec![
+            "ERM0039", "ERM0041", "ERM0037", "ERM0039", "ERM0041", "ERM0041"
+        ]
+    );
+}
+
+#[test]
+fn conventions_can_be_changed_before_linting() {
+    let opened = open_fixture(
+        &[("PARENT_MEMBER_CODE", "親会員コード")],
+        "conventions_can_be_changed_before_linting",
+    );
+    assert_eq!(naming_warnings(&opened), Vec::new());
+
+    let conventions = NamingConventions {
+        character_set: CharacterSet::Ascii,
+        ..NamingConventions::new(&opened.diagram.diagram_settings)
+    };
+    let messages = lint::lint(&opened.diagram, &conventions)
+        .into_iter()
+        .map(|error| error.message)
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        messages,
+        vec!["invalid column physical_name: 親会員コード".to_string()]
+    );
+}
@@ -0,0 +1,78 @@
+use std::collections::HashSet;
+
+use pretty_assertions::assert_eq;
+
+use erm::errors::{Error, RULES, Rule, Severity};
+
+use crate::open::support;
+
+const DIAGRAM_WALKERS_DETAILS_FIXTURE: &str =
+    "./tests/open/fixtures/diagram/diagram_walkers_details.erm";
+const TEMP_PREFIX: &str = "erm_rules_validation";
+const ASSERTIONS: support::FixtureAssertions =
+    support::FixtureAssertions::new(DIAGRAM_WALKERS_DETAILS_FIXTURE, TEMP_PREFIX, "      ");
+
+#[test]
+fn rule_codes_are_unique_and_numbered_in_order() {
+    let codes = RULES.iter().map(|rule| rule.code).collect::<Vec<_>>();
+    let expected = (1..=RULES.len())
+        .map(|number| format!("ERM{number:04}"))
+        .collect::<Vec<_>>();
+
+    assert_eq!(codes, expected);
+    assert_eq!(codes.iter().collect::<HashSet<_>>().len(), RULES.len());
+    assert!(RULES.iter().all(|rule| !rule.description.is_empty()));
+}
+
+#[test]
+fn rules_are_found_by_code() {
+    let rule = Rule::find("ERM0012").expect("missing rule");
+
+    assert_eq!(
+        rule.to_string(),
+        "ERM0012 (error): Index names must be unique within a table."
+    );
+    assert_eq!(Rule::find("ERM9999"), None);
+}
+
+#[test]
+fn validation_errors_carry_the_code_of_their_rule() {
+    let result = ASSERTIONS.open_replaced_fixture(
+        "<physical_name>MEMBER_NAME</physical_name>",
+        "<physical_name>MEMBER_ID</physical_name>",
+        "validation_errors_carry_the_code_of_their_rule",
+    );
+
+    let Err(Error::Validation(errors)) = result else {
+        panic!("expected validation errors");
+    };
+    let error = &errors.errors[0];
+
+    assert_eq!(error.code(), "ERM0011");
+    assert_eq!(error.severity(), Severity::Error);
+    assert_eq!(error.message, "duplicate column physical_name: MEMBER_ID");
+    assert!(error.to_string().contains("\n- rule: ERM0011\n"));
+}
+
+#[test]
+fn warnings_carry_the_code_of_their_rule() {
+    let opened = erm::open(DIAGRAM_WALKERS_DETAILS_FIXTURE).expect("failed to parse");
+
+    let codes = opened
+        .warnings
+        .iter()
+        .map(|warning| warning.code())
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        codes,
+        vec![
+            "ERM0039", "ERM0041", "ERM0037", "ERM0039", "ERM0041", "ERM0041"
+        ]
+    );
+    assert!(
+        codes.iter().all(|code| {
+            Rule::find(code).is_some_and(|rule| rule.severity == Severity::Warning)
+        })
+    );
+}
@@ -0,0 +1,184 @@
+use pretty_assertions::assert_eq;
+
+use erm::errors::Error;
+use erm::{OpenedDiagram, ValidationSettings};
+
+use crate::open::validation::support::{open_with_settings, warning_codes};
+
+const DIAGRAM_WALKERS_DETAILS_FIXTURE: &str =
+    "./tests/open/fixtures/diagram/diagram_walkers_details.erm";
+const TEMP_PREFIX: &str = "erm_settings_validation";
+
+fn open_fixture(
+    replacements: &[(&str, &str)],
+    settings: Option<&str>,
+    test_name: &str,
+) -> Result<OpenedDiagram, Error> {
+    open_with_settings(
+        DIAGRAM_WALKERS_DETAILS_FIXTURE,
+        replacements,
+        settings,
+        TEMP_PREFIX,
+        test_name,
+    )
+}
+
+#[test]
+fn sidecar_path_is_next_to_the_diagram() {
+    assert_eq!(
+        ValidationSettings::sidecar_path("models/shop.erm"),
+        std::path::PathBuf::from("models/shop.erm.validation.xml")
+    );
+}
+
+#[test]
+fn suspend_validator_turns_off_warnings() {
+    let opened = open_fixture(
+        &[(
+            "<suspend_validator>false</suspend_validator>",
+            "<suspend_validator>true</suspend_validator>",
+        )],
+        None,
+        "suspend_validator_turns_off_warnings",
+    )
+    .expect("failed to parse");
+
+    assert_eq!(warning_codes(&opened), Vec::<&str>::new());
+}
+
+#[test]
+fn invalid_physical_names_are_reported_when_validate_physical_name_is_on() {
+    let opened = open_fixture(
+        &[("PARENT_MEMBER_CODE", "PARENT MEMBER CODE")],
+        None,
+        "invalid_physical_names_are_reported_when_validate_physical_name_is_on",
+    )
+    .expect("failed to parse");
+
+    let warning = opened
+        .warnings
+        .iter()
+        .find(|warning| warning.code() == "ERM0042")
+        .expect("missing physical name warning");
+    assert_eq!(
+        warning.path,
+        "diagram_walkers.table[1].columns.normal_column[1].physical_name"
+    );
+    assert_eq!(
+        warning.message,
+        "invalid column physical_name: PARENT MEMBER CODE"
+    );
+    assert_eq!(warning.target("table name"), Some("PARENT_MEMBERS"));
+}
+
+#[test]
+fn validate_physical_name_off_skips_the_physical_name_rule() {
+    let opened = open_fixture(
+        &[
+            ("PARENT_MEMBER_CODE", "PARENT MEMBER CODE"),
+            (
+                "<validate_physical_name>true</validate_physical_name>",
+                "<validate_physical_name>false</validate_physical_name>",
+            ),
+        ],
+        None,
+        "validate_physical_name_off_skips_the_physical_name_rule",
+    )
+    .expect("failed to parse");
+
+    assert!(!warning_codes(&opened).contains(&"ERM0042"));
+}
+
+#[test]
+fn disabled_rules_are_not_run() {
+    let opened = open_fixture(
+        &[],
+        Some("<validation><disabled_rules><rule>ERM0041</rule></disabled_rules></validation>"),
+        "disabled_rules_are_not_run",
+    )
+    .expect("failed to parse");
+
+    assert_eq!(
+        warning_codes(&opened),
+        vec!["ERM0039", "ERM0037", "ERM0039"]
+    );
+}
+
+#[test]
+fn enabled_rules_run_while_the_validator_is_suspended() {
+    let opened = open_fixture(
+        &[(
+            "<suspend_validator>false</suspend_validator>",
+            "<suspend_validator>true</suspend_validator>",
+        )],
+        Some("<validation><enabled_rules><rule>ERM0037</rule></enabled_rules></validation>"),
+        "enabled_rules_run_while_the_validator_is_suspended",
+    )
+    .expect("failed to parse");
+
+    assert_eq!(warning_codes(&opened), vec!["ERM0037"]);
+}
+
+#[test]
+fn suppressions_silence_a_table_or_a_column() {
+    let opened = open_fixture(
+        &[],
+        Some(
+            r#"<validation>
+  <suppressions>
+    <suppression>
+      <table>PARENT_MEMBERS</table>
+    </suppression>
+    <suppression>
+      <rule>ERM0041</rule>
+      <table>MEMBERS</table>
+      <column>MEMBER_NAME</column>
+    </suppression>
+  </suppressions>
+</validation>"#,
+        ),
+        "suppressions_silence_a_table_or_a_column",
+    )
+    .expect("failed to parse");
+
+    assert_eq!(warning_codes(&opened), vec!["ERM0039"]);
+}
+
+#[test]
+fn suppressed_errors_no_longer_keep_a_diagram_from_opening() {
+    let replacements = [(
+        "<physical_name>MEMBER_NAME</physical_name>",
+        "<physical_name>MEMBER_ID</physical_name>",
+    )];
+    let test_name = "suppressed_errors_no_longer_keep_a_diagram_from_opening";
+
+    let result = open_fixture(&replacements, None, test_name);
+    assert!(matches!(result, Err(Error::Validation(_))));
+
+    open_fixture(
+        &replacements,
+        Some(
+            "<validation><suppressions><suppression><table>MEMBERS</table></suppression></suppressions></validation>",
+        ),
+        test_name,
+    )
+    .expect("failed to parse");
+}
+
+#[test]
+fn unknown_rule_codes_in_settings_are_rejected() {
+    let result = open_fixture(
+        &[],
+        Some("<validation><disabled_rules><rule>ERM9999</rule></disabled_rules></validation>"),
+        "unknown_rule_codes_in_settings_are_rejected",
+    );
+
+    let Err(error @ Error::Parse { .. }) = result else {
+        panic!("expected a parse error");
+    };
+    assert!(
+        error
+            .to_string()
+            .contains("unknown validation rule: ERM9999")
+    );
+}
@@ -1,6 +1,52 @@
+use std::fs;
+
 use pretty_assertions::assert_eq;
 
 use erm::errors::Error;
+use erm::{OpenedDiagram, ValidationSettings, open};
+
+/// Opens the fixture with its replacements applied, next to the given validation settings.
+pub(super) fn open_with_settings(
+    fixture_path: &str,
+    replacements: &[(&str, &str)],
+    settings: Option<&str>,
+    temp_prefix: &str,
+    test_name: &str,
+) -> Result<OpenedDiagram, Error> {
+    let fixture = fs::read_to_string(fixture_path).expect("failed to read fixture");
+    let content = replacements
+        .iter()
+        .fold(fixture, |content, (target, replacement)| {
+            assert!(content.contains(target));
+            content.replace(target, replacement)
+        });
+    let path = std::env::temp_dir().join(format!(
+        "{temp_prefix}_{}_{test_name}.erm",
+        std::process::id()
+    ));
+    let settings_path = ValidationSettings::sidecar_path(&path);
+
+    fs::write(&path, content).expect("failed to write fixture");
+    if let Some(settings) = settings {
+        fs::write(&settings_path, settings).expect("failed to write settings");
+    }
+
+    let result = open(path.to_str().expect("invalid fixture path"));
+
+    fs::remove_file(&path).expect("failed to remove fixture");
+    if settings.is_some() {
+        fs::remove_file(&settings_path).expect("failed to remove settings");
+    }
+    result
+}
+
+pub(super) fn warning_codes(opened: &OpenedDiagram) -> Vec<&str> {
+    opened
+        .warnings
+        .iter()
+        .map(|warning| warning.code())
+        .collect()
+}
 
 pub(super) fn assert_validation_error_with_targets(
     result: Result<erm::dtos::diagram::Diagram, Error>,
@@ -8,9 +54,11 @@ pub(super) fn assert_validation_error_with_targets(
     message: &str,
     targets: &[(&str, &str)],
 ) {
-    let Err(Error::Validation(error)) = result else {
+    let Err(Error::Validation(errors)) = result else {
         panic!("expected validation error");
     };
+    // Later errors are often consequences of the first one, which is the one asserted here.
+    let error = &errors.errors[0];
 
     assert_eq!(error.path, path);
     assert_eq!(error.message, message);
@@ -0,0 +1,23 @@
+use crate::open::support;
+use crate::open::validation::support::assert_validation_error_with_targets;
+
+const VIRTUAL_DIAGRAMS_FIXTURE: &str = "./tests/open/fixtures/diagram/virtual_diagrams.erm";
+const TEMP_PREFIX: &str = "erm_virtual_diagrams_validation";
+const ASSERTIONS: support::FixtureAssertions =
+    support::FixtureAssertions::new(VIRTUAL_DIAGRAMS_FIXTURE, TEMP_PREFIX, "      ");
+
+#[test]
+fn virtual_table_of_unknown_table_is_rejected() {
+    let result = ASSERTIONS.open_replaced_fixture(
+        "<table_id>table.PARENT_MEMBERS</table_id>",
+        "<table_id>table.GRANDPARENT_MEMBERS</table_id>",
+        "virtual_table_of_unknown_table",
+    );
+
+    assert_validation_error_with_targets(
+        result,
+        "vdiagrams.vdiagram[1].vtables.vtable[1].table_id",
+        "unknown virtual table: table.GRANDPARENT_MEMBERS",
+        &[("virtual diagram name", "Hierarchy")],
+    );
+}
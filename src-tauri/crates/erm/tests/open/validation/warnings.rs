@@ -0,0 +1,111 @@
+use pretty_assertions::assert_eq;
+
+use erm::errors::{Error, Severity};
+use erm::open;
+
+use crate::open::support;
+
+const DIAGRAM_WALKERS_DETAILS_FIXTURE: &str =
+    "./tests/open/fixtures/diagram/diagram_walkers_details.erm";
+const READ_SNAPSHOT_FIXTURE: &str = "./tests/open/fixtures/read_snapshot.erm";
+const TEMP_PREFIX: &str = "erm_warnings_validation";
+const ASSERTIONS: support::FixtureAssertions =
+    support::FixtureAssertions::new(DIAGRAM_WALKERS_DETAILS_FIXTURE, TEMP_PREFIX, "      ");
+
+#[test]
+fn warnings_are_returned_next_to_the_diagram() {
+    let opened = open(DIAGRAM_WALKERS_DETAILS_FIXTURE).expect("failed to parse");
+
+    let warnings = opened
+        .warnings
+        .iter()
+        .map(|warning| (warning.path.as_str(), warning.message.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        warnings,
+        vec![
+            (
+                "diagram_walkers.table[0].compound_unique_key_list.compound_unique_key[0].columns[0].column_id",
+                "compound unique key column is nullable: MEMBER_NAME",
+            ),
+            (
+                "diagram_walkers.table[0].columns.normal_column[1].logical_name",
+                "column logical_name is empty: MEMBER_NAME",
+            ),
+            (
+                "diagram_walkers.table[1].columns",
+                "table has no primary key: PARENT_MEMBERS",
+            ),
+            (
+                "diagram_walkers.table[1].compound_unique_key_list.compound_unique_key[0].columns[0].column_id",
+                "compound unique key column is nullable: PARENT_MEMBER_CODE",
+            ),
+            (
+                "diagram_walkers.table[1].columns.normal_column[0].logical_name",
+                "column logical_name is empty: PARENT_MEMBER_ID",
+            ),
+            (
+                "diagram_walkers.table[1].columns.normal_column[1].logical_name",
+                "column logical_name is empty: PARENT_MEMBER_CODE",
+            ),
+        ]
+    );
+    assert!(
+        opened
+            .warnings
+            .iter()
+            .all(|warning| warning.severity() == Severity::Warning)
+    );
+}
+
+#[test]
+fn foreign_key_columns_without_an_index_are_reported() {
+    let opened = open(READ_SNAPSHOT_FIXTURE).expect("failed to parse");
+
+    let warnings = opened
+        .warnings
+        .iter()
+        .filter(|warning| warning.path.ends_with("fk_columns"))
+        .map(|warning| warning.message.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        warnings,
+        vec![
+            "foreign key columns are not indexed: FK_MEMBER_PROFILES_MEMBERS",
+            "foreign key columns are not indexed: FK_MEMBER_PROFILES_MST_GENDER",
+            "foreign key columns are not indexed: FK_MEMBER_POSTS_MEMBERS",
+            "foreign key columns are not indexed: FK_POST_REPLIES_POST_THREADS",
+            "foreign key columns are not indexed: FK_POST_REPLIES_MEMBERS",
+        ]
+    );
+}
+
+#[test]
+fn warnings_are_left_out_of_validation_errors() {
+    let result = ASSERTIONS.open_replaced_fixture(
+        "<column_id>MEMBER_ID</column_id>",
+        "<column_id>UNKNOWN_ID</column_id>",
+        "warnings_are_left_out_of_validation_errors",
+    );
+
+    let Err(Error::Validation(errors)) = result else {
+        panic!("expected validation errors");
+    };
+    assert_eq!(errors.errors.len(), 2);
+    assert!(
+        errors
+            .errors
+            .iter()
+            .all(|error| error.severity() == Severity::Error)
+    );
+}
+
+#[test]
+fn warnings_are_described_as_warnings() {
+    let opened = open(DIAGRAM_WALKERS_DETAILS_FIXTURE).expect("failed to parse");
+
+    assert_eq!(
+        opened.warnings[2].to_string(),
+        "Validation warning\n\ntable has no primary key: PARENT_MEMBERS\n\nTarget:\n- table name: PARENT_MEMBERS\n\nTechnical details:\n- rule: ERM0037\n- path: diagram_walkers.table[1].columns\n- location: line 144, column 7"
+    );
+}
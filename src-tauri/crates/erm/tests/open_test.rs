@@ -1 +1,3 @@
+#![allow(clippy::module_inception)]
+
 mod open;
@@ -0,0 +1,144 @@
+use pretty_assertions::assert_eq;
+
+use erm::dtos::diagram::Diagram;
+use erm::{Format, open};
+
+use super::support::{open_content, save_and_read};
+
+const FIXTURES: &[&str] = &[
+    "./tests/open/fixtures/read_snapshot.erm",
+    "./tests/open/fixtures/diagram/column_groups.erm",
+    "./tests/open/fixtures/diagram/diagram.erm",
+    "./tests/open/fixtures/diagram/diagram_settings.erm",
+    "./tests/open/fixtures/diagram/diagram_walkers.erm",
+    "./tests/open/fixtures/diagram/diagram_walkers_details.erm",
+    "./tests/open/fixtures/diagram/groups.erm",
+    "./tests/open/fixtures/diagram/images.erm",
+    "./tests/open/fixtures/diagram/notes.erm",
+    "./tests/open/fixtures/diagram/page_settings.erm",
+    "./tests/open/fixtures/diagram/views.erm",
+    "./tests/open/fixtures/diagram/virtual_diagrams.erm",
+    "./tests/save/fixtures/unknown_elements.erm",
+];
+const UNKNOWN_ELEMENTS_FIXTURE: &str = "./tests/save/fixtures/unknown_elements.erm";
+
+#[test]
+fn canonical_file_reopens_to_the_same_model() {
+    for fixture in FIXTURES {
+        let diagram = open(fixture).expect("failed to parse fixture").diagram;
+
+        let saved = save_and_read(&diagram, Format::Canonical, "canonical_reopen");
+        let reopened = open_content(&saved, "canonical_reopen");
+
+        assert_eq!(
+            sorted(reopened),
+            sorted(diagram.clone()),
+            "fixture: {fixture}"
+        );
+    }
+}
+
+#[test]
+fn canonical_file_is_stable_across_repeated_saves() {
+    for fixture in FIXTURES {
+        let diagram = open(fixture).expect("failed to parse fixture").diagram;
+
+        let first = save_and_read(&diagram, Format::Canonical, "canonical_repeated");
+        let reopened = open_content(&first, "canonical_repeated");
+        let second = save_and_read(&reopened, Format::Canonical, "canonical_repeated");
+
+        assert_eq!(first, second, "fixture: {fixture}");
+    }
+}
+
+#[test]
+fn canonical_file_does_not_depend_on_table_and_relationship_order() {
+    for fixture in FIXTURES {
+        let diagram = open(fixture).expect("failed to parse fixture").diagram;
+        let mut reversed = diagram.clone();
+        if let Some(tables) = reversed
+            .diagram_walkers
+            .as_mut()
+            .and_then(|walkers| walkers.tables.as_mut())
+        {
+            tables.reverse();
+            for table in tables {
+                if let Some(relationships) = table.connections.relationships.as_mut() {
+                    relationships.reverse();
+                }
+            }
+        }
+
+        assert_eq!(
+            save_and_read(&reversed, Format::Canonical, "canonical_reversed"),
+            save_and_read(&diagram, Format::Canonical, "canonical_original"),
+            "fixture: {fixture}"
+        );
+    }
+}
+
+#[test]
+fn canonical_file_follows_schema_order() {
+    let content = std::fs::read_to_string(UNKNOWN_ELEMENTS_FIXTURE)
+        .expect("failed to read fixture")
+        .replace("<word_id>word.1</word_id>", "")
+        .replace(
+            "<type>bigint</type>",
+            "<type>bigint</type><word_id>word.1</word_id>",
+        );
+    let diagram = open_content(&content, "canonical_schema_order");
+
+    let saved = save_and_read(&diagram, Format::Canonical, "canonical_schema_order");
+
+    // Walkers are grouped by kind in the order the schema lists them.
+    let last_table = saved.rfind("<table>").expect("missing table");
+    let view = saved.find("<view>").expect("missing view");
+    let walker_note = saved.find("<walker_note>").expect("missing walker note");
+    let walker_group = saved.find("<walker_group>").expect("missing walker group");
+    assert!(last_table < view);
+    assert!(view < walker_note);
+    assert!(walker_note < walker_group);
+
+    // Unmodelled children are moved to their schema position.
+    assert!(saved.contains(
+        "<normal_column>\n\t\t\t\t\t<word_id>word.1</word_id>\n\t\t\t\t\t<id>column.MEMBER_ID</id>"
+    ));
+}
+
+#[test]
+fn canonical_file_normalizes_unmodelled_booleans() {
+    let content = std::fs::read_to_string(UNKNOWN_ELEMENTS_FIXTURE)
+        .expect("failed to read fixture")
+        .replace(
+            "<use_default_driver>true</use_default_driver>",
+            "<use_default_driver> TRUE </use_default_driver>",
+        )
+        .replace("<display>false</display>", "<display>0</display>");
+    let diagram = open_content(&content, "canonical_booleans");
+
+    let saved = save_and_read(&diagram, Format::Canonical, "canonical_booleans");
+
+    assert!(saved.contains("<use_default_driver>true</use_default_driver>"));
+    assert!(saved.contains("<display>false</display>"));
+}
+
+fn sorted(mut diagram: Diagram) -> Diagram {
+    // Unmodelled elements are re-anchored to their canonical neighbours, and the file's own
+    // layout is not kept.
+    diagram.unknown_elements = Default::default();
+    diagram.xml_layout = Default::default();
+
+    if let Some(tables) = diagram
+        .diagram_walkers
+        .as_mut()
+        .and_then(|walkers| walkers.tables.as_mut())
+    {
+        tables.sort_by(|a, b| a.physical_name.cmp(&b.physical_name));
+        for table in tables {
+            if let Some(relationships) = table.connections.relationships.as_mut() {
+                relationships.sort_by(|a, b| a.name.cmp(&b.name));
+            }
+        }
+    }
+    diagram
+}
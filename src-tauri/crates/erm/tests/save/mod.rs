@@ -0,0 +1,5 @@
+pub mod canonical;
+pub mod preserve;
+pub mod round_trip;
+mod support;
+pub mod unknown_elements;
@@ -0,0 +1,72 @@
+use std::fs;
+
+use pretty_assertions::assert_eq;
+
+use erm::{Format, open};
+
+use super::support::{open_content, save_and_read};
+
+const FIXTURES: &[&str] = &[
+    "./tests/open/fixtures/read_snapshot.erm",
+    "./tests/save/fixtures/interleaved_walkers.erm",
+];
+const INTERLEAVED_FIXTURE: &str = "./tests/save/fixtures/interleaved_walkers.erm";
+
+#[test]
+fn erflute_files_are_written_back_byte_for_byte() {
+    for fixture in FIXTURES {
+        let diagram = open(fixture).expect("failed to open fixture").diagram;
+        let original = fs::read_to_string(fixture).expect("failed to read fixture");
+
+        let saved = save_and_read(&diagram, Format::Preserve, "preserve_unchanged");
+
+        assert_eq!(saved.trim_end(), original.trim_end(), "fixture: {fixture}");
+    }
+}
+
+#[test]
+fn interleaved_walkers_are_read_under_their_aliases() {
+    let diagram = open(INTERLEAVED_FIXTURE)
+        .expect("failed to open fixture")
+        .diagram;
+
+    assert!(
+        diagram
+            .unknown_elements
+            .get("diagram/diagram_walkers")
+            .is_empty()
+    );
+    let walkers = diagram.diagram_walkers.expect("fixture has walkers");
+    assert_eq!(walkers.notes.map(|notes| notes.len()), Some(1));
+    assert_eq!(walkers.groups.map(|groups| groups.len()), Some(1));
+    assert_eq!(walkers.images.map(|images| images.len()), Some(1));
+}
+
+#[test]
+fn walkers_added_since_follow_their_sibling() {
+    let mut diagram = open(INTERLEAVED_FIXTURE)
+        .expect("failed to open fixture")
+        .diagram;
+    let tables = diagram
+        .diagram_walkers
+        .as_mut()
+        .and_then(|walkers| walkers.tables.as_mut())
+        .expect("fixture has tables");
+    let mut tags = tables[0].clone();
+    tags.physical_name = "TAGS".to_string();
+    tables.insert(1, tags);
+
+    let saved = save_and_read(&diagram, Format::Preserve, "preserve_added");
+
+    // The new table follows the table it follows in the model, ahead of the walkers that came
+    // next in the file.
+    let members = saved.find("<physical_name>MEMBERS</physical_name>");
+    let tags = saved.find("<physical_name>TAGS</physical_name>");
+    let note = saved.find("<note>");
+    assert!(members < tags);
+    assert!(tags < note);
+    assert_eq!(
+        open_content(&saved, "preserve_added").diagram_walkers,
+        diagram.diagram_walkers
+    );
+}
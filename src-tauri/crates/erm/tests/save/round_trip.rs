@@ -0,0 +1,224 @@
+use std::fs;
+
+use pretty_assertions::assert_eq;
+
+use erm::dtos::diagram::Diagram;
+use erm::dtos::diagram::diagram_walkers::images::{Image, ImageFormat};
+use erm::{open, save};
+
+const FIXTURES: &[&str] = &[
+    "./tests/open/fixtures/read_snapshot.erm",
+    "./tests/open/fixtures/diagram/column_groups.erm",
+    "./tests/open/fixtures/diagram/diagram.erm",
+    "./tests/open/fixtures/diagram/diagram_settings.erm",
+    "./tests/open/fixtures/diagram/diagram_walkers.erm",
+    "./tests/open/fixtures/diagram/diagram_walkers_details.erm",
+    "./tests/open/fixtures/diagram/groups.erm",
+    "./tests/open/fixtures/diagram/images.erm",
+    "./tests/open/fixtures/diagram/notes.erm",
+    "./tests/open/fixtures/diagram/page_settings.erm",
+    "./tests/open/fixtures/diagram/views.erm",
+    "./tests/open/fixtures/diagram/virtual_diagrams.erm",
+];
+const TEMP_PREFIX: &str = "erm_save_round_trip";
+const IMAGES_FIXTURE: &str = "./tests/open/fixtures/diagram/images.erm";
+const PNG_DATA: &str = "iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAYAAAAfFcSJAAAADUlEQVR42mNk+M9QDwADhgGAWjR9awAAAABJRU5ErkJggg==";
+const GIF_DATA: &str = "R0lGODlhAQABAAAAACw=";
+
+#[test]
+fn fixtures_survive_save_and_reopen() {
+    for fixture in FIXTURES {
+        let diagram = open(fixture).expect("failed to parse fixture").diagram;
+        let path = temp_file_path("reopen", fixture);
+
+        save(&diagram, path.to_str().expect("invalid temp path")).expect("failed to save");
+        let reopened = open(path.to_str().expect("invalid temp path"));
+
+        fs::remove_file(&path).expect("failed to remove saved file");
+        assert_eq!(
+            reopened.expect("failed to reopen saved file").diagram,
+            diagram
+        );
+    }
+}
+
+#[test]
+fn saved_file_is_stable_across_repeated_saves() {
+    for fixture in FIXTURES {
+        let diagram = open(fixture).expect("failed to parse fixture").diagram;
+        let path = temp_file_path("repeated", fixture);
+        let path = path.to_str().expect("invalid temp path");
+
+        save(&diagram, path).expect("failed to save");
+        let first = fs::read_to_string(path).expect("failed to read saved file");
+        save(
+            &open(path).expect("failed to reopen saved file").diagram,
+            path,
+        )
+        .expect("failed to save");
+        let second = fs::read_to_string(path).expect("failed to read saved file");
+
+        fs::remove_file(path).expect("failed to remove saved file");
+        assert_eq!(first, second);
+    }
+}
+
+#[test]
+fn saved_file_uses_erm_document_layout() {
+    let diagram = open(FIXTURES[0]).expect("failed to parse fixture").diagram;
+    let path = temp_file_path("layout", FIXTURES[0]);
+
+    save(&diagram, path.to_str().expect("invalid temp path")).expect("failed to save");
+    let content = fs::read_to_string(&path).expect("failed to read saved file");
+
+    fs::remove_file(&path).expect("failed to remove saved file");
+    assert!(content.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<diagram>\n"));
+    assert!(content.contains("\n\t<presenter>ERFlute</presenter>\n"));
+    assert!(content.contains("\t\t\t\t<column_group>COMMON</column_group>\n"));
+    assert!(content.contains("<on_delete_action>RESTRICT</on_delete_action>"));
+    assert!(content.ends_with("</diagram>\n"));
+}
+
+#[test]
+fn diagram_sent_back_by_the_frontend_keeps_its_image_data() {
+    let fixture = "./tests/open/fixtures/diagram/images.erm";
+    let path = temp_file_path("frontend", fixture);
+    let path = path.to_str().expect("invalid temp path");
+    fs::copy(fixture, path).expect("failed to copy fixture");
+    save(&open(path).expect("failed to parse fixture").diagram, path).expect("failed to save");
+    let saved = fs::read(path).expect("failed to read saved file");
+
+    let payload = serde_json::to_string(&open(path).expect("failed to reopen").diagram)
+        .expect("failed to serialize");
+    let diagram: Diagram = serde_json::from_str(&payload).expect("failed to deserialize");
+    let result = save(&diagram, path);
+    let resaved = fs::read(path).expect("failed to read saved file");
+
+    fs::remove_file(path).expect("failed to remove saved file");
+    result.expect("failed to save");
+    assert!(!payload.contains("iVBORw0KGgo"));
+    assert_eq!(resaved, saved);
+}
+
+#[test]
+fn image_data_follows_its_image_when_one_is_deleted() {
+    let path = temp_file_path("deleted_image", IMAGES_FIXTURE);
+    let path = path.to_str().expect("invalid temp path");
+    let mut diagram = two_unnamed_images(path);
+    images_mut(&mut diagram).remove(0);
+
+    let result = save(&diagram, path);
+    let reopened = open(path);
+
+    fs::remove_file(path).expect("failed to remove saved file");
+    result.expect("failed to save");
+    let images = reopened
+        .expect("failed to reopen")
+        .diagram
+        .diagram_walkers
+        .and_then(|walkers| walkers.images)
+        .expect("missing images");
+    assert_eq!(images.len(), 1);
+    assert_eq!(images[0].data, GIF_DATA);
+}
+
+#[test]
+fn image_data_follows_its_image_when_they_are_reordered() {
+    let path = temp_file_path("reordered_images", IMAGES_FIXTURE);
+    let path = path.to_str().expect("invalid temp path");
+    let mut diagram = two_unnamed_images(path);
+    images_mut(&mut diagram).reverse();
+
+    let result = save(&diagram, path);
+    let reopened = open(path);
+
+    fs::remove_file(path).expect("failed to remove saved file");
+    result.expect("failed to save");
+    let formats = reopened
+        .expect("failed to reopen")
+        .diagram
+        .diagram_walkers
+        .and_then(|walkers| walkers.images)
+        .expect("missing images")
+        .into_iter()
+        .map(|image| image.format)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        formats,
+        vec![Some(ImageFormat::Gif), Some(ImageFormat::Png)]
+    );
+}
+
+#[test]
+fn image_without_data_is_not_saved_to_a_new_file() {
+    let payload = serde_json::to_string(&open(IMAGES_FIXTURE).expect("failed to parse").diagram)
+        .expect("failed to serialize");
+    let diagram: Diagram = serde_json::from_str(&payload).expect("failed to deserialize");
+    let path = temp_file_path("new_file_image", IMAGES_FIXTURE);
+
+    let result = save(&diagram, path.to_str().expect("invalid temp path"));
+
+    assert_eq!(
+        result.expect_err("expected missing image data").code(),
+        "E_MISSING_IMAGE_DATA"
+    );
+    assert!(!path.exists());
+}
+
+#[test]
+fn invalid_diagram_is_not_saved() {
+    let mut diagram = open(FIXTURES[0]).expect("failed to parse fixture").diagram;
+    let tables = diagram
+        .diagram_walkers
+        .as_mut()
+        .and_then(|diagram_walkers| diagram_walkers.tables.as_mut())
+        .expect("missing tables");
+    tables[1].physical_name = tables[0].physical_name.clone();
+    let path = temp_file_path("invalid", FIXTURES[0]);
+
+    let result = save(&diagram, path.to_str().expect("invalid temp path"));
+
+    assert!(result.is_err());
+    assert!(!path.exists());
+}
+
+/// Writes the images fixture to `path` with a GIF image after its PNG one and no image ids, and
+/// returns it as the frontend sends it back, without image data.
+fn two_unnamed_images(path: &str) -> Diagram {
+    let fixture = fs::read_to_string(IMAGES_FIXTURE)
+        .expect("failed to read fixture")
+        .replace("      <id>image.1</id>\n", "");
+    let start = fixture.find("    <image>").expect("missing image");
+    let end = fixture
+        .find("  </diagram_walkers>")
+        .expect("missing walkers");
+    let png = &fixture[start..end];
+    let gif = png.replace(PNG_DATA, GIF_DATA);
+    fs::write(path, fixture.replacen(png, &format!("{png}{gif}"), 1))
+        .expect("failed to write fixture");
+
+    let payload = serde_json::to_string(&open(path).expect("failed to parse").diagram)
+        .expect("failed to serialize");
+    serde_json::from_str(&payload).expect("failed to deserialize")
+}
+
+fn images_mut(diagram: &mut Diagram) -> &mut Vec<Image> {
+    diagram
+        .diagram_walkers
+        .as_mut()
+        .and_then(|walkers| walkers.images.as_mut())
+        .expect("missing images")
+}
+
+fn temp_file_path(test_name: &str, fixture: &str) -> std::path::PathBuf {
+    let fixture_name = fixture
+        .rsplit('/')
+        .next()
+        .unwrap_or(fixture)
+        .trim_end_matches(".erm");
+
+    std::env::temp_dir().join(format!(
+        "{TEMP_PREFIX}_{}_{test_name}_{fixture_name}.erm",
+        std::process::id()
+    ))
+}
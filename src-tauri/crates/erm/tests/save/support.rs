@@ -0,0 +1,116 @@
+use std::fs;
+
+use erm::Format;
+use quick_xml::Reader;
+use quick_xml::events::Event;
+
+const TEMP_PREFIX: &str = "erm_save";
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum XmlToken {
+    Start(String, Vec<(String, String)>),
+    Text(String),
+    End(String),
+}
+
+/// Flattens an XML document into tokens, ignoring layout whitespace, empty-element syntax and
+/// the escaping style, so two documents compare equal when they carry the same content.
+pub(crate) fn xml_tokens(content: &str) -> Vec<XmlToken> {
+    let mut reader = Reader::from_str(content);
+    let mut tokens = Vec::new();
+    let mut text = String::new();
+
+    loop {
+        let event = reader.read_event().expect("failed to read XML");
+
+        if !matches!(
+            event,
+            Event::Text(_) | Event::CData(_) | Event::GeneralRef(_)
+        ) {
+            let trimmed = text.trim();
+            if !trimmed.is_empty() {
+                tokens.push(XmlToken::Text(trimmed.to_string()));
+            }
+            text.clear();
+        }
+
+        match event {
+            Event::Empty(start) => {
+                let name = String::from_utf8_lossy(start.name().as_ref()).into_owned();
+                tokens.push(XmlToken::Start(name.clone(), attributes(&start)));
+                tokens.push(XmlToken::End(name));
+            }
+            Event::Start(start) => {
+                let name = String::from_utf8_lossy(start.name().as_ref()).into_owned();
+                tokens.push(XmlToken::Start(name, attributes(&start)));
+            }
+            Event::End(end) => {
+                tokens.push(XmlToken::End(
+                    String::from_utf8_lossy(end.name().as_ref()).into_owned(),
+                ));
+            }
+            Event::Text(content) => text.push_str(&content.xml_content().expect("invalid text")),
+            Event::CData(content) => text.push_str(&String::from_utf8_lossy(&content)),
+            Event::GeneralRef(reference) => {
+                let name = reference.decode().expect("invalid reference");
+                let resolved = quick_xml::escape::resolve_predefined_entity(&name)
+                    .expect("unknown entity reference");
+                text.push_str(resolved);
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    tokens
+}
+
+fn attributes(start: &quick_xml::events::BytesStart<'_>) -> Vec<(String, String)> {
+    let mut attributes = start
+        .attributes()
+        .map(|attribute| {
+            let attribute = attribute.expect("invalid attribute");
+            (
+                String::from_utf8_lossy(attribute.key.as_ref()).into_owned(),
+                attribute
+                    .unescape_value()
+                    .expect("invalid attribute value")
+                    .into_owned(),
+            )
+        })
+        .collect::<Vec<_>>();
+    attributes.sort();
+    attributes
+}
+
+pub(crate) fn temp_file_path(test_name: &str) -> std::path::PathBuf {
+    std::env::temp_dir().join(format!(
+        "{TEMP_PREFIX}_{}_{test_name}.erm",
+        std::process::id()
+    ))
+}
+
+pub(crate) fn save_and_read(
+    diagram: &erm::dtos::diagram::Diagram,
+    format: Format,
+    test_name: &str,
+) -> String {
+    let path = temp_file_path(test_name);
+
+    erm::save_with_format(diagram, path.to_str().expect("invalid temp path"), format)
+        .expect("failed to save");
+    let content = fs::read_to_string(&path).expect("failed to read saved file");
+
+    fs::remove_file(&path).expect("failed to remove saved file");
+    content
+}
+
+pub(crate) fn open_content(content: &str, test_name: &str) -> erm::dtos::diagram::Diagram {
+    let path = temp_file_path(&format!("{test_name}_reopen"));
+
+    fs::write(&path, content).expect("failed to write temp file");
+    let result = erm::open(path.to_str().expect("invalid temp path"));
+
+    fs::remove_file(&path).expect("failed to remove temp file");
+    result.expect("failed to reopen saved file").diagram
+}
@@ -0,0 +1,55 @@
+use std::fs;
+
+use pretty_assertions::assert_eq;
+
+use erm::{Format, open};
+
+use super::support::{open_content, save_and_read, xml_tokens};
+
+const FIXTURE: &str = "tests/save/fixtures/unknown_elements.erm";
+
+#[test]
+fn unmodelled_elements_survive_open_and_save() {
+    let diagram = open(FIXTURE).expect("failed to open fixture").diagram;
+    let original = fs::read_to_string(FIXTURE).expect("failed to read fixture");
+
+    let saved = save_and_read(&diagram, Format::Preserve, "unmodelled");
+
+    assert_eq!(xml_tokens(&saved), xml_tokens(&original));
+}
+
+#[test]
+fn unmodelled_elements_are_exposed_on_the_diagram() {
+    let diagram = open(FIXTURE).expect("failed to open fixture").diagram;
+
+    assert!(!diagram.unknown_elements.is_empty());
+    assert!(
+        diagram
+            .unknown_elements
+            .get("diagram")
+            .iter()
+            .any(|element| element.xml.starts_with("<dictionary>"))
+    );
+}
+
+#[test]
+fn unmodelled_elements_survive_edits_to_modelled_data() {
+    let mut diagram = open(FIXTURE).expect("failed to open fixture").diagram;
+    let tables = diagram
+        .diagram_walkers
+        .as_mut()
+        .and_then(|walkers| walkers.tables.as_mut())
+        .expect("fixture has tables");
+    tables[0].logical_name = "Renamed".to_string();
+
+    let saved = save_and_read(&diagram, Format::Preserve, "edited");
+    let reopened = open_content(&saved, "edited");
+
+    let reopened_tables = reopened
+        .diagram_walkers
+        .as_ref()
+        .and_then(|walkers| walkers.tables.as_ref())
+        .expect("saved file has tables");
+    assert_eq!(reopened_tables[0].logical_name, "Renamed");
+    assert_eq!(reopened.unknown_elements, diagram.unknown_elements);
+}
@@ -18,7 +18,7 @@ fn expand_validate(input: DeriveInput) -> Result<proc_macro2::TokenStream> {
 
     let rule_calls = rule_paths.iter().map(|path| {
         quote! {
-            #path(self)?;
+            errors.extend(#path(self));
         }
     });
 
@@ -35,10 +35,12 @@ fn expand_validate(input: DeriveInput) -> Result<proc_macro2::TokenStream> {
 
     Ok(quote! {
         impl crate::validation::Validate for #ident {
-            fn validate(&self) -> Result<(), crate::validation::ValidationError> {
+            fn validate_all(&self) -> Vec<crate::validation::ValidationError> {
+                #[allow(unused_mut)]
+                let mut errors = Vec::new();
                 #(#rule_calls)*
                 #child_validations
-                Ok(())
+                errors
             }
         }
     })
@@ -60,8 +62,11 @@ fn expand_struct_fields(fields: Fields) -> Result<proc_macro2::TokenStream> {
                 });
 
             Ok(quote! {
-                crate::validation::Validate::validate(&self.#access)
-                    .map_err(|error| error.prepend_path(#path))?;
+                errors.extend(
+                    crate::validation::Validate::validate_all(&self.#access)
+                        .into_iter()
+                        .map(|error| error.prepend_path(#path)),
+                );
             })
         })
         .collect::<Result<Vec<_>>>()?;
@@ -87,7 +92,7 @@ fn expand_enum_variants(
                 let ident = &variant.ident;
                 Ok(quote! {
                     Self::#ident(value) => {
-                        crate::validation::Validate::validate(value)?;
+                        errors.extend(crate::validation::Validate::validate_all(value));
                     }
                 })
             }
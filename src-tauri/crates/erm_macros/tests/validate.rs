@@ -28,32 +28,32 @@ mod validation {
     }
 
     pub trait Validate {
-        fn validate(&self) -> Result<(), ValidationError>;
+        fn validate_all(&self) -> Vec<ValidationError>;
     }
 
     impl<T: Validate> Validate for Option<T> {
-        fn validate(&self) -> Result<(), ValidationError> {
-            if let Some(value) = self {
-                value.validate()?;
-            }
-            Ok(())
+        fn validate_all(&self) -> Vec<ValidationError> {
+            self.as_ref().map_or_else(Vec::new, Validate::validate_all)
         }
     }
 
     impl<T: Validate> Validate for Vec<T> {
-        fn validate(&self) -> Result<(), ValidationError> {
-            for (index, value) in self.iter().enumerate() {
-                value
-                    .validate()
-                    .map_err(|error| error.prepend_path(format!("[{index}]")))?;
-            }
-            Ok(())
+        fn validate_all(&self) -> Vec<ValidationError> {
+            self.iter()
+                .enumerate()
+                .flat_map(|(index, value)| {
+                    value
+                        .validate_all()
+                        .into_iter()
+                        .map(move |error| error.prepend_path(format!("[{index}]")))
+                })
+                .collect()
         }
     }
 
     impl Validate for String {
-        fn validate(&self) -> Result<(), ValidationError> {
-            Ok(())
+        fn validate_all(&self) -> Vec<ValidationError> {
+            Vec::new()
         }
     }
 }
@@ -77,56 +77,56 @@ struct MultipleRules {
     name: String,
 }
 
-fn validate_parent(value: &Parent) -> Result<(), validation::ValidationError> {
+fn validate_parent(value: &Parent) -> Vec<validation::ValidationError> {
     if value.children.is_none() {
-        return Err(validation::ValidationError::new(
+        return vec![validation::ValidationError::new(
             "children".to_string(),
             "missing children".to_string(),
-        ));
+        )];
     }
 
-    Ok(())
+    Vec::new()
 }
 
-fn validate_child(value: &Child) -> Result<(), validation::ValidationError> {
+fn validate_child(value: &Child) -> Vec<validation::ValidationError> {
     if value.name == "invalid" {
-        return Err(validation::ValidationError::new(
+        return vec![validation::ValidationError::new(
             "name".to_string(),
             "invalid child".to_string(),
-        ));
+        )];
     }
 
-    Ok(())
+    Vec::new()
 }
 
-fn validate_first_rule(value: &MultipleRules) -> Result<(), validation::ValidationError> {
+fn validate_first_rule(value: &MultipleRules) -> Vec<validation::ValidationError> {
     if value.name == "first" {
-        return Err(validation::ValidationError::new(
+        return vec![validation::ValidationError::new(
             "name".to_string(),
             "first rule".to_string(),
-        ));
+        )];
     }
 
-    Ok(())
+    Vec::new()
 }
 
-fn validate_second_rule(value: &MultipleRules) -> Result<(), validation::ValidationError> {
+fn validate_second_rule(value: &MultipleRules) -> Vec<validation::ValidationError> {
     if value.name == "second" {
-        return Err(validation::ValidationError::new(
+        return vec![validation::ValidationError::new(
             "name".to_string(),
             "second rule".to_string(),
-        ));
+        )];
     }
 
-    Ok(())
+    Vec::new()
 }
 
 #[test]
 fn struct_level_rule_is_called() {
-    let result = Parent { children: None }.validate();
+    let result = Parent { children: None }.validate_all();
 
-    let Err(error) = result else {
-        panic!("expected validation error");
+    let [error] = result.as_slice() else {
+        panic!("expected one validation error");
     };
 
     assert_eq!(error.path, "children");
@@ -140,10 +140,10 @@ fn option_vec_child_path_uses_field_override_and_index() {
             name: "invalid".to_string(),
         }]),
     }
-    .validate();
+    .validate_all();
 
-    let Err(error) = result else {
-        panic!("expected validation error");
+    let [error] = result.as_slice() else {
+        panic!("expected one validation error");
     };
 
     assert_eq!(error.path, "child[0].name");
@@ -155,12 +155,36 @@ fn multiple_struct_level_rules_are_called_in_order() {
     let result = MultipleRules {
         name: "second".to_string(),
     }
-    .validate();
+    .validate_all();
 
-    let Err(error) = result else {
-        panic!("expected validation error");
+    let [error] = result.as_slice() else {
+        panic!("expected one validation error");
     };
 
     assert_eq!(error.path, "name");
     assert_eq!(error.message, "second rule");
 }
+
+#[test]
+fn errors_of_every_rule_and_child_are_collected() {
+    let errors = Parent {
+        children: Some(vec![
+            Child {
+                name: "invalid".to_string(),
+            },
+            Child {
+                name: "valid".to_string(),
+            },
+            Child {
+                name: "invalid".to_string(),
+            },
+        ]),
+    }
+    .validate_all();
+
+    let paths = errors
+        .iter()
+        .map(|error| error.path.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(paths, vec!["child[0].name", "child[2].name"]);
+}
@@ -0,0 +1,77 @@
+//! Git merge driver for `.erm` files.
+//!
+//! Register it once per clone:
+//!
+//! ```text
+//! git config merge.erm.name "ERFlute diagram merge"
+//! git config merge.erm.driver "erm-merge-driver %O %A %B %P"
+//! ```
+//!
+//! and route diagrams to it in `.gitattributes`:
+//!
+//! ```text
+//! *.erm merge=erm
+//! ```
+//!
+//! The merged diagram replaces `%A`, written back in the element order, tag names and empty-element
+//! form `%A` was read with, so that the merge commit only touches what changed. Pass
+//! `--canonical` before `%O` to write the canonical order instead. The validation settings are
+//! read beside `%P`, since the other files are temporary copies. When the changes conflict, `%A`
+//! is left untouched, the conflicts are printed to stderr and the driver exits with a non-zero
+//! status.
+
+use std::process::ExitCode;
+
+use erm::errors::Error;
+use erm::{
+    Format, ValidationSettings, merge_with_settings, open_with_settings, save_with_settings,
+};
+
+const USAGE: &str = "usage: erm-merge-driver [--canonical] <base> <ours> <theirs> [<path>]";
+
+/// Exit status for merges git should report as conflicted.
+const CONFLICT: u8 = 1;
+/// Exit status for invalid invocations.
+const USAGE_ERROR: u8 = 2;
+
+fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1).collect::<Vec<_>>();
+    let format = if args.first().is_some_and(|arg| arg == "--canonical") {
+        args.remove(0);
+        Format::Canonical
+    } else {
+        Format::Preserve
+    };
+
+    let [base, ours, theirs, rest @ ..] = args.as_slice() else {
+        eprintln!("{USAGE}");
+        return ExitCode::from(USAGE_ERROR);
+    };
+    if rest.len() > 1 {
+        eprintln!("{USAGE}");
+        return ExitCode::from(USAGE_ERROR);
+    }
+    let path = rest.first().unwrap_or(ours);
+
+    match run(base, ours, theirs, path, format) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("{}", report(path, &error));
+            ExitCode::from(CONFLICT)
+        }
+    }
+}
+
+fn run(base: &str, ours: &str, theirs: &str, path: &str, format: Format) -> Result<(), Error> {
+    let settings = ValidationSettings::read(path)?;
+    let open = |filename| open_with_settings(filename, &settings).map(|opened| opened.diagram);
+    let merged = merge_with_settings(&open(base)?, &open(ours)?, &open(theirs)?, &settings)?;
+    save_with_settings(&merged, ours, format, &settings)
+}
+
+fn report(path: &str, error: &Error) -> String {
+    match error {
+        Error::Merge(_) => format!("CONFLICT (erm): could not merge {path}\n\n{error}"),
+        _ => format!("erm-merge-driver: failed to merge {path}:\n\t{error}"),
+    }
+}
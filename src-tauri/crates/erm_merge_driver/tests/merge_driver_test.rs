@@ -0,0 +1,246 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+const FIXTURE: &str = "../erm/tests/open/fixtures/read_snapshot.erm";
+const TEMP_PREFIX: &str = "erm_merge_driver";
+
+struct Checkout {
+    base: PathBuf,
+    ours: PathBuf,
+    theirs: PathBuf,
+}
+
+impl Checkout {
+    fn new(test_name: &str, ours: &[(&str, &str)], theirs: &[(&str, &str)]) -> Self {
+        let fixture = fs::read_to_string(FIXTURE).expect("failed to read fixture");
+        Self::with_content(&fixture, test_name, ours, theirs)
+    }
+
+    fn with_content(
+        fixture: &str,
+        test_name: &str,
+        ours: &[(&str, &str)],
+        theirs: &[(&str, &str)],
+    ) -> Self {
+        let checkout = Self {
+            base: temp_file_path(test_name, "base"),
+            ours: temp_file_path(test_name, "ours"),
+            theirs: temp_file_path(test_name, "theirs"),
+        };
+
+        fs::write(&checkout.base, fixture).expect("failed to write base");
+        fs::write(&checkout.ours, replace(fixture, ours)).expect("failed to write ours");
+        fs::write(&checkout.theirs, replace(fixture, theirs)).expect("failed to write theirs");
+        checkout
+    }
+
+    fn run(&self) -> Output {
+        self.run_with(&[])
+    }
+
+    fn run_with(&self, options: &[&str]) -> Output {
+        self.run_as(options, Path::new("docs/model.erm"))
+    }
+
+    /// Runs the driver for the diagram at `path`, which git passes as `%P`.
+    fn run_as(&self, options: &[&str], path: &Path) -> Output {
+        Command::new(env!("CARGO_BIN_EXE_erm-merge-driver"))
+            .args(options)
+            .args([&self.base, &self.ours, &self.theirs])
+            .arg(path)
+            .output()
+            .expect("failed to run merge driver")
+    }
+}
+
+impl Drop for Checkout {
+    fn drop(&mut self) {
+        for path in [&self.base, &self.ours, &self.theirs] {
+            let _ = fs::remove_file(path);
+        }
+    }
+}
+
+#[test]
+fn independent_changes_are_merged_into_ours() {
+    let checkout = Checkout::new(
+        "independent",
+        &[(
+            "<logical_name>会員</logical_name>",
+            "<logical_name>Members</logical_name>",
+        )],
+        &[(
+            "<logical_name>投稿</logical_name>",
+            "<logical_name>Posts</logical_name>",
+        )],
+    );
+
+    let output = checkout.run();
+
+    assert!(output.status.success(), "{output:?}");
+    let merged = fs::read_to_string(&checkout.ours).expect("failed to read merged file");
+    assert!(merged.contains("<logical_name>Members</logical_name>"));
+    assert!(merged.contains("<logical_name>Posts</logical_name>"));
+}
+
+#[test]
+fn identical_inputs_leave_the_file_unchanged() {
+    let checkout = Checkout::new("identical", &[], &[]);
+    let ours = fs::read_to_string(&checkout.ours).expect("failed to read ours");
+
+    let output = checkout.run();
+
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(
+        fs::read_to_string(&checkout.ours).expect("failed to read merged file"),
+        ours
+    );
+}
+
+#[test]
+fn merged_file_keeps_the_layout_of_ours() {
+    let fixture = fs::read_to_string(FIXTURE).expect("failed to read fixture");
+    let checkout = Checkout::new(
+        "layout",
+        &[],
+        &[(
+            "<logical_name>投稿</logical_name>",
+            "<logical_name>Posts</logical_name>",
+        )],
+    );
+
+    let output = checkout.run();
+
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(
+        fs::read_to_string(&checkout.ours).expect("failed to read merged file"),
+        fixture.replacen(
+            "<logical_name>投稿</logical_name>",
+            "<logical_name>Posts</logical_name>",
+            1
+        )
+    );
+}
+
+#[test]
+fn canonical_option_writes_the_canonical_order() {
+    let preserved = Checkout::new("canonical_preserved", &[], &[]);
+    assert!(preserved.run().status.success());
+    let canonical = Checkout::new("canonical", &[], &[]);
+
+    let output = canonical.run_with(&["--canonical"]);
+
+    assert!(output.status.success(), "{output:?}");
+    assert_ne!(
+        fs::read_to_string(&canonical.ours).expect("failed to read merged file"),
+        fs::read_to_string(&preserved.ours).expect("failed to read merged file")
+    );
+}
+
+#[test]
+fn conflicting_changes_are_reported_and_leave_ours_untouched() {
+    let checkout = Checkout::new(
+        "conflict",
+        &[(
+            "<logical_name>会員</logical_name>",
+            "<logical_name>Members</logical_name>",
+        )],
+        &[(
+            "<logical_name>会員</logical_name>",
+            "<logical_name>Users</logical_name>",
+        )],
+    );
+    let ours = fs::read_to_string(&checkout.ours).expect("failed to read ours");
+
+    let output = checkout.run();
+
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "CONFLICT (erm): could not merge docs/model.erm\n\n\
+         Merge conflict\n\n\
+         - diagram_walkers.table[MEMBERS].logical_name: changed on both sides \
+         (base: 会員, ours: Members, theirs: Users)\n"
+    );
+    assert_eq!(
+        fs::read_to_string(&checkout.ours).expect("failed to read ours"),
+        ours
+    );
+}
+
+#[test]
+fn validation_settings_are_read_beside_the_merged_path() {
+    let fixture = replace(
+        &fs::read_to_string(FIXTURE).expect("failed to read fixture"),
+        &[(
+            "<physical_name>FIRST_NAME</physical_name>",
+            "<physical_name>LAST_NAME</physical_name>",
+        )],
+    );
+    let checkout = Checkout::with_content(
+        &fixture,
+        "settings",
+        &[],
+        &[(
+            "<logical_name>投稿</logical_name>",
+            "<logical_name>Posts</logical_name>",
+        )],
+    );
+    let path = temp_file_path("settings", "path");
+    let sidecar = PathBuf::from(format!("{}.validation.xml", path.display()));
+
+    assert_eq!(checkout.run_as(&[], &path).status.code(), Some(1));
+
+    fs::write(
+        &sidecar,
+        "<validation><suppressions><suppression><table>MEMBERS</table></suppression></suppressions></validation>",
+    )
+    .expect("failed to write validation settings");
+    let output = checkout.run_as(&[], &path);
+    let _ = fs::remove_file(&sidecar);
+
+    assert!(output.status.success(), "{output:?}");
+    let merged = fs::read_to_string(&checkout.ours).expect("failed to read merged file");
+    assert!(merged.contains("<logical_name>Posts</logical_name>"));
+}
+
+#[test]
+fn unreadable_input_is_reported() {
+    let checkout = Checkout::new("unreadable", &[], &[("<diagram>", "<diagram><oops>")]);
+
+    let output = checkout.run();
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(
+        String::from_utf8_lossy(&output.stderr)
+            .starts_with("erm-merge-driver: failed to merge docs/model.erm:")
+    );
+}
+
+#[test]
+fn missing_arguments_print_usage() {
+    let output = Command::new(env!("CARGO_BIN_EXE_erm-merge-driver"))
+        .arg("base.erm")
+        .output()
+        .expect("failed to run merge driver");
+
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).starts_with("usage: erm-merge-driver"));
+}
+
+fn replace(content: &str, replacements: &[(&str, &str)]) -> String {
+    replacements
+        .iter()
+        .fold(content.to_string(), |content, (from, to)| {
+            assert!(content.contains(from), "fixture does not contain {from}");
+            content.replacen(from, to, 1)
+        })
+}
+
+fn temp_file_path(test_name: &str, side: &str) -> PathBuf {
+    std::env::temp_dir().join(format!(
+        "{TEMP_PREFIX}_{}_{test_name}_{side}.erm",
+        std::process::id()
+    ))
+}
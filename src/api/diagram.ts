@@ -5,19 +5,34 @@ import {
   mapRelationshipsFrom,
   mapTablesFrom,
 } from "@/domain/mappers/tableMapper";
-import type { DiagramResponse } from "@/types/api/diagram";
+import type {
+  LoadDiagramResponse,
+  ValidationErrorResponse,
+} from "@/types/api/diagram";
+import type { ImageDataResponse } from "@/types/api/diagramWalkers";
 import type { ColumnGroup } from "@/types/domain/columnGroup";
 import type { Relationship } from "@/types/domain/relationship";
 import { type Settings } from "@/types/domain/settings";
 import type { Table } from "@/types/domain/table";
 
-export async function loadDiagram(filename: string): Promise<{
+/**
+ * Loads the diagram, or only the tables and positions of its virtual diagram
+ * named `virtualDiagram` when one is given.
+ */
+export async function loadDiagram(
+  filename: string,
+  virtualDiagram?: string,
+): Promise<{
   settings: Settings;
   tables: Table[];
   relationships: Relationship[];
   columnGroups: ColumnGroup[];
+  warnings: ValidationErrorResponse[];
 }> {
-  const diagram = await invoke<DiagramResponse>("load_diagram", { filename });
+  const { diagram, warnings } = await invoke<LoadDiagramResponse>(
+    "load_diagram",
+    { filename, virtualDiagram },
+  );
   const settings = diagram.diagramSettings;
   const tables = diagram.diagramWalkers?.tables ?? [];
   const columnGroups = diagram.columnGroups ?? [];
@@ -26,5 +41,13 @@ export async function loadDiagram(filename: string): Promise<{
     tables: mapTablesFrom(tables),
     relationships: mapRelationshipsFrom(tables),
     columnGroups: mapColumnGroupsFrom(columnGroups),
+    warnings,
   };
 }
+
+export async function loadImage(
+  filename: string,
+  index: number,
+): Promise<ImageDataResponse | null> {
+  return invoke<ImageDataResponse | null>("load_image", { filename, index });
+}
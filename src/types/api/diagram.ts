@@ -1,9 +1,34 @@
 import type { ColumnGroupsResponse } from "./columnGroups";
 import { type DiagramSettingsResponse } from "./diagramSettings";
 import type { DiagramWalkersResponse } from "./diagramWalkers";
+import type { VirtualDiagramResponse } from "./virtualDiagrams";
 
 export type DiagramResponse = {
+  currentErmodel?: string;
   diagramSettings?: DiagramSettingsResponse;
   diagramWalkers?: DiagramWalkersResponse;
+  virtualDiagrams?: VirtualDiagramResponse[];
   columnGroups?: ColumnGroupsResponse["columnGroups"];
 };
+
+export type SourceSpanResponse = {
+  start: number;
+  end: number;
+  line: number;
+  column: number;
+};
+
+export type ValidationErrorResponse = {
+  code: string;
+  kind: "validation";
+  severity: "error" | "warning";
+  path: string;
+  message: string;
+  targets: { label: string; value: string }[];
+  span: SourceSpanResponse | null;
+};
+
+export type LoadDiagramResponse = {
+  diagram: DiagramResponse;
+  warnings: ValidationErrorResponse[];
+};
@@ -37,8 +37,16 @@ type Relationship = {
   referredCompoundUniqueKey?: string;
 };
 
+type CommentConnection = {
+  id?: string;
+  source: string;
+  target: string;
+  bendpoints?: Bendpoint[];
+};
+
 type Connections = {
   relationships: Relationship[];
+  commentConnections?: CommentConnection[];
 };
 
 export type NormalColumn = {
@@ -83,6 +91,81 @@ export type TableResponse = {
   compoundUniqueKeyList: CompoundUniqueKeyList;
 };
 
+export type ViewResponse = {
+  physicalName: string;
+  logicalName: string;
+  description: string;
+  height?: number;
+  width?: number;
+  fontName?: string;
+  fontSize?: number;
+  x?: number;
+  y?: number;
+  color?: Color;
+  connections?: Connections;
+  sql: string;
+  columns: Columns;
+};
+
+export type WalkerNoteResponse = {
+  id?: string;
+  height?: number;
+  width?: number;
+  fontName?: string;
+  fontSize?: number;
+  x?: number;
+  y?: number;
+  color?: Color;
+  connections?: Connections;
+  noteText?: string;
+};
+
+export type WalkerGroupResponse = {
+  id?: string;
+  height?: number;
+  width?: number;
+  fontName?: string;
+  fontSize?: number;
+  x?: number;
+  y?: number;
+  color?: Color;
+  connections?: Connections;
+  walkerGroupName?: string;
+  members?: string[];
+};
+
+export type ImageFormat = "png" | "jpeg" | "gif";
+
+/** The image data itself is fetched separately with `load_image`. */
+export type ImageResponse = {
+  id?: string;
+  height?: number;
+  width?: number;
+  fontName?: string;
+  fontSize?: number;
+  x?: number;
+  y?: number;
+  color?: Color;
+  connections?: Connections;
+  /** Identifies the image data, which saving restores from the file; send it back unchanged. */
+  dataDigest?: string;
+  format?: ImageFormat;
+  hue: number;
+  saturation: number;
+  brightness: number;
+  alpha?: number;
+  fixAspectRatio?: boolean;
+};
+
+export type ImageDataResponse = {
+  format: ImageFormat;
+  data: string;
+};
+
 export type DiagramWalkersResponse = {
   tables?: TableResponse[];
+  views?: ViewResponse[];
+  notes?: WalkerNoteResponse[];
+  groups?: WalkerGroupResponse[];
+  images?: ImageResponse[];
 };
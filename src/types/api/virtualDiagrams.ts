@@ -0,0 +1,24 @@
+import type {
+  Color,
+  WalkerGroupResponse,
+  WalkerNoteResponse,
+} from "./diagramWalkers";
+
+type VirtualTable = {
+  tableId: string;
+  x?: number;
+  y?: number;
+  width?: number;
+  height?: number;
+  fontName?: string;
+  fontSize?: number;
+};
+
+export type VirtualDiagramResponse = {
+  vdiagramId?: string;
+  vdiagramName?: string;
+  color?: Color;
+  tables?: VirtualTable[];
+  notes?: WalkerNoteResponse[];
+  groups?: WalkerGroupResponse[];
+};